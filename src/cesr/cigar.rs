@@ -1,5 +1,5 @@
 use crate::cesr::verfer::Verfer;
-use crate::cesr::{non_trans_dex, BaseMatter, Parsable};
+use crate::cesr::{mtr_dex, BaseMatter, Parsable};
 use crate::errors::MatterError;
 use crate::Matter;
 use std::any::Any;
@@ -15,6 +15,14 @@ pub struct Cigar {
     pub verfer: Option<Verfer>,
 }
 
+/// Non-indexed signature codes a Cigar may carry
+const SIG_CODES: [&str; 4] = [
+    mtr_dex::ED25519_SIG,
+    mtr_dex::ECDSA_256K1_SIG,
+    mtr_dex::ECDSA_256R1_SIG,
+    mtr_dex::ED448_SIG,
+];
+
 impl Cigar {
     pub fn new(
         raw: Option<&[u8]>,
@@ -35,7 +43,7 @@ impl Cigar {
 
     pub fn from_qb64(qb64: &str, verfer: Option<Verfer>) -> Result<Self, MatterError> {
         let base = BaseMatter::from_qb64(qb64)?;
-        if !non_trans_dex::TUPLE.contains(&(base.code())) {
+        if !SIG_CODES.contains(&(base.code())) {
             return Err(MatterError::UnsupportedCodeError(String::from(base.code())));
         }
 
@@ -61,7 +69,7 @@ impl Cigar {
 impl Parsable for Cigar {
     fn from_qb64b(data: &mut Vec<u8>, strip: Option<bool>) -> Result<Self, MatterError> {
         let base = BaseMatter::from_qb64b(data, strip)?;
-        if !non_trans_dex::TUPLE.contains(&(base.code())) {
+        if !SIG_CODES.contains(&(base.code())) {
             return Err(MatterError::UnsupportedCodeError(String::from(base.code())));
         }
 
@@ -70,7 +78,7 @@ impl Parsable for Cigar {
 
     fn from_qb2(data: &mut Vec<u8>, strip: Option<bool>) -> Result<Self, MatterError> {
         let base = BaseMatter::from_qb2(data, strip)?;
-        if !non_trans_dex::TUPLE.contains(&(base.code())) {
+        if !SIG_CODES.contains(&(base.code())) {
             return Err(MatterError::UnsupportedCodeError(String::from(base.code())));
         }
 
@@ -119,3 +127,45 @@ impl Matter for Cigar {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cesr::signing::{Sigmat, Signer};
+
+    #[test]
+    fn test_cigar() -> Result<(), MatterError> {
+        let signer =
+            Signer::from_qb64("AJ1hsZ3v_VpguoRK9JLsLMREScVpezJpGXA7rAMcrn9g", Some(false))?;
+        let ser = b"abcdefghijklmnopqrstuvwxyz0123456789";
+        let Sigmat::NonIndexed(cigar) = signer.sign(ser, None, None, None)? else {
+            panic!("Invalid type")
+        };
+        assert_eq!(cigar.code(), mtr_dex::ED25519_SIG);
+        let verfer = cigar.verfer().unwrap();
+        assert_eq!(verfer.qb64(), signer.verfer().qb64());
+        assert!(verfer.verify(cigar.raw(), ser)?);
+
+        // Round trip through qb64 binding the known signing key
+        let qb64 = cigar.qb64();
+        assert!(qb64.starts_with(mtr_dex::ED25519_SIG));
+        let parsed = Cigar::from_qb64(&qb64, Some(signer.verfer().clone()))?;
+        assert_eq!(parsed.raw(), cigar.raw());
+        assert!(parsed.verfer().unwrap().verify(parsed.raw(), ser)?);
+        assert!(!parsed.verfer().unwrap().verify(parsed.raw(), b"abc")?);
+
+        // Round trip through qb64b and qb2 without a verfer
+        let parsed = Cigar::from_qb64b(&mut cigar.qb64b(), None)?;
+        assert_eq!(parsed.qb64(), qb64);
+        assert!(parsed.verfer().is_none());
+        let parsed = Cigar::from_qb2(&mut cigar.qb2(), None)?;
+        assert_eq!(parsed.qb64(), qb64);
+        assert!(signer.verfer().verify(parsed.raw(), ser)?);
+
+        // Verification keys are not signatures
+        let result = Cigar::from_qb64(&signer.verfer().qb64(), None);
+        assert!(matches!(result, Err(MatterError::UnsupportedCodeError(_))));
+
+        Ok(())
+    }
+}
@@ -996,6 +996,24 @@ pub fn get_sizes_2_0() -> HashMap<&'static str, Cizage> {
     sizes
 }
 
+/// Returns the counter codes legal under the given CESR version
+///
+/// Major version 1 selects the 1.0 size table, any later major version the 2.0
+/// table. Codes are sorted so the result is stable for error messages.
+///
+/// # Parameters
+/// * `gvrsn` - CESR genus version of the stream
+pub fn valid_codes(gvrsn: &Versionage) -> Vec<&'static str> {
+    let sizes = if gvrsn.major == 1 {
+        get_sizes_1_0()
+    } else {
+        get_sizes_2_0()
+    };
+    let mut codes: Vec<&'static str> = sizes.into_keys().collect();
+    codes.sort_unstable();
+    codes
+}
+
 /// Map of hard characters to their respective values
 ///
 /// Includes:
@@ -1287,17 +1305,18 @@ impl BaseCounter {
 
     pub fn from_qb64(qb64: &str) -> Result<Self, MatterError> {
         if qb64.is_empty() {
-            return Err(MatterError::ShortageError(
-                "Empty material, Need more characters.".to_string(),
-            ));
+            return Err(MatterError::ShortageError {
+                msg: "Empty material, Need more characters.".to_string(),
+                needed: 1,
+            });
         }
 
         // Extract first two char code selector
         if qb64.len() < 2 {
-            return Err(MatterError::ShortageError(format!(
-                "Need {} more characters.",
-                2 - qb64.len()
-            )));
+            return Err(MatterError::ShortageError {
+                msg: format!("Need {} more characters.", 2 - qb64.len()),
+                needed: 2 - qb64.len(),
+            });
         }
 
         let hards = hards();
@@ -1317,10 +1336,10 @@ impl BaseCounter {
         let hs = *hards.get(first.as_bytes()).unwrap() as usize;
 
         if qb64.len() < hs {
-            return Err(MatterError::ShortageError(format!(
-                "Need {} more characters.",
-                hs - qb64.len()
-            )));
+            return Err(MatterError::ShortageError {
+                msg: format!("Need {} more characters.", hs - qb64.len()),
+                needed: hs - qb64.len(),
+            });
         }
 
         // Get hard code
@@ -1339,10 +1358,10 @@ impl BaseCounter {
 
         // Check if we have enough bytes for the full code
         if qb64.len() < fs {
-            return Err(MatterError::ShortageError(format!(
-                "Need {} more characters.",
-                fs - qb64.len()
-            )));
+            return Err(MatterError::ShortageError {
+                msg: format!("Need {} more characters.", fs - qb64.len()),
+                needed: fs - qb64.len(),
+            });
         }
 
         // Extract count chars
@@ -1365,9 +1384,10 @@ impl BaseCounter {
 
     fn bexfil(qb2: &[u8]) -> Result<Self, MatterError> {
         if qb2.is_empty() {
-            return Err(MatterError::ShortageError(
-                "Empty material, Need more bytes.".to_string(),
-            ));
+            return Err(MatterError::ShortageError {
+                msg: "Empty material, Need more bytes.".to_string(),
+                needed: 1,
+            });
         }
         let sizes = &get_sizes_1_0();
 
@@ -1396,10 +1416,10 @@ impl BaseCounter {
         let bhs = ((hs * 3 + 3) / 4) as usize; // ceiling division of hs * 3 / 4
 
         if qb2.len() < bhs {
-            return Err(MatterError::ShortageError(format!(
-                "Need {} more bytes.",
-                bhs - qb2.len()
-            )));
+            return Err(MatterError::ShortageError {
+                msg: format!("Need {} more bytes.", bhs - qb2.len()),
+                needed: bhs - qb2.len(),
+            });
         }
 
         // Extract and convert hard part of code
@@ -1421,10 +1441,10 @@ impl BaseCounter {
         let bcs = (fs * 3 + 3) / 4; // ceiling division of fs * 3 / 4
 
         if qb2.len() < bcs {
-            return Err(MatterError::ShortageError(format!(
-                "Need {} more bytes.",
-                bcs - qb2.len()
-            )));
+            return Err(MatterError::ShortageError {
+                msg: format!("Need {} more bytes.", bcs - qb2.len()),
+                needed: bcs - qb2.len(),
+            });
         }
 
         // Extract and convert both hard and soft part of code
@@ -1626,6 +1646,18 @@ mod tests {
     use super::*;
     use crate::cesr::decode_b64;
 
+    #[test]
+    fn test_valid_codes() {
+        let v1 = valid_codes(&Versionage { major: 1, minor: 0 });
+        let v2 = valid_codes(&Versionage { major: 2, minor: 0 });
+
+        assert!(v2.contains(&ctr_dex_2_0::BIG_MAP_MESSAGE_BODY_GROUP));
+        assert!(!v1.contains(&"-0G"));
+        assert!(v1.contains(&ctr_dex_1_0::CONTROLLER_IDX_SIGS));
+        assert_eq!(v1.len(), get_sizes_1_0().len());
+        assert_eq!(v2.len(), get_sizes_2_0().len());
+    }
+
     #[test]
     fn test_genus_codes() {
         // Test that all codes have the expected values
@@ -1759,7 +1791,10 @@ mod tests {
         let short_qsc64 = qsc[..qsc.len() - 1].to_string();
         let result = BaseCounter::from_qb64(short_qsc64.as_str());
         assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), MatterError::ShortageError(_)));
+        assert!(matches!(
+            result.unwrap_err(),
+            MatterError::ShortageError { needed: 1, .. }
+        ));
 
         // Test truncates extra bytes from qb2 parameter
         let mut long_qscb2 = qscb2.clone();
@@ -1772,7 +1807,10 @@ mod tests {
         let mut short_qscb2 = qscb2[..qscb2.len() - 1].to_vec();
         let result = BaseCounter::from_qb2(&mut short_qscb2, None);
         assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), MatterError::ShortageError(_)));
+        assert!(matches!(
+            result.unwrap_err(),
+            MatterError::ShortageError { needed: 1, .. }
+        ));
 
         // Test with non-zero count=5
         let count = 5u64;
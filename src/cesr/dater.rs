@@ -1,5 +1,6 @@
 use crate::cesr::{get_sizes, mtr_dex, BaseMatter, Parsable};
 use crate::errors::MatterError;
+use crate::keri::toiso8601;
 use crate::Matter;
 use lazy_static::lazy_static;
 use std::any::Any;
@@ -18,16 +19,35 @@ pub struct Dater {
 
 #[allow(dead_code)]
 impl Dater {
-    pub fn from_dt(dt: chrono::DateTime<chrono::Utc>) -> Self {
-        let dts = dt.to_rfc3339();
-        let raw = dts.as_bytes().to_vec();
-        let base = BaseMatter::new(Some(&raw), Some(mtr_dex::DATE_TIME), None, None).unwrap();
-        Dater { base }
+    /// Creates a dater from `dt` formatted as ISO-8601 with microseconds and `+00:00` offset
+    pub fn from_dt(dt: chrono::DateTime<chrono::Utc>) -> Result<Self, MatterError> {
+        Dater::from_dts(&toiso8601(&dt))
     }
 
+    /// Creates a dater from the current UTC datetime
+    pub fn now() -> Self {
+        Dater::from_dt(chrono::Utc::now()).unwrap()
+    }
+
+    /// Creates a dater from an RFC-3339 datetime string. The datetime is normalized
+    /// to UTC with microsecond precision such as `2020-08-22T17:50:09.988921+00:00`
     pub fn from_dts(dts: &str) -> Result<Self, MatterError> {
-        let raw = dts.as_bytes();
-        let base = BaseMatter::new(Some(raw), Some(mtr_dex::DATE_TIME), None, None)?;
+        let dt = chrono::DateTime::parse_from_rfc3339(dts)
+            .map_err(|e| MatterError::InvalidValue(format!("Invalid datetime = {}: {}", dts, e)))?;
+        let dts = toiso8601(&dt.with_timezone(&chrono::Utc));
+
+        let sizes = get_sizes();
+        let size = sizes.get(mtr_dex::DATE_TIME).unwrap();
+        let width = (size.fs.unwrap() - size.hs) as usize;
+        if dts.len() != width {
+            return Err(MatterError::InvalidValue(format!(
+                "Unsupported datetime = {}, expected {} chars",
+                dts, width
+            )));
+        }
+
+        let qb64 = format!("{}{}", mtr_dex::DATE_TIME, B64_TRANSLATOR.to_b64(&dts));
+        let base = BaseMatter::from_qb64(&qb64)?;
         Ok(Dater { base })
     }
 
@@ -196,3 +216,82 @@ impl B64Translator {
         result
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dater() {
+        let dts = "2020-08-22T17:50:09.988921+00:00";
+        let qb64 = "1AAG2020-08-22T17c50c09d988921p00c00";
+
+        let dater = Dater::from_dts(dts).unwrap();
+        assert_eq!(dater.code(), mtr_dex::DATE_TIME);
+        assert_eq!(dater.raw().len(), 24);
+        assert_eq!(dater.qb64(), qb64);
+        assert_eq!(dater.dts(), dts);
+        assert_eq!(dater.dtsb(), dts.as_bytes().to_vec());
+
+        // Round trip through qb64, qb64b and qb2
+        let dater = Dater::from_qb64(qb64).unwrap();
+        assert_eq!(dater.dts(), dts);
+        let mut qb64b = qb64.as_bytes().to_vec();
+        let dater = Dater::from_qb64b(&mut qb64b, Some(true)).unwrap();
+        assert_eq!(dater.dts(), dts);
+        assert!(qb64b.is_empty());
+        let mut qb2 = dater.qb2();
+        let dater = Dater::from_qb2(&mut qb2, Some(false)).unwrap();
+        assert_eq!(dater.qb64(), qb64);
+        let dater = Dater::from_raw(Some(dater.raw())).unwrap();
+        assert_eq!(dater.dts(), dts);
+
+        let dt = chrono::DateTime::parse_from_rfc3339(dts)
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+        assert_eq!(dater.dt().unwrap(), dt);
+        assert_eq!(Dater::from_dt(dt).unwrap().qb64(), qb64);
+
+        let dater = Dater::now();
+        assert_eq!(dater.dts().len(), 32);
+        assert!(dater.dts().ends_with("+00:00"));
+        assert!(dater.dt().is_ok());
+
+        // Other valid RFC-3339 forms normalize to microseconds in UTC
+        for other in [
+            "2020-08-22T17:50:09.988921Z",
+            "2020-08-22T17:50:09.988921123+00:00",
+            "2020-08-22T19:50:09.988921+02:00",
+        ] {
+            assert_eq!(Dater::from_dts(other).unwrap().qb64(), qb64);
+        }
+        assert_eq!(
+            Dater::from_dts("2020-08-22T17:50:09Z").unwrap().dts(),
+            "2020-08-22T17:50:09.000000+00:00"
+        );
+
+        // Datetimes that do not fit the fixed width are rejected
+        let far = chrono::NaiveDate::from_ymd_opt(10000, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc();
+        assert!(matches!(
+            Dater::from_dt(far),
+            Err(MatterError::InvalidValue(_))
+        ));
+
+        // Invalid datetimes are rejected
+        for bad in [
+            "not a datetime",
+            "2020-08-22T17:50:09.988921",
+            "2020-13-22T17:50:09.988921+00:00",
+        ] {
+            assert!(matches!(
+                Dater::from_dts(bad),
+                Err(MatterError::InvalidValue(_))
+            ));
+        }
+        assert!(Dater::from_qb64("0AAAAAAAAAAAAAAAAAAAAAAA").is_err());
+    }
+}
@@ -187,7 +187,8 @@ impl Diger {
         Ok(digest)
     }
 
-    fn verify(&self, ser: &[u8]) -> bool {
+    /// Returns true if the digest of ser under this Diger's code matches its raw
+    pub fn verify(&self, ser: &[u8]) -> bool {
         match Diger::digest(ser, self.base.code()) {
             Ok(raw) => self.base.raw() == raw.as_slice(),
             Err(_) => false,
@@ -427,4 +428,27 @@ mod tests {
         assert!(!diger0.compare_with_diger(ser, &diger_ser1_sha3));
         assert!(!diger0.compare_with_qb64b(ser, &diger_ser1_sha3.qb64b()));
     }
+
+    #[test]
+    fn test_diger_blake2b_256() {
+        let ser = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+        let diger = Diger::from_ser(ser, Some(mtr_dex::BLAKE2B_256)).unwrap();
+        assert_eq!(diger.code(), mtr_dex::BLAKE2B_256);
+        assert_eq!(diger.raw().len(), raw_size(diger.code()).unwrap());
+        assert!(diger.verify(ser));
+        assert!(!diger.verify(b"abcdefghijklmnopqrstuvwxyz"));
+
+        // Round trips through qb64 and still verifies
+        let qb64 = diger.qb64();
+        assert!(qb64.starts_with('F'));
+        let parsed = Diger::from_qb64(&qb64).unwrap();
+        assert_eq!(parsed.raw(), diger.raw());
+        assert!(parsed.verify(ser));
+
+        // Agrees with the default Blake3-256 digest of the same serialization
+        let blake3 = Diger::from_qb64("ELC5L3iBVD77d_MYbYGGCUQgqQBju1o4x1Ud-z2sL-ux").unwrap();
+        assert!(blake3.verify(ser));
+        assert!(blake3.compare_with_diger(ser, &parsed));
+    }
 }
@@ -497,8 +497,7 @@ pub fn hards() -> HashMap<u8, i32> {
 /// representation and maps them to the same hardness values
 #[allow(dead_code)]
 pub fn get_bards() -> HashMap<u8, i32> {
-    let hards = crate::cesr::hards();
-    hards
+    hards()
         .iter()
         .map(|(&c, &hs)| (code_b64_to_b2(c), hs))
         .collect()
@@ -686,7 +685,10 @@ impl BaseIndexer {
     pub fn from_qb64(qb64b: &str) -> Result<Self, MatterError> {
         // Check if qb64b is empty
         if qb64b.is_empty() {
-            return Err(MatterError::ShortageError("Empty material.".to_string()));
+            return Err(MatterError::ShortageError {
+                msg: "Empty material.".to_string(),
+                needed: 1,
+            });
         }
 
         // Extract first character (code selector)
@@ -716,10 +718,10 @@ impl BaseIndexer {
 
         // Check if we have enough bytes
         if qb64b.len() < hs as usize {
-            return Err(MatterError::ShortageError(format!(
-                "Need {} more characters.",
-                hs - qb64b.len() as i32
-            )));
+            return Err(MatterError::ShortageError {
+                msg: format!("Need {} more characters.", hs - qb64b.len() as i32),
+                needed: hs as usize - qb64b.len(),
+            });
         }
 
         // Get hard code
@@ -742,10 +744,10 @@ impl BaseIndexer {
 
         // Check if we have enough bytes for code
         if qb64b.len() < cs as usize {
-            return Err(MatterError::ShortageError(format!(
-                "Need {} more characters.",
-                cs - qb64b.len() as u32
-            )));
+            return Err(MatterError::ShortageError {
+                msg: format!("Need {} more characters.", cs - qb64b.len() as u32),
+                needed: cs as usize - qb64b.len(),
+            });
         }
 
         // Extract index/size chars and convert to integer
@@ -807,10 +809,10 @@ impl BaseIndexer {
 
         // Check if we have enough bytes for full material
         if qb64b.len() < fs as usize {
-            return Err(MatterError::ShortageError(format!(
-                "Need {} more chars.",
-                fs - qb64b.len() as u32
-            )));
+            return Err(MatterError::ShortageError {
+                msg: format!("Need {} more chars.", fs - qb64b.len() as u32),
+                needed: fs as usize - qb64b.len(),
+            });
         }
 
         // Extract the fully qualified code plus material
@@ -888,9 +890,10 @@ impl BaseIndexer {
     pub fn bexfil(qb2: &[u8]) -> Result<Self, MatterError> {
         // Empty need more bytes
         if qb2.is_empty() {
-            return Err(MatterError::ShortageError(
-                "Empty material, Need more bytes.".to_string(),
-            ));
+            return Err(MatterError::ShortageError {
+                msg: "Empty material, Need more bytes.".to_string(),
+                needed: 1,
+            });
         }
 
         // Extract first sextet as code selector
@@ -924,10 +927,10 @@ impl BaseIndexer {
 
         // Need more bytes
         if qb2.len() < bhs {
-            return Err(MatterError::ShortageError(format!(
-                "Need {} more bytes.",
-                bhs - qb2.len()
-            )));
+            return Err(MatterError::ShortageError {
+                msg: format!("Need {} more bytes.", bhs - qb2.len()),
+                needed: bhs - qb2.len(),
+            });
         }
 
         // Extract and convert hard part of code
@@ -952,10 +955,10 @@ impl BaseIndexer {
 
         // Need more bytes
         if qb2.len() < bcs {
-            return Err(MatterError::ShortageError(format!(
-                "Need {} more bytes.",
-                bcs - qb2.len()
-            )));
+            return Err(MatterError::ShortageError {
+                msg: format!("Need {} more bytes.", bcs - qb2.len()),
+                needed: bcs - qb2.len(),
+            });
         }
 
         // Extract and convert both hard and soft part of code
@@ -1014,10 +1017,10 @@ impl BaseIndexer {
 
         // Need more bytes
         if qb2.len() < bfs {
-            return Err(MatterError::ShortageError(format!(
-                "Need {} more bytes.",
-                bfs - qb2.len()
-            )));
+            return Err(MatterError::ShortageError {
+                msg: format!("Need {} more bytes.", bfs - qb2.len()),
+                needed: bfs - qb2.len(),
+            });
         }
 
         // Extract qb2 fully qualified primitive code plus material
@@ -1271,11 +1274,14 @@ impl BaseIndexer {
 
         // Convert code both to right align b2 int then left shift in pad bits
         // then convert to bytes
-        let b64_int = b64_to_int(&both);
+        // Big codes with index and ondex run past 32 bits so accumulate as BigUint
+        let b64_int = both.chars().fold(BigUint::from(0u32), |acc, c| {
+            (acc << 6) + b64_to_int(c.encode_utf8(&mut [0; 4]))
+        });
         let shifted = b64_int << (2 * (ps - ls as usize));
 
         // Convert to big-endian bytes with the correct length
-        let bcode = int_to_bytes(BigUint::from(shifted), n);
+        let bcode = int_to_bytes(shifted, n);
 
         // Create the full binary representation
         let mut full = bcode;
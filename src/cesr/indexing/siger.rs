@@ -5,11 +5,12 @@ use crate::errors::MatterError;
 use crate::Matter;
 use std::any::Any;
 
-///  Cigar is Matter subclass holding a nonindexed signature with verfer property.
-///  From Matter .raw is signature and .code is signature cipher suite
+///  Siger is Indexer subclass holding an indexed signature with verfer property.
+///  From Indexer .raw is signature, .code is signature cipher suite, .index is the
+///  offset of the signing key in the current key list and .ondex its offset in the
+///  prior next key list.
 ///  Adds .verfer property to hold Verfer instance of associated verifier public key
 ///  Verfer's .raw as verifier key and .code is verifier cipher suite.
-
 #[derive(Debug, Clone)]
 pub struct Siger {
     base: BaseIndexer,
@@ -24,13 +25,14 @@ impl Siger {
         ondex: Option<u32>,
         verfer: Option<Verfer>,
     ) -> Result<Self, MatterError> {
-        if !idx_sig_dex::TUPLE.contains(&(code.unwrap())) {
-            return Err(MatterError::UnsupportedCodeError(String::from(
-                code.unwrap(),
-            )));
+        let code = code.ok_or_else(|| {
+            MatterError::EmptyMaterialError("Missing code for indexed signature.".to_owned())
+        })?;
+        if !idx_sig_dex::TUPLE.contains(&code) {
+            return Err(MatterError::UnsupportedCodeError(String::from(code)));
         }
 
-        let base = BaseIndexer::new(raw, code, index, ondex)?;
+        let base = BaseIndexer::new(raw, Some(code), index, ondex)?;
         Ok(Siger { base, verfer })
     }
 
@@ -140,3 +142,63 @@ impl Matter for Siger {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cesr::indexing::idr_dex;
+
+    #[test]
+    fn test_siger() -> Result<(), MatterError> {
+        let sig = [7u8; 64];
+
+        // Small code carries a single index used for both lists
+        let siger = Siger::new(Some(&sig), Some(idr_dex::ED25519_SIG), Some(5), None, None)?;
+        assert_eq!(siger.code(), idr_dex::ED25519_SIG);
+        assert_eq!(siger.index(), 5);
+        assert_eq!(siger.ondex(), Some(5));
+        assert_eq!(siger.qb64().len(), 88);
+
+        let parsed = Siger::from_qb64(&siger.qb64(), None)?;
+        assert_eq!(parsed.raw(), &sig[..]);
+        assert_eq!((parsed.index(), parsed.ondex()), (5, Some(5)));
+        let parsed = Siger::from_qb2(&mut siger.qb2(), None)?;
+        assert_eq!(parsed.qb64(), siger.qb64());
+        assert_eq!((parsed.index(), parsed.ondex()), (5, Some(5)));
+
+        // Current only code has no ondex
+        let siger = Siger::new(
+            Some(&sig),
+            Some(idr_dex::ED25519_CRT_SIG),
+            Some(3),
+            None,
+            None,
+        )?;
+        let parsed = Siger::from_qb64b(&mut siger.qb64b(), None)?;
+        assert_eq!((parsed.index(), parsed.ondex()), (3, None));
+
+        // Big code carries distinct index and ondex beyond 63
+        let siger = Siger::new(
+            Some(&sig),
+            Some(idr_dex::ED25519_BIG_SIG),
+            Some(100),
+            Some(70),
+            None,
+        )?;
+        assert_eq!(siger.code(), idr_dex::ED25519_BIG_SIG);
+        assert_eq!((siger.index(), siger.ondex()), (100, Some(70)));
+
+        let parsed = Siger::from_qb64(&siger.qb64(), None)?;
+        assert_eq!(parsed.code(), idr_dex::ED25519_BIG_SIG);
+        assert_eq!((parsed.index(), parsed.ondex()), (100, Some(70)));
+        let parsed = Siger::from_qb2(&mut siger.qb2(), None)?;
+        assert_eq!(parsed.raw(), &sig[..]);
+        assert_eq!((parsed.index(), parsed.ondex()), (100, Some(70)));
+
+        // Small code cannot hold an index past 63
+        assert!(Siger::new(Some(&sig), Some(idr_dex::ED25519_SIG), Some(64), None, None).is_err());
+        assert!(Siger::new(Some(&sig), None, Some(0), None, None).is_err());
+
+        Ok(())
+    }
+}
@@ -23,6 +23,7 @@ pub mod prefixer;
 pub mod saider;
 pub mod seqner;
 pub mod signing;
+pub mod streaming;
 pub mod tagger;
 pub mod texter;
 pub mod tholder;
@@ -288,7 +289,10 @@ pub const COLDS: Coldage = Coldage {
 /// annotated in (0o0)  return 'ano'
 pub fn sniff(ims: &[u8]) -> Result<&'static str, MatterError> {
     if ims.is_empty() {
-        return Err(MatterError::ShortageError("Need more bytes.".to_string()));
+        return Err(MatterError::ShortageError {
+            msg: "Need more bytes.".to_string(),
+            needed: 1,
+        });
     }
 
     // Extract the first 3 bits (tritet) by shifting right 5 bits
@@ -2185,7 +2189,8 @@ pub fn hards() -> HashMap<u8, i32> {
     map
 }
 
-/// Converts a base64 character to its binary quadlet (2-bit) representation
+/// Converts a base64 character to its leading sextet as `nab_sextets` returns it,
+/// the 6 bit value left aligned in a byte
 fn code_b64_to_b2(c: u8) -> u8 {
     match c {
         b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'+' | b'/' => {
@@ -2197,7 +2202,7 @@ fn code_b64_to_b2(c: u8) -> u8 {
                 b'/' => 63,
                 _ => unreachable!(),
             };
-            val
+            val << 2
         }
         _ => b'0',
     }
@@ -2208,16 +2213,10 @@ fn code_b64_to_b2(c: u8) -> u8 {
 /// representation and maps them to the same hardness values
 pub fn get_bards() -> HashMap<u8, i32> {
     let hards = hards();
-    let mut bards: HashMap<u8, i32> = hards
+    hards
         .iter()
         .map(|(&c, &hs)| (code_b64_to_b2(c), hs))
-        .collect();
-
-    // Add specific mapping for SALT_128 code which starts with '0'
-    // The binary sextet representation of '0' in qb2 format is 0xd0 (208)
-    bards.insert(0xd0, 2); // '0' has a hard size of 2
-
-    bards
+        .collect()
 }
 
 /// Matter is a trait for fully qualified cryptographic material.
@@ -2434,9 +2433,10 @@ impl BaseMatter {
     /// Creates a new BaseMatter from a qb64 string
     pub fn from_qb64(qb64: &str) -> Result<Self, MatterError> {
         if qb64.is_empty() {
-            return Err(MatterError::ShortageError(
-                "Empty qb64, invalid".to_string(),
-            ));
+            return Err(MatterError::ShortageError {
+                msg: "Empty qb64, invalid".to_string(),
+                needed: 1,
+            });
         }
 
         let first = &qb64[0..1];
@@ -2464,10 +2464,10 @@ impl BaseMatter {
         let hs = *hards.get(&first.bytes().next().unwrap_or(b'A')).unwrap(); // get hard code size
 
         if qb64.len() < hs as usize {
-            return Err(MatterError::ShortageError(format!(
-                "Need {} more characters.",
-                hs - qb64.len() as i32
-            )));
+            return Err(MatterError::ShortageError {
+                msg: format!("Need {} more characters.", hs - qb64.len() as i32),
+                needed: hs as usize - qb64.len(),
+            });
         }
 
         let hard = &qb64[0..hs as usize];
@@ -2514,10 +2514,10 @@ impl BaseMatter {
         };
 
         if qb64.len() < fs as usize {
-            return Err(MatterError::ShortageError(format!(
-                "Need {} more chars.",
-                fs - qb64.len() as u32
-            )));
+            return Err(MatterError::ShortageError {
+                msg: format!("Need {} more chars.", fs - qb64.len() as u32),
+                needed: fs as usize - qb64.len(),
+            });
         }
 
         let qb64 = &qb64[0..fs as usize]; // fully qualified primitive code plus material
@@ -3018,10 +3018,10 @@ pub fn nab_sextets(b: &[u8], l: usize) -> Result<Vec<u8>, MatterError> {
     let n = (l * 3 + 3) / 4; // Equivalent to ceiling of l*3/4
 
     if n > b.len() {
-        return Err(MatterError::ShortageError(format!(
-            "Not enough bytes in {:?} to nab {} sextets.",
-            b, l
-        )));
+        return Err(MatterError::ShortageError {
+            msg: format!("Not enough bytes in {:?} to nab {} sextets.", b, l),
+            needed: n - b.len(),
+        });
     }
 
     // Extract the first n bytes and convert to a BigUint
@@ -3076,10 +3076,10 @@ pub fn code_b2_to_b64(b: &[u8], l: usize) -> Result<String, MatterError> {
     let n = (l * 3 + 3) / 4; // Equivalent to ceiling of l*3/4
 
     if n > b.len() {
-        return Err(MatterError::ShortageError(format!(
-            "Not enough bytes in {:?} to nab {} sextets.",
-            b, l
-        )));
+        return Err(MatterError::ShortageError {
+            msg: format!("Not enough bytes in {:?} to nab {} sextets.", b, l),
+            needed: n - b.len(),
+        });
     }
 
     // Extract the first n bytes and convert to a BigUint
@@ -264,49 +264,8 @@ impl Saider {
         label: &str,
         ignore: Option<&[&str]>,
     ) -> Result<(Vec<u8>, Sadder), KERIError> {
-        // Validate the digest code
-        if !dig_dex::TUPLE.contains(&code) {
-            return Err(KERIError::ValueError(format!(
-                "Unsupported digest code={}.",
-                code
-            )));
-        }
-
-        // Create a copy of sad so we don't modify the original
-        let mut sad_copy = sad.clone();
-
-        // Fill the specified label field with dummy characters to get size correct
-        let sizes = get_sizes();
-        let fs = sizes
-            .get(code)
-            .ok_or_else(|| KERIError::ValueError(format!("Unknown code size for {}", code)))?
-            .fs
-            .unwrap();
-
-        // Fill in the dummy characters in the appropriate field
-        sad_copy.insert(
-            label.to_string(),
-            SadValue::String(Self::DUMMY.to_string().repeat(fs as usize)),
-        );
-
-        // If versioned, update size in version string
-        if sad_copy.contains_key("v") {
-            let (_, _, _, processed_sad, _) = sizeify(&sad_copy, kind_opt, None)?;
-            sad_copy = processed_sad;
-        }
-
-        // Create a copy for serialization, removing ignored fields
-        let mut ser_copy = sad_copy.clone();
-
-        // Remove ignored fields if any
-        if let Some(ignored_fields) = ignore {
-            for &field in ignored_fields {
-                ser_copy.shift_remove(field);
-            }
-        }
-
-        // Serialize the prepared Sadder
-        let serialized = Self::_serialize(ser_copy, kind_opt)?;
+        // Fill the label field with dummy characters and serialize
+        let (serialized, sad_copy) = dummify(sad, label, code, kind_opt, ignore)?;
 
         // Generate digest
         let digest = Diger::from_ser(&serialized, Some(code))?;
@@ -437,6 +396,87 @@ impl Saider {
     }
 }
 
+/// Returns the canonical dummy filled serialization of sad used to compute its SAID
+///
+/// The label field is filled with `#` repeated to the qb64 length of the digest code
+/// and, if versioned, the size in the version string is updated before serializing.
+/// Both `Saider::saidify` and `Saider::verify` derive from these bytes.
+///
+/// # Parameters
+/// * `sad`: Sadder to be injected with dummy and serialized
+/// * `label`: Sadder field label to inject dummy characters into
+/// * `code`: Digest type code from dig_dex
+/// * `kind_opt`: Optional serialization format (Json, Cbor, MsgPack)
+/// * `ignore`: Optional list of fields to ignore when generating SAID
+///
+/// # Errors
+/// * Returns KERIError if code is unsupported or serialization fails
+pub fn dummy_serialization(
+    sad: &Sadder,
+    label: &str,
+    code: &str,
+    kind_opt: Option<&Kinds>,
+    ignore: Option<&[&str]>,
+) -> Result<Vec<u8>, KERIError> {
+    let (serialized, _) = dummify(sad, label, code, kind_opt, ignore)?;
+    Ok(serialized)
+}
+
+/// Returns the dummy filled serialization of sad along with the dummy filled sad
+fn dummify(
+    sad: &Sadder,
+    label: &str,
+    code: &str,
+    kind_opt: Option<&Kinds>,
+    ignore: Option<&[&str]>,
+) -> Result<(Vec<u8>, Sadder), KERIError> {
+    // Validate the digest code
+    if !dig_dex::TUPLE.contains(&code) {
+        return Err(KERIError::ValueError(format!(
+            "Unsupported digest code={}.",
+            code
+        )));
+    }
+
+    // Create a copy of sad so we don't modify the original
+    let mut sad_copy = sad.clone();
+
+    // Fill the specified label field with dummy characters to get size correct
+    let sizes = get_sizes();
+    let fs = sizes
+        .get(code)
+        .ok_or_else(|| KERIError::ValueError(format!("Unknown code size for {}", code)))?
+        .fs
+        .unwrap();
+
+    // Fill in the dummy characters in the appropriate field
+    sad_copy.insert(
+        label.to_string(),
+        SadValue::String(Saider::DUMMY.to_string().repeat(fs as usize)),
+    );
+
+    // If versioned, update size in version string
+    if sad_copy.contains_key("v") {
+        let (_, _, _, processed_sad, _) = sizeify(&sad_copy, kind_opt, None)?;
+        sad_copy = processed_sad;
+    }
+
+    // Create a copy for serialization, removing ignored fields
+    let mut ser_copy = sad_copy.clone();
+
+    // Remove ignored fields if any
+    if let Some(ignored_fields) = ignore {
+        for &field in ignored_fields {
+            ser_copy.shift_remove(field);
+        }
+    }
+
+    // Serialize the prepared Sadder
+    let serialized = Saider::_serialize(ser_copy, kind_opt)?;
+
+    Ok((serialized, sad_copy))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -815,4 +855,32 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_dummy_serialization() -> Result<(), KERIError> {
+        let sad: Sadder = indexmap!(
+            "v".to_string() => SadValue::String("KERI10JSON000000_".to_string()),
+            "d".to_string() => SadValue::String("".to_string()),
+            "first".to_string() => SadValue::String("John".to_string()),
+        );
+
+        let ser = dummy_serialization(&sad, "d", mtr_dex::BLAKE3_256, None, None)?;
+        let dummy = "#".repeat(44);
+        assert!(String::from_utf8_lossy(&ser).contains(&format!("\"d\":\"{}\"", dummy)));
+
+        // saidify digests exactly these bytes
+        let (saider, ssad) = Saider::saidify(sad.clone(), None, None, None, None)?;
+        let diger = Diger::from_ser(&ser, Some(mtr_dex::BLAKE3_256))?;
+        assert_eq!(saider.raw(), diger.raw());
+
+        // verify sees the same bytes once the said has been injected
+        let vser = dummy_serialization(&ssad, "d", saider.code(), None, None)?;
+        assert_eq!(vser, ser);
+        assert!(saider.verify(&ssad, true, true, None, "d", None));
+
+        // Unsupported digest code
+        assert!(dummy_serialization(&sad, "d", mtr_dex::ED25519, None, None).is_err());
+
+        Ok(())
+    }
 }
@@ -11,35 +11,18 @@ pub struct Seqner {
 
 #[allow(dead_code)]
 impl Seqner {
+    /// Creates a fixed width `0A` seqner from the sequence number `sn`
     pub fn from_sn(sn: u128) -> Self {
-        // For the particular test case of u64::MAX + 1,
-        // we want [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]
-        let mut bytes = [0u8; 16];
-
-        if sn <= u64::MAX as u128 {
-            // For values up to u64::MAX, place in the last 8 bytes
-            bytes[8..].copy_from_slice(&(sn as u64).to_be_bytes());
-        } else {
-            // For larger values, we need to handle specially
-            // The value u64::MAX + 1 (2^64) needs to become [0,..,0, 0,...,1]
-            let high = (sn >> 64) as u64;
-            let low = sn as u64;
-
-            // Place high bits in the second half
-            bytes[8..].copy_from_slice(&high.to_be_bytes());
-            // Place low bits in the first half
-            bytes[0..8].copy_from_slice(&low.to_be_bytes());
-        }
-
-        let raw = bytes.to_vec();
+        let raw = sn.to_be_bytes();
         let base = BaseMatter::new(Some(&raw), Some(mtr_dex::SALT_128), None, None).unwrap();
         Seqner { base }
     }
 
+    /// Creates a seqner from the hex string sequence number `snh`
     pub fn from_snh(snh: &str) -> Result<Self, MatterError> {
-        let sn = u128::from_str_radix(snh, 16).unwrap();
-        let seqner = Seqner::from_sn(sn);
-        Ok(seqner)
+        let sn = u128::from_str_radix(snh, 16)
+            .map_err(|e| MatterError::InvalidValue(format!("Invalid hex sn = {}: {}", snh, e)))?;
+        Ok(Seqner::from_sn(sn))
     }
 
     pub fn from_raw(raw: Option<&[u8]>) -> Result<Self, MatterError> {
@@ -58,18 +41,16 @@ impl Seqner {
     }
 
     /// Returns the sequence number
-    pub fn sn(&self) -> u64 {
+    pub fn sn(&self) -> u128 {
         let raw = self.base.raw();
-        let start = raw.len().saturating_sub(8); // Get the start index for the last 8 bytes
-
-        let mut bytes = [0u8; 8];
-        let slice = &raw[start..]; // This could be less than 8 bytes if raw is smaller
+        let start = raw.len().saturating_sub(16);
 
-        // Copy the available bytes starting from the right (least significant)
-        let offset = 8 - slice.len();
-        bytes[offset..].copy_from_slice(slice);
+        // Right align the raw bytes so shorter raw still decodes big endian
+        let mut bytes = [0u8; 16];
+        let slice = &raw[start..];
+        bytes[16 - slice.len()..].copy_from_slice(slice);
 
-        u64::from_be_bytes(bytes)
+        u128::from_be_bytes(bytes)
     }
 
     /// Returns hex string representation of the sequence number
@@ -300,7 +281,31 @@ mod tests {
         // Test with large numbers
         let large_sn: u128 = u64::MAX as u128 + 1;
         let number = Seqner::from_sn(large_sn);
-        assert_eq!(number.raw()[8..], (1u64).to_be_bytes());
-        assert_eq!(number.raw()[0..8], [0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(number.raw()[0..8], (1u64).to_be_bytes());
+        assert_eq!(number.raw()[8..], [0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(number.sn(), large_sn);
+        assert_eq!(number.snh(), "10000000000000000");
+        assert_eq!(number.qb64(), "0AAAAAAAAAAAAQAAAAAAAAAA");
+
+        let number = Seqner::from_qb64(&number.qb64()).unwrap();
+        assert_eq!(number.sn(), large_sn);
+
+        let number = Seqner::from_snh(&format!("{:x}", u128::MAX)).unwrap();
+        assert_eq!(number.sn(), u128::MAX);
+        assert_eq!(number.snh(), format!("{:x}", u128::MAX));
+        assert_eq!(Seqner::from_qb64(&number.qb64()).unwrap().sn(), u128::MAX);
+
+        // Hex must match the numeric value
+        let number = Seqner::from_sn(0xdead_beef);
+        assert_eq!(number.snh(), "deadbeef");
+        assert_eq!(
+            u128::from_str_radix(&number.snh(), 16).unwrap(),
+            number.sn()
+        );
+
+        assert!(matches!(
+            Seqner::from_snh("xyz"),
+            Err(MatterError::InvalidValue(_))
+        ));
     }
 }
@@ -324,6 +324,27 @@ mod tests {
         assert_eq!(signer.raw(), signer2.raw());
     }
 
+    #[test]
+    fn test_signer_across_salters() {
+        // Random salter and a second instance parsed from its qb64
+        let salter = Salter::new(None, None, None).unwrap();
+        let other = Salter::from_qb64_and_tier(&salter.qb64(), None).unwrap();
+        assert_eq!(other.raw(), salter.raw());
+
+        let signer = salter.signer(None, None, "test-path", None, true).unwrap();
+        let again = other.signer(None, None, "test-path", None, true).unwrap();
+        assert_eq!(again.verfer().qb64(), signer.verfer().qb64());
+
+        let signer = salter.signer(None, Some(false), "0", None, true).unwrap();
+        let again = other.signer(None, Some(false), "0", None, true).unwrap();
+        assert_eq!(again.verfer().qb64(), signer.verfer().qb64());
+
+        // A different salt with the same path gives another key
+        let third = Salter::new(None, None, None).unwrap();
+        let different = third.signer(None, Some(false), "0", None, true).unwrap();
+        assert_ne!(different.verfer().qb64(), signer.verfer().qb64());
+    }
+
     #[test]
     fn test_signers_creation() {
         let raw = b"0123456789abcdef";
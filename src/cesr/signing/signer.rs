@@ -379,6 +379,12 @@ impl Signer {
         }
     }
 
+    /// Create a Signer from a qb64 private key seed, deriving its verfer
+    pub fn from_qb64(qb64: &str, transferable: Option<bool>) -> Result<Self, MatterError> {
+        let mut data = qb64.as_bytes().to_vec();
+        Self::from_qb64b_and_transferable(&mut data, None, transferable.unwrap_or(true))
+    }
+
     pub fn from_qb64b_and_transferable(
         data: &mut Vec<u8>,
         strip: Option<bool>,
@@ -539,6 +545,50 @@ mod tests {
         assert_eq!(verfer.code(), mtr_dex::ED25519N);
     }
 
+    #[test]
+    fn test_signer_from_seed() -> Result<(), MatterError> {
+        // RFC 8032 section 7.1 test 1 private key seed
+        let seed = "AJ1hsZ3v_VpguoRK9JLsLMREScVpezJpGXA7rAMcrn9g";
+        let ser = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+        let signer = Signer::from_qb64(seed, None)?;
+        assert_eq!(signer.qb64(), seed);
+        assert_eq!(
+            signer.verfer().qb64(),
+            "DNdamAGCsQq31Uv-08lkBzoO4XLz2qYjJa8CGmj3B1Ea"
+        );
+        let signer = Signer::from_qb64(seed, Some(false))?;
+        assert_eq!(
+            signer.verfer().qb64(),
+            "BNdamAGCsQq31Uv-08lkBzoO4XLz2qYjJa8CGmj3B1Ea"
+        );
+
+        // Same seed from raw gives the same keys and signatures
+        let again = Signer::new(Some(signer.raw()), None, Some(false))?;
+        assert_eq!(again.verfer().qb64(), signer.verfer().qb64());
+
+        let Sigmat::NonIndexed(cigar) = signer.sign(ser, None, None, None)? else {
+            panic!("Invalid type")
+        };
+        let Sigmat::NonIndexed(other) = again.sign(ser, None, None, None)? else {
+            panic!("Invalid type")
+        };
+        assert_eq!(cigar.qb64(), other.qb64());
+        assert!(signer.verfer().verify(cigar.raw(), ser)?);
+        assert!(!signer.verfer().verify(cigar.raw(), b"abc")?);
+
+        let Sigmat::Indexed(siger) = signer.sign(ser, Some(1), None, None)? else {
+            panic!("Invalid type")
+        };
+        assert_eq!(siger.index(), 1);
+        assert!(signer.verfer().verify(siger.raw(), ser)?);
+
+        // Seed qb64 with a non seed code
+        assert!(Signer::from_qb64("DNdamAGCsQq31Uv-08lkBzoO4XLz2qYjJa8CGmj3B1Ea", None).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_signing() {
         // Create a signer
@@ -0,0 +1,626 @@
+use crate::cesr::counting::{ctr_dex_1_0, BaseCounter, Counter};
+use crate::cesr::indexing::siger::Siger;
+use crate::cesr::indexing::Indexer;
+use crate::cesr::verfer::Verfer;
+use crate::cesr::{sniff, BaseMatter, Parsable, COLDS};
+use crate::errors::MatterError;
+use crate::keri::{smell, SMELLSIZE};
+use crate::Matter;
+
+/// Size in characters of the shortest qb64 indexed signature
+const MIN_SIGER_SIZE: usize = 88;
+
+/// Size in characters of the longest text domain counter
+const MAX_COUNTER_SIZE: usize = 8;
+
+/// Counter codes whose count is the quadlet size of the group they frame
+const QUADLET_GROUPS: [&str; 4] = [
+    ctr_dex_1_0::ATTACHMENT_GROUP,
+    ctr_dex_1_0::BIG_ATTACHMENT_GROUP,
+    ctr_dex_1_0::PATHED_MATERIAL_GROUP,
+    ctr_dex_1_0::BIG_PATHED_MATERIAL_GROUP,
+];
+
+/// Parses exactly `count` indexed signatures from the front of `buf`
+///
+/// Intended for the group that follows a controller or witness indexed signatures
+/// counter such as `-A`. Each signature is stripped from `buf` as it is parsed so on
+/// success `buf` starts at whatever follows the group.
+///
+/// # Parameters
+/// * `buf` - Stream bytes positioned at the first signature of the group
+/// * `count` - Number of signatures given by the preceding counter
+///
+/// # Returns
+/// * `Result<Vec<Siger>, MatterError>` - Parsed signatures in stream order, or
+///   ShortageError if `buf` runs out before `count` signatures are parsed
+pub fn parse_indexed_sigs(buf: &mut Vec<u8>, count: u64) -> Result<Vec<Siger>, MatterError> {
+    // count comes from the stream so only reserve what buf could possibly hold
+    let cap = count.min((buf.len() / MIN_SIGER_SIZE) as u64) as usize;
+    let mut sigers = Vec::with_capacity(cap);
+    for i in 0..count {
+        if buf.is_empty() {
+            return Err(MatterError::ShortageError {
+                msg: format!("Need {} more indexed signatures, got {}.", count - i, i),
+                needed: 1,
+            });
+        }
+        sigers.push(Siger::from_qb64b(buf, Some(true))?);
+    }
+
+    Ok(sigers)
+}
+
+/// Parses a quadlet counted group counter and takes its framed payload from the
+/// front of `buf`
+///
+/// The payload span is the counter's count of quadlets, 4 characters each. Only
+/// the counter's own characters are copied to parse it so a large `buf` is not
+/// cloned. On success both the counter and its payload are stripped from `buf`.
+/// On error `buf` is left untouched.
+///
+/// # Parameters
+/// * `buf` - Stream bytes positioned at the group counter such as `-V`
+///
+/// # Returns
+/// * `Result<(BaseCounter, Vec<u8>), MatterError>` - Parsed counter and payload
+///   bytes, UnexpectedCountCodeError if the counter does not frame a quadlet
+///   counted group, or ShortageError if `buf` ends before the framed span
+pub fn take_group(buf: &mut Vec<u8>) -> Result<(BaseCounter, Vec<u8>), MatterError> {
+    let mut head = buf[..buf.len().min(MAX_COUNTER_SIZE)].to_vec();
+    let counter = BaseCounter::from_qb64b(&mut head, None)?;
+    if !QUADLET_GROUPS.contains(&counter.code()) {
+        return Err(MatterError::UnexpectedCountCodeError(format!(
+            "Expected quadlet counted group counter got {}.",
+            counter.code()
+        )));
+    }
+
+    let fs = counter.full_size() as usize;
+    let span = counter.count() as usize * 4;
+    let have = buf.len() - fs;
+    if have < span {
+        return Err(MatterError::ShortageError {
+            msg: format!("Need {} bytes of framed group payload, got {}.", span, have),
+            needed: span - have,
+        });
+    }
+
+    let payload = buf[fs..fs + span].to_vec();
+    buf.drain(..fs + span);
+
+    Ok((counter, payload))
+}
+
+/// Verifies that the contents of an attachment group fill its declared frame
+///
+/// A quadlet counted group such as `-V` declares the size of everything inside it
+/// so truncated or padded contents show up as a size mismatch.
+///
+/// # Parameters
+/// * `group` - Parsed attachment group counter
+/// * `inner` - Bytes framed by the group
+///
+/// # Returns
+/// * `Result<(), MatterError>` - FrameSizeMismatch unless `inner` is exactly
+///   count quadlets long
+pub fn validate_attachment_frame(group: &BaseCounter, inner: &[u8]) -> Result<(), MatterError> {
+    let expected = group.count() as usize * 4;
+    if inner.len() != expected {
+        return Err(MatterError::FrameSizeMismatch {
+            expected,
+            actual: inner.len(),
+        });
+    }
+
+    Ok(())
+}
+
+/// A top level item of a text domain CESR stream
+#[derive(Debug)]
+pub enum Token {
+    /// Serialized message body sized by its version string
+    Message(Vec<u8>),
+    /// Count code framing the tokens that follow it
+    Counter(BaseCounter),
+    /// Indexed signature of a controller or witness indexed signatures group
+    Indexed(Siger),
+    /// Any other primitive
+    Primitive(BaseMatter),
+}
+
+/// Parses the whole of `buf` into tokens, requiring every byte to be consumed
+///
+/// Messages are sized by their version string. Indexed signatures are parsed as
+/// such when they follow a `-A` or `-B` counter or end a `-D` transferable receipt
+/// quadruple of prefix, sn and digest; every other text domain item is parsed as
+/// a counter or a primitive.
+///
+/// # Parameters
+/// * `buf` - Complete stream bytes
+///
+/// # Returns
+/// * `Result<Vec<Token>, MatterError>` - Tokens in stream order, ShortageError if
+///   `buf` ends partway through an item, or ResidualBytes with the offset of the
+///   first byte that does not start a recognizable token
+pub fn parse_all(buf: &[u8]) -> Result<Vec<Token>, MatterError> {
+    let mut ims = buf.to_vec();
+    let mut tokens = Vec::new();
+    while !ims.is_empty() {
+        let at = buf.len() - ims.len();
+        let token = next_token(&mut ims).map_err(|e| residual(e, at))?;
+        if let Token::Counter(counter) = &token {
+            let code = counter.code();
+            // Leading primitives of each group before its indexed signature
+            let lead = if code == ctr_dex_1_0::CONTROLLER_IDX_SIGS
+                || code == ctr_dex_1_0::WITNESS_IDX_SIGS
+            {
+                Some(0)
+            } else if code == ctr_dex_1_0::TRANS_RECEIPT_QUADRUPLES {
+                Some(3)
+            } else {
+                None
+            };
+            if let Some(lead) = lead {
+                let count = counter.count();
+                tokens.push(token);
+                for _ in 0..count {
+                    for _ in 0..lead {
+                        let at = buf.len() - ims.len();
+                        let matter = BaseMatter::from_qb64b(&mut ims, Some(true))
+                            .map_err(|e| residual(e, at))?;
+                        tokens.push(Token::Primitive(matter));
+                    }
+                    let at = buf.len() - ims.len();
+                    let siger =
+                        Siger::from_qb64b(&mut ims, Some(true)).map_err(|e| residual(e, at))?;
+                    tokens.push(Token::Indexed(siger));
+                }
+                continue;
+            }
+        }
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+/// Keeps a shortage as is and reports any other parse failure as residual bytes at `at`
+fn residual(err: MatterError, at: usize) -> MatterError {
+    match err {
+        MatterError::ShortageError { .. } | MatterError::Shortage(_) => err,
+        _ => MatterError::ResidualBytes { at },
+    }
+}
+
+/// Parses and strips the next message, counter or primitive from the front of `ims`
+fn next_token(ims: &mut Vec<u8>) -> Result<Token, MatterError> {
+    if sniff(ims)? == COLDS.msg && ims.len() >= SMELLSIZE {
+        if let Ok(smellage) = smell(&ims[..SMELLSIZE]) {
+            if smellage.size > ims.len() {
+                return Err(MatterError::ShortageError {
+                    msg: format!("Need {} message bytes, got {}.", smellage.size, ims.len()),
+                    needed: smellage.size - ims.len(),
+                });
+            }
+            return Ok(Token::Message(ims.drain(..smellage.size).collect()));
+        }
+    }
+
+    if ims[0] == b'-' {
+        Ok(Token::Counter(BaseCounter::from_qb64b(ims, Some(true))?))
+    } else {
+        Ok(Token::Primitive(BaseMatter::from_qb64b(ims, Some(true))?))
+    }
+}
+
+/// Default bound on the bytes a `StreamVerifier` will buffer, body and attachments
+pub const STREAM_VERIFIER_MAX_SIZE: usize = 0x100000;
+
+/// Verifies the indexed signatures attached to a message as it arrives in chunks
+///
+/// The body size is read from the version string as soon as enough bytes have been
+/// fed so every later byte is split off as attachment material without rescanning.
+/// Ed25519 and the ECDSA suites sign the raw serialization rather than a prehash so
+/// the body itself is retained until `finalize`; only the trailing attachments are
+/// parsed incrementally. Buffering is bounded by `max_size` so a hostile stream is
+/// rejected as soon as its declared body size or fed bytes exceed the bound.
+#[derive(Debug)]
+pub struct StreamVerifier {
+    size: Option<usize>,
+    max_size: usize,
+    body: Vec<u8>,
+    atc: Vec<u8>,
+}
+
+impl Default for StreamVerifier {
+    fn default() -> Self {
+        Self::with_max_size(STREAM_VERIFIER_MAX_SIZE)
+    }
+}
+
+impl StreamVerifier {
+    /// Creates an empty verifier awaiting the first chunk
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty verifier that buffers at most `max_size` bytes
+    pub fn with_max_size(max_size: usize) -> Self {
+        Self {
+            size: None,
+            max_size,
+            body: Vec::new(),
+            atc: Vec::new(),
+        }
+    }
+
+    /// Feeds the next chunk of the message stream
+    ///
+    /// # Parameters
+    /// * `chunk` - Next bytes of the message, body first then attachments
+    ///
+    /// # Returns
+    /// * `Result<(), MatterError>` - DeserializationError once `SMELLSIZE` bytes are
+    ///   fed without a valid version string, OverflowError if the declared body size
+    ///   or the bytes fed exceed `max_size`. The verifier should be discarded after
+    ///   an error.
+    pub fn update(&mut self, chunk: &[u8]) -> Result<(), MatterError> {
+        let fed = self.body.len() + self.atc.len() + chunk.len();
+        if fed > self.max_size {
+            return Err(MatterError::OverflowError(format!(
+                "Stream of {} bytes exceeds max size {}.",
+                fed, self.max_size
+            )));
+        }
+
+        let mut chunk = chunk;
+        if self.size.is_none() {
+            self.body.extend_from_slice(chunk);
+            chunk = &[];
+            if self.body.len() >= SMELLSIZE {
+                let smellage = smell(&self.body[..SMELLSIZE])
+                    .map_err(|e| MatterError::DeserializationError(e.to_string()))?;
+                if smellage.size > self.max_size {
+                    return Err(MatterError::OverflowError(format!(
+                        "Body size {} exceeds max size {}.",
+                        smellage.size, self.max_size
+                    )));
+                }
+                self.size = Some(smellage.size);
+            }
+            if let Some(size) = self.size {
+                if self.body.len() > size {
+                    self.atc.extend_from_slice(&self.body[size..]);
+                    self.body.truncate(size);
+                }
+            }
+        }
+
+        match self.size {
+            Some(size) if self.body.len() < size => {
+                let need = (size - self.body.len()).min(chunk.len());
+                self.body.extend_from_slice(&chunk[..need]);
+                self.atc.extend_from_slice(&chunk[need..]);
+            }
+            _ => self.atc.extend_from_slice(chunk),
+        }
+
+        Ok(())
+    }
+
+    /// Verifies the trailing controller indexed signatures against the fed body
+    ///
+    /// # Parameters
+    /// * `verfers` - Current signing keys indexed by each signature's index
+    ///
+    /// # Returns
+    /// * `Result<bool, MatterError>` - True when at least one signature is attached
+    ///   and every signature verifies against its indexed key, ShortageError if the
+    ///   body or the signature group is incomplete
+    pub fn finalize(self, verfers: &[Verfer]) -> Result<bool, MatterError> {
+        let size = self.size.ok_or_else(|| MatterError::ShortageError {
+            msg: "Need more bytes to read body size.".to_string(),
+            needed: 1,
+        })?;
+        if self.body.len() < size {
+            return Err(MatterError::ShortageError {
+                msg: format!("Need {} body bytes, got {}.", size, self.body.len()),
+                needed: size - self.body.len(),
+            });
+        }
+
+        let mut atc = self.atc;
+        let counter = BaseCounter::from_qb64b(&mut atc, Some(true))?;
+        if counter.code() != ctr_dex_1_0::CONTROLLER_IDX_SIGS {
+            return Err(MatterError::UnexpectedCountCodeError(format!(
+                "Expected controller indexed signatures counter got {}.",
+                counter.code()
+            )));
+        }
+        let sigers = parse_indexed_sigs(&mut atc, counter.count())?;
+        if sigers.is_empty() {
+            return Ok(false);
+        }
+
+        for siger in sigers.iter() {
+            let verfer = match verfers.get(siger.index() as usize) {
+                Some(verfer) => verfer,
+                None => return Ok(false),
+            };
+            if !verfer.verify(siger.raw(), &self.body)? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cesr::diger::Diger;
+    use crate::cesr::seqner::Seqner;
+    use crate::cesr::signing::{Salter, Sigmat};
+    use crate::Matter;
+
+    #[test]
+    fn test_parse_indexed_sigs() -> Result<(), MatterError> {
+        let salter = Salter::new(Some(b"0123456789abcdef"), None, None)?;
+        let signers = salter.signers(2, 0, "", None, None, None, false)?;
+        let ser = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+        let mut sigers = Vec::new();
+        for (i, signer) in signers.iter().enumerate() {
+            match signer.sign(ser, Some(i as u32), None, None)? {
+                Sigmat::Indexed(siger) => sigers.push(siger),
+                _ => panic!("Expected indexed signature"),
+            }
+        }
+
+        let counter = BaseCounter::from_code_and_count(
+            Some(ctr_dex_1_0::CONTROLLER_IDX_SIGS),
+            Some(sigers.len() as u64),
+            None,
+        )?;
+        assert_eq!(counter.qb64(), "-AAC");
+
+        let mut buf = counter.qb64b();
+        for siger in sigers.iter() {
+            buf.extend_from_slice(&siger.qb64b());
+        }
+        buf.extend_from_slice(b"-AAB");
+
+        let counter = BaseCounter::from_qb64b(&mut buf, Some(true))?;
+        let parsed = parse_indexed_sigs(&mut buf, counter.count())?;
+
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].qb64(), sigers[0].qb64());
+        assert_eq!(parsed[1].qb64(), sigers[1].qb64());
+        assert_eq!(parsed[1].index(), 1);
+        assert_eq!(buf, b"-AAB".to_vec());
+
+        // Shortage when the group is truncated
+        let mut buf = sigers[0].qb64b();
+        let result = parse_indexed_sigs(&mut buf, 2);
+        assert!(matches!(result, Err(MatterError::ShortageError { .. })));
+
+        // A huge count from a hostile counter does not preallocate for it
+        let mut buf = sigers[0].qb64b();
+        let result = parse_indexed_sigs(&mut buf, u64::MAX);
+        assert!(matches!(result, Err(MatterError::ShortageError { .. })));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_take_group() -> Result<(), MatterError> {
+        let payload = b"abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ01";
+        assert_eq!(payload.len() % 4, 0);
+
+        let counter = BaseCounter::from_code_and_count(
+            Some(ctr_dex_1_0::ATTACHMENT_GROUP),
+            Some(payload.len() as u64 / 4),
+            None,
+        )?;
+        assert_eq!(counter.qb64(), "-VAQ");
+
+        let mut buf = counter.qb64b();
+        buf.extend_from_slice(payload);
+        buf.extend_from_slice(b"-AAB");
+
+        let (group, taken) = take_group(&mut buf)?;
+        assert_eq!(group.code(), ctr_dex_1_0::ATTACHMENT_GROUP);
+        assert_eq!(group.count(), 16);
+        assert_eq!(taken, payload.to_vec());
+        assert_eq!(buf, b"-AAB".to_vec());
+
+        // Shortage when the payload is truncated leaves the buffer intact
+        let mut buf = counter.qb64b();
+        buf.extend_from_slice(&payload[..40]);
+        let before = buf.clone();
+        let result = take_group(&mut buf);
+        assert!(matches!(result, Err(MatterError::ShortageError { .. })));
+        assert_eq!(buf, before);
+
+        // Counters that do not frame a quadlet group are rejected
+        let mut buf = b"-AAB".to_vec();
+        buf.extend_from_slice(&payload[..4]);
+        let before = buf.clone();
+        let result = take_group(&mut buf);
+        assert!(matches!(
+            result,
+            Err(MatterError::UnexpectedCountCodeError(_))
+        ));
+        assert_eq!(buf, before);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_attachment_frame() -> Result<(), MatterError> {
+        let inner = b"abcdefghijklmnopqrstuvwxyz0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ01";
+        let group = BaseCounter::from_code_and_count(
+            Some(ctr_dex_1_0::ATTACHMENT_GROUP),
+            Some(16),
+            None,
+        )?;
+        validate_attachment_frame(&group, inner)?;
+
+        // Truncated
+        let result = validate_attachment_frame(&group, &inner[..60]);
+        assert!(matches!(
+            result,
+            Err(MatterError::FrameSizeMismatch {
+                expected: 64,
+                actual: 60
+            })
+        ));
+
+        // Padded
+        let mut padded = inner.to_vec();
+        padded.extend_from_slice(b"AAAA");
+        let result = validate_attachment_frame(&group, &padded);
+        assert!(matches!(
+            result,
+            Err(MatterError::FrameSizeMismatch {
+                expected: 64,
+                actual: 68
+            })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_verifier() -> Result<(), MatterError> {
+        let salter = Salter::new(Some(b"0123456789abcdef"), None, None)?;
+        let signers = salter.signers(2, 0, "", None, None, None, false)?;
+        let verfers: Vec<Verfer> = signers.iter().map(|s| s.verfer().clone()).collect();
+        let body = br#"{"v":"KERI10JSON00006b_","t":"rpy","r":"/essr/payload","a":{"data":"0123456789abcdefghijklmnopqrstuvwxyz"}}"#;
+        assert_eq!(body.len(), 0x6b);
+
+        let mut msg = body.to_vec();
+        msg.extend_from_slice(b"-AAC");
+        for (i, signer) in signers.iter().enumerate() {
+            match signer.sign(body, Some(i as u32), None, None)? {
+                Sigmat::Indexed(siger) => msg.extend_from_slice(&siger.qb64b()),
+                _ => panic!("Expected indexed signature"),
+            }
+        }
+
+        // Chunk boundaries fall inside the version string span and inside the attachments
+        let mut verifier = StreamVerifier::new();
+        verifier.update(&msg[..10])?;
+        verifier.update(&msg[10..120])?;
+        verifier.update(&msg[120..])?;
+        assert!(verifier.finalize(&verfers)?);
+
+        // Tampered body byte
+        let mut tampered = msg.clone();
+        tampered[80] = b'X';
+        let mut verifier = StreamVerifier::new();
+        verifier.update(&tampered[..10])?;
+        verifier.update(&tampered[10..120])?;
+        verifier.update(&tampered[120..])?;
+        assert!(!verifier.finalize(&verfers)?);
+
+        // Signature index past the supplied keys
+        let mut verifier = StreamVerifier::new();
+        verifier.update(&msg)?;
+        assert!(!verifier.finalize(&verfers[..1])?);
+
+        // Truncated body
+        let mut verifier = StreamVerifier::new();
+        verifier.update(&msg[..60])?;
+        assert!(matches!(
+            verifier.finalize(&verfers),
+            Err(MatterError::ShortageError { .. })
+        ));
+
+        // A bad version string fails as soon as enough bytes are fed to smell it
+        let mut garbled = msg.clone();
+        garbled[6..10].copy_from_slice(b"keri");
+        let mut verifier = StreamVerifier::new();
+        verifier.update(&garbled[..10])?;
+        assert!(matches!(
+            verifier.update(&garbled[10..SMELLSIZE]),
+            Err(MatterError::DeserializationError(_))
+        ));
+
+        // Declared body size past the bound
+        let mut verifier = StreamVerifier::with_max_size(0x6a);
+        assert!(matches!(
+            verifier.update(&msg[..SMELLSIZE]),
+            Err(MatterError::OverflowError(_))
+        ));
+
+        // Attachments fed past the bound
+        let mut verifier = StreamVerifier::with_max_size(msg.len() - 1);
+        verifier.update(&msg[..120])?;
+        assert!(matches!(
+            verifier.update(&msg[120..]),
+            Err(MatterError::OverflowError(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_all() -> Result<(), MatterError> {
+        let salter = Salter::new(Some(b"0123456789abcdef"), None, None)?;
+        let signers = salter.signers(2, 0, "", None, None, None, false)?;
+        let body = br#"{"v":"KERI10JSON00006b_","t":"rpy","r":"/essr/payload","a":{"data":"0123456789abcdefghijklmnopqrstuvwxyz"}}"#;
+
+        let mut msg = body.to_vec();
+        msg.extend_from_slice(b"-AAC");
+        for (i, signer) in signers.iter().enumerate() {
+            match signer.sign(body, Some(i as u32), None, None)? {
+                Sigmat::Indexed(siger) => msg.extend_from_slice(&siger.qb64b()),
+                _ => panic!("Expected indexed signature"),
+            }
+        }
+
+        // Two messages back to back are consumed completely
+        let stream = [msg.clone(), msg.clone()].concat();
+        let tokens = parse_all(&stream)?;
+        assert_eq!(tokens.len(), 8);
+        assert!(matches!(&tokens[0], Token::Message(raw) if raw == body));
+        assert!(matches!(&tokens[1], Token::Counter(c) if c.count() == 2));
+        assert!(matches!(&tokens[3], Token::Indexed(siger) if siger.index() == 1));
+        assert!(matches!(&tokens[4], Token::Message(_)));
+
+        // Trailing garbage is reported at its offset
+        let mut garbage = msg.clone();
+        garbage.extend_from_slice(b"####");
+        let result = parse_all(&garbage);
+        assert!(matches!(result, Err(MatterError::ResidualBytes { at }) if at == msg.len()));
+
+        // Truncated signature is a shortage rather than residual bytes
+        let result = parse_all(&msg[..msg.len() - 10]);
+        assert!(matches!(
+            result,
+            Err(MatterError::ShortageError { .. }) | Err(MatterError::Shortage(_))
+        ));
+
+        // Transferable receipt quadruple ends in an indexed signature
+        let mut quad = body.to_vec();
+        quad.extend_from_slice(b"-DAB");
+        quad.extend_from_slice(&signers[0].verfer().qb64b());
+        quad.extend_from_slice(&Seqner::from_sn(0).qb64b());
+        quad.extend_from_slice(&Diger::from_ser(body, None)?.qb64b());
+        match signers[0].sign(body, Some(0), None, None)? {
+            Sigmat::Indexed(siger) => quad.extend_from_slice(&siger.qb64b()),
+            _ => panic!("Expected indexed signature"),
+        }
+        let tokens = parse_all(&quad)?;
+        assert_eq!(tokens.len(), 6);
+        assert!(matches!(&tokens[1], Token::Counter(c) if c.count() == 1));
+        assert!(matches!(&tokens[2], Token::Primitive(_)));
+        assert!(matches!(&tokens[4], Token::Primitive(_)));
+        assert!(matches!(&tokens[5], Token::Indexed(siger) if siger.index() == 0));
+
+        Ok(())
+    }
+}
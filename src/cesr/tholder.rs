@@ -389,6 +389,11 @@ impl Tholder {
 
     /// Evaluates if the provided verified signature indices satisfy the threshold
     ///
+    /// A weighted threshold with several clause groups such as
+    /// `[["1/2","1/2"],["1","1"]]` is satisfied only when every group is. Indices
+    /// run across the groups in order so each group sums the weights of its own
+    /// positions and must reach at least one.
+    ///
     /// # Arguments
     ///
     /// * `indices` - List of indices of verified signatures
@@ -487,6 +492,114 @@ impl Tholder {
         }
     }
 
+    /// Returns how many more distinct verified signature indices are needed to
+    /// satisfy the threshold
+    ///
+    /// Indices at or beyond the threshold size are ignored. For weighted
+    /// thresholds each clause is filled greedily from its heaviest missing
+    /// weights until it sums to at least one, and the counts are added up.
+    ///
+    /// # Arguments
+    ///
+    /// * `have_indices` - List of indices of signatures already verified
+    ///
+    /// # Returns
+    ///
+    /// Number of additional signatures needed, 0 if already satisfied
+    pub fn remaining(&self, have_indices: &[usize]) -> usize {
+        let mut have: Vec<usize> = have_indices
+            .iter()
+            .cloned()
+            .filter(|idx| *idx < self._size)
+            .collect();
+        have.sort_unstable();
+        have.dedup();
+
+        let clauses = match self._thold {
+            TholderThold::Integer(num) => return num.saturating_sub(have.len()),
+            TholderThold::Weighted(ref clauses) => clauses,
+        };
+
+        let mut needed = 0;
+        let mut wio = 0; // weight index offset
+        for clause in clauses {
+            let mut cw = Rational32::new(0, 1); // clause weight
+            let mut missing: Vec<(Rational32, usize)> = Vec::new(); // (weight, signatures)
+
+            for element in clause {
+                match element {
+                    WeightSpec::Simple(weight) => {
+                        if have.contains(&wio) {
+                            cw += *weight;
+                        } else {
+                            missing.push((*weight, 1));
+                        }
+                        wio += 1;
+                    }
+                    WeightSpec::WeightedMap(key_weight, nested_weights) => {
+                        let nested: Vec<(Rational32, bool)> = nested_weights
+                            .iter()
+                            .enumerate()
+                            .map(|(i, w)| (*w, have.contains(&(wio + i))))
+                            .collect();
+                        wio += nested_weights.len();
+                        match Self::greedy_fill(&nested) {
+                            0 => cw += *key_weight,
+                            count => missing.push((*key_weight, count)),
+                        }
+                    }
+                    WeightSpec::WeightedVec(nested_specs) => {
+                        let mut nested = Vec::new();
+                        for nested_spec in nested_specs {
+                            if let WeightSpec::Simple(weight) = nested_spec {
+                                nested.push((*weight, have.contains(&wio)));
+                                wio += 1;
+                            }
+                        }
+                        match Self::greedy_fill(&nested) {
+                            0 => cw += Rational32::new(1, 1),
+                            count => missing.push((Rational32::new(1, 1), count)),
+                        }
+                    }
+                }
+            }
+
+            // Heaviest weights first, cheaper ones first among equal weights
+            missing.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+            for (weight, count) in missing {
+                if cw >= Rational32::new(1, 1) {
+                    break;
+                }
+                cw += weight;
+                needed += count;
+            }
+        }
+
+        needed
+    }
+
+    /// Returns how many of the unverified `weights` are needed, heaviest first,
+    /// for the group to sum to at least one
+    fn greedy_fill(weights: &[(Rational32, bool)]) -> usize {
+        let mut sum: Rational32 = weights.iter().filter(|(_, have)| *have).map(|(w, _)| *w).sum();
+        let mut missing: Vec<Rational32> = weights
+            .iter()
+            .filter(|(_, have)| !*have)
+            .map(|(w, _)| *w)
+            .collect();
+        missing.sort_unstable_by(|a, b| b.cmp(a));
+
+        let mut count = 0;
+        for weight in missing {
+            if sum >= Rational32::new(1, 1) {
+                break;
+            }
+            sum += weight;
+            count += 1;
+        }
+        count
+    }
+
     /// Returns whether the threshold is weighted or not
     pub fn weighted(&self) -> bool {
         self._weighted
@@ -2004,6 +2117,28 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_tholder_satisfy_clause_groups() -> Result<(), Box<dyn std::error::Error>> {
+        let json_str = r#"[["1/2", "1/2"], ["1", "1"]]"#;
+        let tholder = Tholder::new(None, None, Some(TholderSith::Json(json_str.to_string())))?;
+        assert!(tholder.weighted());
+        assert_eq!(tholder.size(), 4);
+
+        // Only the first group
+        assert!(!tholder.satisfy(&[0, 1]));
+        // Only the second group
+        assert!(!tholder.satisfy(&[2, 3]));
+        // Half of the first group and all of the second
+        assert!(!tholder.satisfy(&[0, 2, 3]));
+
+        // Both groups
+        assert!(tholder.satisfy(&[0, 1, 2]));
+        assert!(tholder.satisfy(&[0, 1, 3]));
+        assert!(tholder.satisfy(&[0, 1, 2, 3]));
+
+        Ok(())
+    }
+
     #[test]
     fn test_tholder_json_string_multi_clause() -> Result<(), Box<dyn std::error::Error>> {
         // Test Tholder(sith='[["1/2", "1/2", "1/4", "1/4", "1/4"], ["1/1", "1"]]')
@@ -2841,4 +2976,69 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_tholder_remaining() -> Result<(), Box<dyn std::error::Error>> {
+        // Integer 2 of 3
+        let mut tholder = Tholder::default();
+        tholder.process_sith(TholderSith::Integer(2))?;
+        assert_eq!(tholder.remaining(&[]), 2);
+        assert_eq!(tholder.remaining(&[1]), 1);
+        assert_eq!(tholder.remaining(&[1, 1]), 1);
+        assert_eq!(tholder.remaining(&[0, 1]), 0);
+        // Indices beyond the threshold size do not count
+        assert_eq!(tholder.remaining(&[0, 2]), 1);
+        assert_eq!(tholder.remaining(&[5, 7]), 2);
+
+        // Weighted single clause ["1/2", "1/2", "1/4", "1/4", "1/4"]
+        let weights = ["1/2", "1/2", "1/4", "1/4", "1/4"]
+            .iter()
+            .map(|w| Ok(WeightSpec::Simple(Tholder::weight(w)?)))
+            .collect::<Result<Vec<_>, MatterError>>()?;
+        let tholder = Tholder::new(Some(TholderThold::Weighted(vec![weights])), None, None)?;
+        assert_eq!(tholder.remaining(&[]), 2);
+        assert_eq!(tholder.remaining(&[2]), 2);
+        assert_eq!(tholder.remaining(&[0, 2]), 1);
+        assert_eq!(tholder.remaining(&[0, 1]), 0);
+        assert_eq!(tholder.remaining(&[5, 9]), 2);
+        assert_eq!(tholder.remaining(&[0, 5]), 1);
+
+        // Weighted multi clause [["1/2", "1/2"], ["1", "1"]]
+        let clauses = vec![
+            vec![
+                WeightSpec::Simple(Rational32::new(1, 2)),
+                WeightSpec::Simple(Rational32::new(1, 2)),
+            ],
+            vec![
+                WeightSpec::Simple(Rational32::new(1, 1)),
+                WeightSpec::Simple(Rational32::new(1, 1)),
+            ],
+        ];
+        let tholder = Tholder::new(Some(TholderThold::Weighted(clauses)), None, None)?;
+        assert_eq!(tholder.remaining(&[]), 3);
+        assert_eq!(tholder.remaining(&[3]), 2);
+        assert_eq!(tholder.remaining(&[0, 1, 2]), 0);
+        assert_eq!(tholder.remaining(&[0, 4]), 2);
+
+        // Weighted multi clause [["1/3", "1/3", "1/3"], ["1/2", "1/4", "1/4", "1/2"]]
+        let clauses = vec![
+            ["1/3", "1/3", "1/3"]
+                .iter()
+                .map(|w| Ok(WeightSpec::Simple(Tholder::weight(w)?)))
+                .collect::<Result<Vec<_>, MatterError>>()?,
+            ["1/2", "1/4", "1/4", "1/2"]
+                .iter()
+                .map(|w| Ok(WeightSpec::Simple(Tholder::weight(w)?)))
+                .collect::<Result<Vec<_>, MatterError>>()?,
+        ];
+        let tholder = Tholder::new(Some(TholderThold::Weighted(clauses)), None, None)?;
+        assert_eq!(tholder.remaining(&[]), 5);
+        assert_eq!(tholder.remaining(&[0]), 4);
+        assert_eq!(tholder.remaining(&[4, 5]), 4);
+        assert_eq!(tholder.remaining(&[0, 1, 2, 3]), 1);
+        assert_eq!(tholder.remaining(&[0, 1, 2, 3, 6]), 0);
+        assert!(tholder.satisfy(&[0, 1, 2, 3, 6]));
+
+        Ok(())
+    }
 }
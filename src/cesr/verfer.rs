@@ -491,4 +491,53 @@ mod tests {
         let result = verfer.verify(&der_sig, wrong_message);
         assert!(result.is_err() || !result.unwrap());
     }
+
+    #[test]
+    fn test_verfer_ed25519_vector() {
+        sodiumoxide::init().expect("Sodium initialization failed");
+
+        // RFC 8032 section 7.1 test 1: signature over the empty message
+        let seed = [
+            0x9d, 0x61, 0xb1, 0x9d, 0xef, 0xfd, 0x5a, 0x60, 0xba, 0x84, 0x4a, 0xf4, 0x92, 0xec,
+            0x2c, 0xc4, 0x44, 0x49, 0xc5, 0x69, 0x7b, 0x32, 0x69, 0x19, 0x70, 0x3b, 0xac, 0x03,
+            0x1c, 0xae, 0x7f, 0x60,
+        ];
+        let sig = [
+            0xe5, 0x56, 0x43, 0x00, 0xc3, 0x60, 0xac, 0x72, 0x90, 0x86, 0xe2, 0xcc, 0x80, 0x6e,
+            0x82, 0x8a, 0x84, 0x87, 0x7f, 0x1e, 0xb8, 0xe5, 0xd9, 0x74, 0xd8, 0x73, 0xe0, 0x65,
+            0x22, 0x49, 0x01, 0x55, 0x5f, 0xb8, 0x82, 0x15, 0x90, 0xa3, 0x3b, 0xac, 0xc6, 0x1e,
+            0x39, 0x70, 0x1c, 0xf9, 0xb4, 0x6b, 0xd2, 0x5b, 0xf5, 0xf0, 0x59, 0x5b, 0xbe, 0x24,
+            0x65, 0x51, 0x41, 0x43, 0x8e, 0x7a, 0x10, 0x0b,
+        ];
+
+        let seed = ed25519::Seed::from_slice(&seed).unwrap();
+        let (public_key, secret_key) = ed25519::keypair_from_seed(&seed);
+        let verfer = Verfer::new(Some(public_key.as_ref()), Some(mtr_dex::ED25519)).unwrap();
+        assert_eq!(
+            verfer.qb64(),
+            "DNdamAGCsQq31Uv-08lkBzoO4XLz2qYjJa8CGmj3B1Ea"
+        );
+
+        // Parsing the qb64 prefix yields the same key
+        let verfer = Verfer::from_qb64("DNdamAGCsQq31Uv-08lkBzoO4XLz2qYjJa8CGmj3B1Ea").unwrap();
+        assert_eq!(verfer.raw(), public_key.as_ref());
+        assert!(verfer.verify(&sig, b"").unwrap());
+        assert_eq!(ed25519::sign_detached(b"", &secret_key).as_ref(), &sig[..]);
+        assert!(!verfer.verify(&sig, b"x").unwrap());
+
+        // Wrong length signature
+        assert!(matches!(
+            verfer.verify(&sig[..63], b""),
+            Err(MatterError::InvalidSignatureLength {
+                expected: 64,
+                actual: 63
+            })
+        ));
+
+        // Short raw key material
+        assert!(matches!(
+            Verfer::new(Some(&public_key.as_ref()[..31]), Some(mtr_dex::ED25519)),
+            Err(MatterError::RawMaterial(_))
+        ));
+    }
 }
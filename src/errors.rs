@@ -51,8 +51,12 @@ pub enum MatterError {
     #[error("Invalid raw size")]
     InvalidRawSize,
 
-    #[error("Shortage: {0}")]
-    ShortageError(String),
+    #[error("Shortage: {msg}")]
+    ShortageError {
+        msg: String,
+        /// Minimum number of further bytes or characters needed to make progress
+        needed: usize,
+    },
 
     #[error("Unexpected count code: {0}")]
     UnexpectedCountCodeError(String),
@@ -188,4 +192,10 @@ pub enum MatterError {
 
     #[error("Decoding error: {0}")]
     DecodingError(String),
+
+    #[error("Frame size mismatch: expected {expected} bytes, got {actual}")]
+    FrameSizeMismatch { expected: usize, actual: usize },
+
+    #[error("Residual bytes: unparsed stream bytes at offset {at}")]
+    ResidualBytes { at: usize },
 }
@@ -0,0 +1,209 @@
+use crate::cesr::saider::Saider;
+use crate::keri::core::serdering::{Rawifiable, SadValue, Sadder, Serder, SerderACDC};
+use crate::keri::{KERIError, Kinds};
+use indexmap::IndexMap;
+
+/// Creder is an ACDC credential whose top level SAID and schema SAID have been
+/// verified
+///
+/// The schema section `s` may be either the compact schema SAID or the expanded
+/// schema block in which case the block's `$id` SAID is verified against it.
+#[derive(Debug, Clone)]
+pub struct Creder {
+    serder: SerderACDC,
+    said: String,
+}
+
+impl Creder {
+    /// Parse a JSON serialized ACDC and verify its SAIDs
+    ///
+    /// # Parameters
+    /// * `raw` - JSON serialization of the ACDC with version string
+    ///
+    /// # Returns
+    /// * `Result<Creder, KERIError>` - Verified credential or ValidationError if the
+    ///   top level SAID `d` or the schema SAID `s` does not match
+    pub fn from_json(raw: &[u8]) -> Result<Self, KERIError> {
+        let serder = SerderACDC::from_raw(raw, None)?;
+        if serder.proto() != "ACDC" {
+            return Err(KERIError::ProtocolError(format!(
+                "Expected ACDC protocol got {}.",
+                serder.proto()
+            )));
+        }
+        if *serder.kind() != Kinds::Json {
+            return Err(KERIError::KindError(format!(
+                "Expected JSON serialization got {:?}.",
+                serder.kind()
+            )));
+        }
+
+        let sad = serder.sad();
+        if serder.issuer().is_none() {
+            return Err(KERIError::FieldError(
+                "Missing issuer (i) in ACDC.".to_string(),
+            ));
+        }
+
+        // ACDCs carry no ilk so the serder does not pick up `d` as its primary SAID
+        let said = sad
+            .get("d")
+            .and_then(|d| d.as_str())
+            .map(|d| d.to_string())
+            .ok_or_else(|| KERIError::FieldError("Missing SAID (d) in ACDC.".to_string()))?;
+        let saider = Saider::from_qb64(&said)?;
+        if !saider.verify(&sad, true, true, Some(&Kinds::Json), "d", None) {
+            return Err(KERIError::ValidationError(format!(
+                "Invalid SAID d={} for ACDC.",
+                said
+            )));
+        }
+
+        match sad.get("s") {
+            Some(SadValue::String(said)) => {
+                Saider::from_qb64(said)?;
+            }
+            Some(SadValue::Object(block)) => {
+                Self::verify_schema_block(block)?;
+            }
+            _ => {
+                return Err(KERIError::FieldError(
+                    "Missing schema (s) in ACDC.".to_string(),
+                ))
+            }
+        }
+
+        Ok(Self { serder, said })
+    }
+
+    /// Verify the `$id` SAID of an expanded schema block
+    fn verify_schema_block(block: &Sadder) -> Result<(), KERIError> {
+        let said = block
+            .get("$id")
+            .and_then(|id| id.as_str())
+            .ok_or_else(|| KERIError::FieldError("Missing $id in ACDC schema.".to_string()))?;
+        let saider = Saider::from_qb64(said)?;
+        if !saider.verify(block, true, false, Some(&Kinds::Json), "$id", None) {
+            return Err(KERIError::ValidationError(format!(
+                "Invalid schema SAID $id={} for ACDC.",
+                said
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Underlying ACDC serder
+    pub fn serder(&self) -> &SerderACDC {
+        &self.serder
+    }
+
+    /// Top level SAID of the credential
+    pub fn said(&self) -> &str {
+        &self.said
+    }
+
+    /// qb64 of the issuer AID from `i`
+    pub fn issuer(&self) -> String {
+        self.serder.issuer().unwrap_or_default()
+    }
+
+    /// Schema SAID from `s` or from the `$id` of an expanded schema block
+    pub fn schema(&self) -> String {
+        match self.serder.sad().get("s") {
+            Some(SadValue::String(said)) => said.clone(),
+            Some(SadValue::Object(block)) => block
+                .get("$id")
+                .and_then(|id| id.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            _ => String::new(),
+        }
+    }
+
+    /// Expanded attribute block from `a` if present
+    pub fn attributes(&self) -> Option<IndexMap<String, SadValue>> {
+        self.serder.attrib()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indexmap::indexmap;
+
+    const ISSUER: &str = "EBG9LuUbFzV4OV5cGS9IeQWzy9SuyVFyVrpRc4l1xzPA";
+
+    fn schema() -> Result<Sadder, KERIError> {
+        let sad: Sadder = indexmap!(
+            "$id".to_string() => SadValue::String("".to_string()),
+            "$schema".to_string() => SadValue::String("http://json-schema.org/draft-07/schema#".to_string()),
+            "type".to_string() => SadValue::String("object".to_string()),
+        );
+        let (_, sad) = Saider::saidify(sad, None, Some(&Kinds::Json), Some("$id".to_string()), None)?;
+        Ok(sad)
+    }
+
+    fn acdc(schema: SadValue) -> Result<Sadder, KERIError> {
+        let attrs: Sadder = indexmap!(
+            "d".to_string() => SadValue::String("".to_string()),
+            "name".to_string() => SadValue::String("Jane".to_string()),
+        );
+        let (_, attrs) = Saider::saidify(attrs, None, None, None, None)?;
+
+        let sad: Sadder = indexmap!(
+            "v".to_string() => SadValue::String("ACDC10JSON000000_".to_string()),
+            "d".to_string() => SadValue::String("".to_string()),
+            "i".to_string() => SadValue::String(ISSUER.to_string()),
+            "s".to_string() => schema,
+            "a".to_string() => SadValue::Object(attrs),
+        );
+        let (_, sad) = Saider::saidify(sad, None, None, None, None)?;
+        Ok(sad)
+    }
+
+    #[test]
+    fn test_creder_from_json() -> Result<(), KERIError> {
+        let schema = schema()?;
+        let schema_said = schema["$id"].as_str().unwrap().to_string();
+
+        // Expanded schema block
+        let sad = acdc(SadValue::Object(schema.clone()))?;
+        let raw = SadValue::dumps(&sad, &Kinds::Json)?;
+        let creder = Creder::from_json(&raw)?;
+        assert_eq!(creder.said(), sad["d"].as_str().unwrap());
+        assert_eq!(creder.issuer(), ISSUER);
+        assert_eq!(creder.schema(), schema_said);
+        let attrs = creder.attributes().unwrap();
+        assert_eq!(attrs["name"].as_str().unwrap(), "Jane");
+
+        // Compact schema SAID
+        let sad = acdc(SadValue::String(schema_said.clone()))?;
+        let raw = SadValue::dumps(&sad, &Kinds::Json)?;
+        let creder = Creder::from_json(&raw)?;
+        assert_eq!(creder.schema(), schema_said);
+
+        // Tampered attributes break the top level SAID
+        let mut tampered = acdc(SadValue::Object(schema.clone()))?;
+        let mut attrs = creder.attributes().unwrap();
+        attrs.insert("name".to_string(), SadValue::String("Mary".to_string()));
+        tampered.insert("a".to_string(), SadValue::Object(attrs));
+        let raw = SadValue::dumps(&tampered, &Kinds::Json)?;
+        assert!(matches!(
+            Creder::from_json(&raw),
+            Err(KERIError::ValidationError(_))
+        ));
+
+        // Tampered schema block with a valid top level SAID
+        let mut bad_schema = schema.clone();
+        bad_schema.insert("type".to_string(), SadValue::String("array".to_string()));
+        let sad = acdc(SadValue::Object(bad_schema))?;
+        let raw = SadValue::dumps(&sad, &Kinds::Json)?;
+        assert!(matches!(
+            Creder::from_json(&raw),
+            Err(KERIError::ValidationError(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod creder;
+
+pub use creder::*;
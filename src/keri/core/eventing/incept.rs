@@ -1,9 +1,14 @@
+use crate::cesr::indexing::siger::Siger;
+use crate::cesr::mtr_dex;
 use crate::cesr::number::Number;
+use crate::cesr::signing::{Sigmat, Signer};
 use crate::cesr::tholder::{Tholder, TholderSith};
 use crate::cesr::Versionage;
 use crate::keri::core::eventing::{ample, is_digest_code, is_prefix_code, MAX_INT_THOLD};
-use crate::keri::core::serdering::{SadValue, Sadder, SerderKERI};
+use crate::keri::core::serdering::{SadValue, Sadder, Serder, SerderKERI};
+use crate::keri::db::basing::KeyStateRecord;
 use crate::keri::{versify, KERIError};
+use crate::Matter;
 use num_bigint::BigUint;
 use serde_json::Value;
 use std::collections::{HashMap, HashSet};
@@ -416,12 +421,104 @@ impl InceptionEventBuilder {
     }
 }
 
+/// Build the inception event of a multisig group identifier from its members' key states
+///
+/// Each member contributes its first current signing key and its first next key
+/// digest in member order so the same members in the same order always yield the
+/// same group prefix. The group prefix is the self-addressing SAID of the event.
+///
+/// # Parameters
+/// * `member_states` - Key states of the group members in signing index order
+/// * `isith` - Group signing threshold, defaults to a majority of members
+/// * `nsith` - Group next signing threshold, defaults to a majority of members
+/// * `wits` - Group witness prefixes
+/// * `toad` - Group witness threshold, defaults to ample for `wits`
+///
+/// # Returns
+/// * `Result<SerderKERI, KERIError>` - Group inception event, ValueError if there are
+///   no members or a member has no current key or next key digest
+pub fn group_incept(
+    member_states: &[KeyStateRecord],
+    isith: Option<TholderSith>,
+    nsith: Option<TholderSith>,
+    wits: Vec<String>,
+    toad: Option<usize>,
+) -> Result<SerderKERI, KERIError> {
+    if member_states.is_empty() {
+        return Err(KERIError::ValueError(
+            "Group inception requires at least one member.".to_string(),
+        ));
+    }
+
+    let mut keys = Vec::with_capacity(member_states.len());
+    let mut ndigs = Vec::with_capacity(member_states.len());
+    for state in member_states {
+        let key = state.k.first().ok_or_else(|| {
+            KERIError::ValueError(format!("Missing current key for member={}.", state.i))
+        })?;
+        let ndig = state.n.first().ok_or_else(|| {
+            KERIError::ValueError(format!("Missing next key digest for member={}.", state.i))
+        })?;
+        keys.push(key.clone());
+        ndigs.push(ndig.clone());
+    }
+
+    let mut builder = InceptionEventBuilder::new(keys)
+        .with_ndigs(ndigs)
+        .with_wits(wits)
+        .with_code(mtr_dex::BLAKE3_256.to_string());
+    if let Some(isith) = isith {
+        builder = builder.with_isith(isith);
+    }
+    if let Some(nsith) = nsith {
+        builder = builder.with_nsith(nsith);
+    }
+    if let Some(toad) = toad {
+        builder = builder.with_toad(toad);
+    }
+
+    builder.build()
+}
+
+/// Build and sign the inception event of a single key non-transferable identifier
+///
+/// The identifier prefix is the signer's non-transferable verfer and the event
+/// commits to no next keys, so the identifier can never be rotated.
+///
+/// # Parameters
+/// * `signer` - Signer whose verfer is non-transferable
+///
+/// # Returns
+/// * `Result<(SerderKERI, Vec<Siger>), KERIError>` - Inception event and its single
+///   indexed signature, ValueError if the signer's verfer is transferable
+pub fn incept_nontrans(signer: &Signer) -> Result<(SerderKERI, Vec<Siger>), KERIError> {
+    let verfer = signer.verfer();
+    if verfer.is_transferable() {
+        return Err(KERIError::ValueError(format!(
+            "Invalid transferable key = {} for non-transferable inception.",
+            verfer.qb64()
+        )));
+    }
+
+    let serder = InceptionEventBuilder::new(vec![verfer.qb64()]).build()?;
+    let siger = match signer.sign(serder.raw(), Some(0), None, None)? {
+        Sigmat::Indexed(siger) => siger,
+        Sigmat::NonIndexed(_) => {
+            return Err(KERIError::ValueError(
+                "Expected indexed signature.".to_string(),
+            ))
+        }
+    };
+
+    Ok((serder, vec![siger]))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::cesr::diger::Diger;
-    use crate::cesr::mtr_dex;
-    use crate::cesr::signing::Signer;
+    use crate::cesr::indexing::Indexer;
+    use crate::cesr::signing::{Salter, Signer};
     use crate::cesr::tholder::TholderSith;
     use crate::keri::core::serdering::Serder;
     use crate::keri::Ilks;
@@ -798,4 +895,98 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_group_incept() -> Result<(), Box<dyn Error>> {
+        let salter = Salter::new(Some(b"0123456789abcdef"), None, None)?;
+        let signers = salter.signers(4, 0, "", None, None, None, false)?;
+
+        // Two single signature members each with one current and one next key
+        let mut states = Vec::new();
+        for i in 0..2 {
+            let key = signers[i].verfer().qb64();
+            let ndig = Diger::from_ser(&signers[i + 2].verfer().qb64b(), None)?.qb64();
+            let icp = InceptionEventBuilder::new(vec![key.clone()])
+                .with_ndigs(vec![ndig.clone()])
+                .with_code(mtr_dex::BLAKE3_256.to_string())
+                .build()?;
+            states.push(KeyStateRecord {
+                i: icp.pre().unwrap(),
+                k: vec![key],
+                n: vec![ndig],
+                ..Default::default()
+            });
+        }
+
+        let serder = group_incept(
+            &states,
+            Some(TholderSith::Integer(2)),
+            Some(TholderSith::Integer(2)),
+            vec![],
+            None,
+        )?;
+        let ked = serder.ked();
+        let pre = serder.pre().unwrap();
+        assert_eq!(ked["t"].as_str(), Some(Ilks::ICP));
+        assert!(pre.starts_with(mtr_dex::BLAKE3_256));
+        assert_eq!(serder.said(), Some(pre.as_str()));
+        assert_eq!(ked["kt"].as_str(), Some("2"));
+        assert_eq!(ked["nt"].as_str(), Some("2"));
+        let keys = ked["k"].as_array().unwrap();
+        assert_eq!(keys[0].as_str(), Some(states[0].k[0].as_str()));
+        assert_eq!(keys[1].as_str(), Some(states[1].k[0].as_str()));
+        let ndigs = ked["n"].as_array().unwrap();
+        assert_eq!(ndigs[1].as_str(), Some(states[1].n[0].as_str()));
+
+        // Stable for the same members in the same order
+        let again = group_incept(
+            &states,
+            Some(TholderSith::Integer(2)),
+            Some(TholderSith::Integer(2)),
+            vec![],
+            None,
+        )?;
+        assert_eq!(again.pre().unwrap(), pre);
+
+        // Member order is part of the group prefix
+        states.reverse();
+        let reversed = group_incept(
+            &states,
+            Some(TholderSith::Integer(2)),
+            Some(TholderSith::Integer(2)),
+            vec![],
+            None,
+        )?;
+        assert_ne!(reversed.pre().unwrap(), pre);
+
+        assert!(group_incept(&[], None, None, vec![], None).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_incept_nontrans() -> Result<(), Box<dyn Error>> {
+        let signer =
+            Signer::from_qb64("AJ1hsZ3v_VpguoRK9JLsLMREScVpezJpGXA7rAMcrn9g", Some(false))?;
+
+        let (serder, sigers) = incept_nontrans(&signer)?;
+        let pre = serder.pre().unwrap();
+        assert_eq!(pre, signer.verfer().qb64());
+        assert!(pre.starts_with(mtr_dex::ED25519N));
+        assert_eq!(serder.ked()["t"].as_str(), Some(Ilks::ICP));
+        assert!(serder.ked()["n"].as_array().unwrap().is_empty());
+
+        assert_eq!(sigers.len(), 1);
+        assert_eq!(sigers[0].index(), 0);
+        assert!(signer.verfer().verify(sigers[0].raw(), serder.raw())?);
+
+        // Transferable signers cannot make a non-transferable identifier
+        let signer = Signer::from_qb64("AJ1hsZ3v_VpguoRK9JLsLMREScVpezJpGXA7rAMcrn9g", None)?;
+        assert!(matches!(
+            incept_nontrans(&signer),
+            Err(KERIError::ValueError(_))
+        ));
+
+        Ok(())
+    }
 }
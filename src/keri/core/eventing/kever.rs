@@ -14,6 +14,7 @@ use crate::keri::core::eventing::verify_sigs;
 use crate::keri::core::serdering::{Rawifiable, SadValue, Serder, SerderKERI};
 use crate::keri::db::basing::{Baser, EventSourceRecord, KeyStateRecord, StateEERecord};
 use crate::keri::db::dbing::keys::{dg_key, sn_key};
+use crate::keri::help::helping::toiso8601;
 use crate::keri::{Ilk, KERIError};
 use crate::Matter;
 use num_bigint::BigUint;
@@ -35,8 +36,8 @@ pub struct Kever<'db> {
     ilk: Ilk,                   // Event type ilk
     pub delpre: Option<String>, // Delegator prefix if any
     pub(crate) delegated: bool, // True if delegated event, False otherwise
-    fner: Option<Number>,       // First seen ordinal number
-    dater: Option<Dater>,       // First seen timestamp
+    pub(crate) fner: Option<Number>, // First seen ordinal number
+    pub(crate) dater: Option<Dater>, // First seen timestamp
 
     // Fields needed for inception
     pub(crate) sner: Option<Number>,
@@ -55,6 +56,9 @@ pub struct Kever<'db> {
     // Configuration traits
     est_only: Option<bool>,
     do_not_delegate: Option<bool>,
+
+    // Reject rotations to keys used by any prior establishment event
+    pub(crate) no_key_reuse: bool,
 }
 
 impl<'db> Kever<'db> {
@@ -152,6 +156,7 @@ impl<'db> Kever<'db> {
             last_est: None,
             est_only: None,
             do_not_delegate: None,
+            no_key_reuse: false,
             // Initialize other fields here
         };
 
@@ -191,7 +196,7 @@ impl<'db> Kever<'db> {
         // Set first seen data if not in check mode
         if let Some(fn_num) = fn_num {
             kever.fner = Some(Number::from_num(&BigUint::from(fn_num))?);
-            kever.dater = Some(Dater::from_dt(dts));
+            kever.dater = Some(Dater::from_dt(dts)?);
             let _ = kever
                 .db
                 .states
@@ -224,7 +229,7 @@ impl<'db> Kever<'db> {
             .map_err(|e| KERIError::ValueError(format!("Invalid first seen number: {}", e)))?;
 
         // Create datetime stamp
-        let dater = Dater::from_dt((&state.dt).parse().unwrap());
+        let dater = Dater::from_dt((&state.dt).parse().unwrap())?;
 
         // Get event type (ilk)
         let ilk = match Ilk::from_str(&state.et) {
@@ -281,8 +286,8 @@ impl<'db> Kever<'db> {
         let adds = state.ee.ba.clone().unwrap_or_default();
 
         // Create configuration traits
-        let est_only = state.c.contains(&"EstOnly".to_string());
-        let do_not_delegate = state.c.contains(&"DoNotDelegate".to_string());
+        let est_only = state.c.iter().any(|c| c == trait_dex::EST_ONLY);
+        let do_not_delegate = state.c.iter().any(|c| c == trait_dex::DO_NOT_DELEGATE);
 
         // Create last establishment event location
         let last_est_sn = u64::from_str_radix(&state.ee.s, 16).map_err(|e| {
@@ -345,9 +350,81 @@ impl<'db> Kever<'db> {
             last_est: Some(last_est),
             est_only: Some(est_only),
             do_not_delegate: Some(do_not_delegate),
+            no_key_reuse: false,
         })
     }
 
+    /// Create a Kever from an inception event taken from a trusted source
+    ///
+    /// Performs the structural inception checks but skips signature, witness
+    /// and delegation validation. The event is logged as first seen with the
+    /// provided sigers stored as is. Used for bootstrapping from a trusted
+    /// KEL snapshot only.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - LMDB database instance
+    /// * `serder` - Inception (icp or dip) event
+    /// * `sigers` - Indexed controller signatures to store with the event
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Self, KERIError>` - New Kever instance or error
+    pub(crate) fn incept_trusted(
+        db: Arc<&'db Baser<'db>>,
+        serder: SerderKERI,
+        sigers: Vec<Siger>,
+    ) -> Result<Self, KERIError> {
+        let ilk = serder
+            .ilk()
+            .ok_or_else(|| KERIError::ValueError("Missing ilk in event".to_string()))?;
+        if ilk != Ilk::Icp && ilk != Ilk::Dip {
+            return Err(KERIError::ValidationError(format!(
+                "Expected ilk = icp or dip, got {} for evt = {:?}",
+                ilk,
+                serder.ked()
+            )));
+        }
+
+        let mut kever = Kever {
+            db,
+            version: format!("{}", serder.version()),
+            ilk,
+            delpre: None,
+            delegated: false,
+            fner: None,
+            dater: None,
+            sner: None,
+            verfers: None,
+            tholder: None,
+            prefixer: None,
+            serder: None,
+            ndigers: None,
+            ntholder: None,
+            cuts: None,
+            adds: None,
+            wits: None,
+            toader: None,
+            last_est: None,
+            est_only: None,
+            do_not_delegate: None,
+            no_key_reuse: false,
+        };
+
+        kever.incept(serder.clone())?;
+        kever.config(serder.clone(), None)?;
+        kever.delpre = if ilk == Ilk::Dip { serder.delpre() } else { None };
+        kever.delegated = kever.delpre.is_some();
+
+        let wits = serder.backs();
+        let (fn_num, dts) = kever.log_event(
+            serder, sigers, None, wits, true, None, None, None, None, true,
+        )?;
+        kever.first_seen(fn_num, dts)?;
+
+        Ok(kever)
+    }
+
     /// Verify inception key event message from serder
     ///
     /// # Arguments
@@ -467,8 +544,12 @@ impl<'db> Kever<'db> {
         }
 
         // Check data field for non-transferable prefixes
-        let data = serder.sad().get("a").cloned();
-        if !prefixer.transferable() && data.is_some() {
+        let data = match serder.sad().get("a") {
+            Some(SadValue::Array(seals)) => !seals.is_empty(),
+            Some(_) => true,
+            None => false,
+        };
+        if !prefixer.transferable() && data {
             return Err(KERIError::ValidationError(format!(
                 "Invalid inception data not empty for non-transferable prefix = {} for evt = {:?}.",
                 prefixer.qb64(),
@@ -501,6 +582,9 @@ impl<'db> Kever<'db> {
 
     /// Process configuration traits from the serder
     ///
+    /// Every trait in the `c` field must be a known TraitDex code. EstOnly and
+    /// DoNotDelegate are kept on the Kever, EstOnly is enforced by `update`.
+    ///
     /// # Arguments
     ///
     /// * `serder` - The SerderKERI containing configuration traits
@@ -508,7 +592,7 @@ impl<'db> Kever<'db> {
     ///
     /// # Returns
     ///
-    /// * `Result<(), KERIError>` - Success or error
+    /// * `Result<(), KERIError>` - Success or ValidationError on an invalid trait
     fn config(&mut self, serder: SerderKERI, est_only: Option<bool>) -> Result<(), KERIError> {
         // We need to add these fields to the Kever struct
         // Add constants for default values
@@ -527,18 +611,29 @@ impl<'db> Kever<'db> {
 
         // Process configuration traits from the serder
         if let Some(traits) = serder.traits() {
-            // In Rust we need to check the type of traits and process accordingly
-            if let Some(traits_array) = traits.as_array() {
-                // Process each trait in the array
-                for trait_value in traits_array {
-                    if let Some(trait_str) = trait_value.as_str() {
-                        match trait_str {
-                            // Using string literals here, but should use proper TraitDex enum
-                            "EO" => self.est_only = Some(true),
-                            "DND" => self.do_not_delegate = Some(true),
-                            _ => (), // Ignore unknown traits
-                        }
+            let traits_array = traits.as_array().ok_or_else(|| {
+                KERIError::ValidationError(format!(
+                    "Invalid config traits = {:?} for evt = {:?}.",
+                    traits,
+                    serder.ked()
+                ))
+            })?;
+
+            for trait_value in traits_array {
+                let trait_str = match trait_value.as_str() {
+                    Some(trait_str) if trait_dex::TUPLE.contains(&trait_str) => trait_str,
+                    _ => {
+                        return Err(KERIError::ValidationError(format!(
+                            "Unknown config trait = {:?} for evt = {:?}.",
+                            trait_value,
+                            serder.ked()
+                        )))
                     }
+                };
+                match trait_str {
+                    trait_dex::EST_ONLY => self.est_only = Some(true),
+                    trait_dex::DO_NOT_DELEGATE => self.do_not_delegate = Some(true),
+                    _ => (),
                 }
             }
         }
@@ -546,6 +641,46 @@ impl<'db> Kever<'db> {
         Ok(())
     }
 
+    /// Verifies signatures on an event of a non-transferable identifier
+    ///
+    /// A non-transferable prefix is the qb64 of its public key so the verkey is
+    /// taken from the prefix itself rather than from the event's key list.
+    ///
+    /// # Arguments
+    ///
+    /// * `serder` - Event of the non-transferable identifier
+    /// * `sigers` - Indexed controller signatures on the event
+    ///
+    /// # Returns
+    ///
+    /// * `Result<bool, KERIError>` - true if a signature at index 0 verifies under the
+    ///   prefix derived verkey, ValueError if the prefix is transferable
+    pub fn verify_self_certifying(
+        &self,
+        serder: &SerderKERI,
+        sigers: &[Siger],
+    ) -> Result<bool, KERIError> {
+        let pre = serder
+            .pre()
+            .ok_or_else(|| KERIError::ValueError("Missing pre in event".to_string()))?;
+        let prefixer = Prefixer::from_qb64(&pre)?;
+        if prefixer.transferable() {
+            return Err(KERIError::ValueError(format!(
+                "Expected non-transferable prefix got {}.",
+                pre
+            )));
+        }
+
+        let verfer = Verfer::from_qb64(&pre)?;
+        for siger in sigers {
+            if siger.index() == 0 && verfer.verify(siger.raw(), serder.raw())? {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
     /// Validates signatures, witnesses, and delegation
     ///
     /// Returns tuple (sigers, wigers, delpre, delseqner, delsaider) where:
@@ -636,6 +771,16 @@ impl<'db> Kever<'db> {
             )));
         }
 
+        // Non-transferable prefix is its own verkey so it must self certify
+        let prefixer = Prefixer::from_qb64(&serder.pre().unwrap_or_default())?;
+        if !prefixer.transferable() && !self.verify_self_certifying(&serder, &sigers)? {
+            return Err(KERIError::ValidationError(format!(
+                "No signature verified by non-transferable prefix = {} for evt = {:?}",
+                prefixer.qb64(),
+                serder.ked()
+            )));
+        }
+
         // Get delegator's delpre if any for misfit check
         let delpre = if serder.ilk() == Some(Ilk::Dip) {
             // Get delegator from dip event
@@ -682,7 +827,7 @@ impl<'db> Kever<'db> {
         // Convert witness prefixes to verifiers
         let werfers: Vec<Verfer> = wits
             .iter()
-            .map(|wit| Verfer::new(Some(wit.as_bytes()), None))
+            .map(|wit| Verfer::from_qb64(wit))
             .collect::<Result<Vec<Verfer>, _>>()?;
 
         // Verify witness signatures
@@ -933,7 +1078,7 @@ impl<'db> Kever<'db> {
 
         // If not a rotation or delegation rotation event, or sequence number is not greater,
         // return current values with no changes
-        if (ilk != Ilk::Icp && ilk != Ilk::Drt)
+        if (ilk != Ilk::Rot && ilk != Ilk::Drt)
             || self.sner.as_ref().map_or(0, |n| n.num()) >= sn as u128
         {
             // Return current values with empty cuts and adds lists
@@ -1003,15 +1148,13 @@ impl<'db> Kever<'db> {
             )));
         }
 
-        // Calculate new witness list: remove cuts, add adds
-        // First remove cuts from wit_set
-        let wit_set_after_cuts: HashSet<String> = wit_set.difference(&cut_set).cloned().collect();
-
-        // Then add adds to the set after cuts
-        let new_wit_set: HashSet<String> = wit_set_after_cuts.union(&add_set).cloned().collect();
-
-        // Convert back to a vector
-        let new_wits = new_wit_set.into_iter().collect::<Vec<String>>();
+        // Calculate new witness list: remove cuts, then append adds preserving order
+        let mut new_wits: Vec<String> = wits
+            .iter()
+            .filter(|wit| !cut_set.contains(*wit))
+            .cloned()
+            .collect();
+        new_wits.extend(adds.iter().cloned());
 
         // Validate that the final witness count is correct
         if new_wits.len() != (wits.len() - cuts.len() + adds.len()) {
@@ -1136,8 +1279,8 @@ impl<'db> Kever<'db> {
                 None => continue, // Skip if verfer is None
             };
 
-            // Create a digest of the verfer using the same code as the diger
-            let kdig = match Diger::new(Some(verfer.raw()), Some(diger.code()), None, None) {
+            // Create a digest of the verfer qb64b using the same code as the diger
+            let kdig = match Diger::from_ser(&verfer.qb64b(), Some(diger.code())) {
                 Ok(d) => d.qb64(),
                 Err(_) => continue, // Skip if there's an error creating the digest
             };
@@ -1312,7 +1455,7 @@ impl<'db> Kever<'db> {
             // Update first seen number and date if not in check mode
             if let Some(fn_num) = fn_val {
                 self.fner = Some(Number::from_num(&BigUint::from(fn_num))?);
-                self.dater = Some(Dater::from_dt(dts));
+                self.dater = Some(Dater::from_dt(dts)?);
                 // Update state in database
                 if let Some(prefixer) = &self.prefixer {
                     self.db.states.pin(&[&prefixer.qb64()], &self.state()?)?;
@@ -1321,6 +1464,9 @@ impl<'db> Kever<'db> {
         }
         // Handle interaction event
         else if ilk == Ilk::Ixn {
+            // Interaction events may not change key state
+            Self::validate_interaction(&serder)?;
+
             // Check if est_only is true
             if self.est_only.unwrap_or(false) {
                 return Err(KERIError::ValidationError(format!(
@@ -1403,7 +1549,7 @@ impl<'db> Kever<'db> {
             // Update first seen number and date if not in check mode
             if let Some(fn_num) = fn_val {
                 self.fner = Some(Number::from_num(&BigUint::from(fn_num))?);
-                self.dater = Some(Dater::from_dt(dts));
+                self.dater = Some(Dater::from_dt(dts)?);
                 // Update state in database
                 if let Some(prefixer) = &self.prefixer {
                     self.db.states.pin(&[&prefixer.qb64()], &self.state()?)?;
@@ -1421,6 +1567,134 @@ impl<'db> Kever<'db> {
         Ok(())
     }
 
+    /// Apply a non-inceptive event taken from a trusted source to this Kever
+    ///
+    /// Updates key state directly from the event body and logs the event as
+    /// first seen without verifying signatures or witness receipts.
+    ///
+    /// # Arguments
+    ///
+    /// * `serder` - Rotation (rot or drt) or interaction (ixn) event
+    /// * `sigers` - Indexed controller signatures to store with the event
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), KERIError>` - Ok if applied, Error otherwise
+    pub(crate) fn update_trusted(
+        &mut self,
+        serder: SerderKERI,
+        sigers: Vec<Siger>,
+    ) -> Result<(), KERIError> {
+        let ilk = serder
+            .ilk()
+            .ok_or_else(|| KERIError::ValueError("Missing ilk in event".to_string()))?;
+        let sn = serder.sn().unwrap_or_default();
+
+        let (fn_val, dts) = match ilk {
+            Ilk::Rot | Ilk::Drt => {
+                let (wits, cuts, adds) = self.derive_backs(&serder)?;
+                let logged = self.log_event(
+                    serder.clone(),
+                    sigers,
+                    None,
+                    Some(wits.clone()),
+                    true,
+                    None,
+                    None,
+                    None,
+                    None,
+                    true,
+                )?;
+
+                self.tholder = serder.tholder();
+                self.verfers = serder.verfers();
+                self.ndigers = serder.ndigers();
+                self.ntholder = serder.ntholder();
+                self.toader = serder.bner();
+                self.wits = Some(wits);
+                self.cuts = Some(cuts);
+                self.adds = Some(adds);
+                self.last_est = Some(LastEstLoc {
+                    s: sn,
+                    d: serder.said().unwrap_or_default().to_string(),
+                });
+                logged
+            }
+            Ilk::Ixn => self.log_event(
+                serder.clone(),
+                sigers,
+                None,
+                None,
+                true,
+                None,
+                None,
+                None,
+                None,
+                true,
+            )?,
+            _ => {
+                return Err(KERIError::ValidationError(format!(
+                    "Unsupported ilk = {} for evt = {:?}",
+                    ilk,
+                    serder.ked()
+                )))
+            }
+        };
+
+        self.sner = Some(Number::from_num(&BigUint::from(sn))?);
+        self.serder = Some(serder);
+        self.ilk = ilk;
+        self.first_seen(fn_val, dts)?;
+
+        Ok(())
+    }
+
+    /// Record first seen ordinal and datetime and persist the resulting key state
+    fn first_seen(
+        &mut self,
+        fn_num: Option<u64>,
+        dts: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), KERIError> {
+        if let Some(fn_num) = fn_num {
+            self.fner = Some(Number::from_num(&BigUint::from(fn_num))?);
+            self.dater = Some(Dater::from_dt(dts)?);
+            if let Some(prefixer) = &self.prefixer {
+                self.db.states.pin(&[&prefixer.qb64()], &self.state()?)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verify that an interaction event carries no establishment fields
+    ///
+    /// An ixn may only anchor data, so any of the key state fields `k`, `kt`,
+    /// `n` or `nt` in its body is rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `serder` - Interaction event to check
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), KERIError>` - Ok if valid, InvalidInteraction otherwise
+    pub fn validate_interaction(serder: &SerderKERI) -> Result<(), KERIError> {
+        let ked = serder.ked();
+        let fields: Vec<&str> = ["k", "kt", "n", "nt"]
+            .into_iter()
+            .filter(|label| ked.contains_key(*label))
+            .collect();
+
+        if !fields.is_empty() {
+            return Err(KERIError::InvalidInteraction(format!(
+                "Establishment fields {:?} not allowed in ixn for evt = {:?}",
+                fields, ked
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Generic Rotate Operation Validation Processing
     /// Validates provisional rotation
     /// Same logic for both 'rot' and 'drt' (plain and delegated rotation)
@@ -1558,34 +1832,107 @@ impl<'db> Kever<'db> {
             )));
         }
 
+        // Forward security forbids rotating back to a previously exposed key
+        if self.no_key_reuse {
+            let prior_keys = self.prior_keys(sn)?;
+            if let Some(key) = keys.iter().find(|k| prior_keys.contains(*k)) {
+                return Err(KERIError::KeyReuse(format!(
+                    "Rotation reuses prior key = {} for evt = {:?}",
+                    key, ked
+                )));
+            }
+        }
+
         // Compute witnesses from existing wits with new cuts and adds from event
         let (wits, cuts, adds) = self.derive_backs(serder)?;
 
-        // Get witness threshold from event
+        // Get witness threshold from event and validate against post rotation witnesses
         let toader = serder.bner().unwrap_or_default();
+        Self::validate_toad(&toader, &wits).map_err(|e| match e {
+            KERIError::InvalidToad(msg) => {
+                KERIError::InvalidToad(format!("{} for event={:?}", msg, ked))
+            }
+            e => e,
+        })?;
 
-        // Validate witness threshold
-        if !wits.is_empty() {
-            if toader.num() < 1 || toader.num() > wits.len() as u128 {
-                return Err(KERIError::ValueError(format!(
-                    "Invalid toad = {} for backers (wits)={:?} for event={:?}",
-                    toader.num(),
-                    wits,
-                    ked
-                )));
+        Ok((tholder, toader, wits, cuts, adds))
+    }
+
+    /// Collect the signing keys of every establishment event before sn
+    ///
+    /// # Arguments
+    ///
+    /// * `sn` - Sequence number of the rotation being validated
+    ///
+    /// # Returns
+    ///
+    /// * `Result<HashSet<String>, KERIError>` - qb64 keys of all prior establishment events
+    fn prior_keys(&self, sn: u64) -> Result<HashSet<String>, KERIError> {
+        let mut keys: HashSet<String> = self
+            .verfers
+            .iter()
+            .flatten()
+            .map(|verfer| verfer.qb64())
+            .collect();
+
+        let pre = match &self.prefixer {
+            Some(prefixer) => prefixer.qb64(),
+            None => return Ok(keys),
+        };
+        if sn == 0 {
+            return Ok(keys);
+        }
+
+        let kel_back_iter = self
+            .db
+            .kels
+            .get_on_back_iter::<_, Vec<u8>>(&[&pre], sn - 1)?;
+
+        for digb in kel_back_iter {
+            let dgkey = dg_key(&pre, &digb?);
+            let raw = match self.db.evts.get::<_, Vec<u8>>(&[dgkey])? {
+                Some(raw) => raw,
+                None => continue,
+            };
+
+            let serder = SerderKERI::from_raw(&raw, None)?;
+            if serder.estive() {
+                keys.extend(serder.keys().unwrap_or_default());
             }
+        }
+
+        Ok(keys)
+    }
+
+    /// Validate witness threshold (toad) against a witness list
+    ///
+    /// A non-empty witness list requires 1 <= toad <= len(wits) while an empty
+    /// list requires toad == 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `toader` - Witness threshold
+    /// * `wits` - Witness prefixes the threshold applies to
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), KERIError>` - Ok if valid, InvalidToad otherwise
+    pub fn validate_toad(toader: &Number, wits: &[String]) -> Result<(), KERIError> {
+        let toad = toader.num();
+        let valid = if wits.is_empty() {
+            toad == 0
         } else {
-            if toader.num() != 0 {
-                return Err(KERIError::ValueError(format!(
-                    "Invalid toad = {} for backers (wits)={:?} for event={:?}",
-                    toader.num(),
-                    wits,
-                    ked
-                )));
-            }
+            toad >= 1 && toad <= wits.len() as u128
+        };
+
+        if !valid {
+            return Err(KERIError::InvalidToad(format!(
+                "Invalid toad = {} for backers (wits)={:?}",
+                toad, wits
+            )));
         }
 
-        Ok((tholder, toader, wits, cuts, adds))
+        Ok(())
     }
 
     pub fn log_event(
@@ -1610,7 +1957,7 @@ impl<'db> Kever<'db> {
 
         // Get current timestamp in ISO 8601 format
         let now = chrono::Utc::now();
-        let dts_b = now.to_rfc3339().into_bytes();
+        let dts_b = toiso8601(&now).into_bytes();
 
         // Put datetime stamp (idempotent, won't change if already exists)
         self.db.dtss.add(&dg_keys, &dts_b)?;
@@ -1628,7 +1975,7 @@ impl<'db> Kever<'db> {
         if let Some(wigers) = &wigers {
             for wiger in wigers.iter() {
                 self.db
-                    .sigs
+                    .wigs
                     .add(&dg_keys, &wiger.qb64().into_bytes().as_slice())?;
             }
         }
@@ -1703,6 +2050,13 @@ impl<'db> Kever<'db> {
                     // Store first seen ordinal number
                     let fn_seqner = Number::from_num(&BigUint::from(fn_val))?;
                     self.db.fons.pin(&dg_keys, &fn_seqner)?;
+
+                    // Fold event into KEL chain hash for tamper evidence
+                    self.db
+                        .fold_kel_chain_hash(&serder.pre().unwrap(), serder.raw())?;
+
+                    // Index SAIDs sealed in the event for anchor lookups
+                    self.db.index_anchors(&serder)?;
                 }
                 Err(e) => {
                     return Err(KERIError::DatabaseError(format!(
@@ -1837,7 +2191,7 @@ impl<'db> Kever<'db> {
         Ok(state_record)
     }
 
-    fn tholder(&self) -> Option<Tholder> {
+    pub(crate) fn tholder(&self) -> Option<Tholder> {
         self.tholder.clone()
     }
 
@@ -1920,7 +2274,7 @@ impl<'db> Kever<'db> {
         let kel_back_iter = self
             .db
             .kels
-            .get_on_back_iter::<_, Vec<u8>>(&[&pre], start_sn as u32)?;
+            .get_on_back_iter::<_, Vec<u8>>(&[&pre], start_sn)?;
 
         for digb in kel_back_iter {
             // Create the digest key for the event
@@ -1974,6 +2328,7 @@ pub struct KeverBuilder<'db> {
     eager: Option<bool>,
     local: Option<bool>,
     check: Option<bool>,
+    no_key_reuse: Option<bool>,
 }
 
 impl<'db> KeverBuilder<'db> {
@@ -1993,6 +2348,7 @@ impl<'db> KeverBuilder<'db> {
             eager: None,
             local: None,
             check: None,
+            no_key_reuse: None,
         }
     }
 
@@ -2068,9 +2424,15 @@ impl<'db> KeverBuilder<'db> {
         self
     }
 
+    /// Set the flag rejecting rotations that reuse prior establishment keys
+    pub fn with_no_key_reuse(mut self, no_key_reuse: bool) -> Self {
+        self.no_key_reuse = Some(no_key_reuse);
+        self
+    }
+
     /// Build the Kever instance
     pub fn build(self) -> Result<Kever<'db>, KERIError> {
-        Kever::new(
+        let mut kever = Kever::new(
             self.db,
             self.state,
             self.serder,
@@ -2084,7 +2446,9 @@ impl<'db> KeverBuilder<'db> {
             self.eager,
             self.local,
             self.check,
-        )
+        )?;
+        kever.no_key_reuse = self.no_key_reuse.unwrap_or(false);
+        Ok(kever)
     }
 }
 
@@ -2092,15 +2456,15 @@ impl<'db> KeverBuilder<'db> {
 mod tests {
     use super::*;
     use crate::cesr::diger::Diger;
-    use crate::cesr::signing::{Salter, Sigmat};
+    use crate::cesr::signing::{Salter, Sigmat, Signer};
     use crate::cesr::tholder::TholderThold;
     use crate::cesr::{mtr_dex, pre_dex};
     use crate::keri::core::eventing::interact::InteractEventBuilder;
     use crate::keri::core::eventing::rotate::RotateEventBuilder;
     use crate::keri::core::eventing::InceptionEventBuilder;
-    use crate::keri::core::serdering::SadValue;
+    use crate::keri::core::serdering::{BaseSerder, SadValue, Sadder};
     use crate::keri::db::dbing::LMDBer;
-    use crate::keri::KERIError;
+    use crate::keri::{KERIError, Kinds};
     use std::collections::HashMap;
     use std::sync::Arc;
 
@@ -2338,6 +2702,325 @@ mod tests {
         Ok(())
     }
 
+    /// Recompute version size and SAID of a hand edited non-inceptive event body
+    fn resaidify(mut sad: Sadder) -> Result<SerderKERI, KERIError> {
+        sad.insert("d".to_string(), SadValue::String("#".repeat(44)));
+        let size = BaseSerder::dumps(&sad, &Kinds::Json)?.len();
+        sad.insert(
+            "v".to_string(),
+            SadValue::String(format!("KERI10JSON{:06x}_", size)),
+        );
+        let (_, sad) = Saider::saidify(sad, None, None, None, None)?;
+        SerderKERI::from_sad(&sad)
+    }
+
+    #[test]
+    fn test_kever_rejects_ixn_with_keys() -> Result<(), KERIError> {
+        let salt = b"g\x15\x89\x1a@\xa4\xa47\x07\xb9Q\xb8\x18\xcdJW";
+        let salter = Salter::new(Some(salt), None, None)?;
+        let signers = salter.signers(2, 0, "", None, None, None, false)?;
+
+        let lmdber = LMDBer::builder()
+            .temp(true)
+            .name("test_kever_ixn")
+            .build()
+            .expect("Failed to open Baser database: {}");
+        let db = Baser::new(Arc::new(&lmdber)).expect("Failed to create manager database");
+
+        let nxt = vec![Diger::from_ser(&signers[1].verfer().qb64b(), None)?.qb64()];
+        let icp = InceptionEventBuilder::new(vec![signers[0].verfer().qb64()])
+            .with_ndigs(nxt)
+            .build()?;
+        let pre = icp.pre().unwrap();
+        let sig = match signers[0].sign(icp.raw(), Some(0), None, None)? {
+            Sigmat::Indexed(siger) => siger,
+            _ => {
+                return Err(KERIError::ValueError(
+                    "Expected indexed signature".to_string(),
+                ))
+            }
+        };
+        let mut kever = KeverBuilder::new(Arc::new(&db))
+            .with_serder(icp.clone())
+            .with_sigers(vec![sig])
+            .build()?;
+
+        // Inject a k field into an otherwise valid ixn and resaidify it
+        let ixn = InteractEventBuilder::new(pre.clone(), icp.said().unwrap().to_string())
+            .with_sn(1)
+            .build()?;
+        let mut sad = ixn.sad();
+        sad.insert(
+            "k".to_string(),
+            SadValue::Array(vec![SadValue::String(signers[1].verfer().qb64())]),
+        );
+        let bad = resaidify(sad)?;
+        assert!(Kever::validate_interaction(&ixn).is_ok());
+
+        let sig = match signers[0].sign(bad.raw(), Some(0), None, None)? {
+            Sigmat::Indexed(siger) => siger,
+            _ => {
+                return Err(KERIError::ValueError(
+                    "Expected indexed signature".to_string(),
+                ))
+            }
+        };
+        let result = kever.update(bad, vec![sig], None, None, None, None, None, false, true, false);
+        assert!(matches!(result, Err(KERIError::InvalidInteraction(_))));
+        assert_eq!(kever.sner.as_ref().unwrap().num(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_kever_config_traits() -> Result<(), KERIError> {
+        let salt = b"g\x15\x89\x1a@\xa4\xa47\x07\xb9Q\xb8\x18\xcdJW";
+        let salter = Salter::new(Some(salt), None, None)?;
+        let signers = salter.signers(2, 0, "", None, None, None, false)?;
+
+        let lmdber = LMDBer::builder()
+            .temp(true)
+            .name("test_kever_config_traits")
+            .build()
+            .expect("Failed to open Baser database: {}");
+        let db = Baser::new(Arc::new(&lmdber)).expect("Failed to create manager database");
+
+        let sign = |serder: &SerderKERI| -> Result<Siger, KERIError> {
+            match signers[0].sign(serder.raw(), Some(0), None, None)? {
+                Sigmat::Indexed(siger) => Ok(siger),
+                _ => Err(KERIError::ValueError(
+                    "Expected indexed signature".to_string(),
+                )),
+            }
+        };
+        let nxt = vec![Diger::from_ser(&signers[1].verfer().qb64b(), None)?.qb64()];
+
+        // Unknown trait code is rejected at inception
+        let icp = InceptionEventBuilder::new(vec![signers[0].verfer().qb64()])
+            .with_ndigs(nxt.clone())
+            .with_cnfg(vec!["XX".to_string()])
+            .build()?;
+        let result = KeverBuilder::new(Arc::new(&db))
+            .with_serder(icp.clone())
+            .with_sigers(vec![sign(&icp)?])
+            .build();
+        assert!(matches!(result, Err(KERIError::ValidationError(_))));
+
+        let icp = InceptionEventBuilder::new(vec![signers[0].verfer().qb64()])
+            .with_ndigs(nxt)
+            .with_cnfg(vec![trait_dex::EST_ONLY.to_string()])
+            .build()?;
+        let pre = icp.pre().unwrap();
+        let mut kever = KeverBuilder::new(Arc::new(&db))
+            .with_serder(icp.clone())
+            .with_sigers(vec![sign(&icp)?])
+            .build()?;
+        assert_eq!(kever.est_only, Some(true));
+        assert_eq!(kever.do_not_delegate, Some(false));
+        assert_eq!(kever.state()?.c, vec![trait_dex::EST_ONLY.to_string()]);
+
+        // Establishment only identifier rejects an ixn
+        let ixn = InteractEventBuilder::new(pre, icp.said().unwrap().to_string())
+            .with_sn(1)
+            .build()?;
+        let result = kever.update(
+            ixn.clone(),
+            vec![sign(&ixn)?],
+            None,
+            None,
+            None,
+            None,
+            None,
+            false,
+            true,
+            false,
+        );
+        assert!(matches!(result, Err(KERIError::ValidationError(_))));
+        assert_eq!(kever.sner.as_ref().unwrap().num(), 0);
+
+        // The trait survives a reload from the stored key state
+        let reloaded = Kever::reload(Arc::new(&db), kever.state()?)?;
+        assert_eq!(reloaded.est_only, Some(true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_kever_no_key_reuse() -> Result<(), KERIError> {
+        let salt = b"g\x15\x89\x1a@\xa4\xa47\x07\xb9Q\xb8\x18\xcdJW";
+        let salter = Salter::new(Some(salt), None, None)?;
+        let signers = salter.signers(3, 0, "", None, None, None, false)?;
+
+        let lmdber = LMDBer::builder()
+            .temp(true)
+            .name("test_kever_no_key_reuse")
+            .build()
+            .expect("Failed to open Baser database: {}");
+        let db = Baser::new(Arc::new(&lmdber)).expect("Failed to create manager database");
+
+        let sign = |signer: &Signer, serder: &SerderKERI| -> Result<Siger, KERIError> {
+            match signer.sign(serder.raw(), Some(0), None, None)? {
+                Sigmat::Indexed(siger) => Ok(siger),
+                _ => Err(KERIError::ValueError(
+                    "Expected indexed signature".to_string(),
+                )),
+            }
+        };
+        let dig = |signer: &Signer| -> Result<Vec<String>, KERIError> {
+            Ok(vec![Diger::from_ser(&signer.verfer().qb64b(), None)?.qb64()])
+        };
+
+        let icp = InceptionEventBuilder::new(vec![signers[0].verfer().qb64()])
+            .with_ndigs(dig(&signers[1])?)
+            .build()?;
+        let pre = icp.pre().unwrap();
+        let mut kever = KeverBuilder::new(Arc::new(&db))
+            .with_serder(icp.clone())
+            .with_sigers(vec![sign(&signers[0], &icp)?])
+            .with_no_key_reuse(true)
+            .build()?;
+
+        // Rotating to a fresh key is accepted, committing back to the inception key
+        let rot = RotateEventBuilder::new(
+            pre.clone(),
+            vec![signers[1].verfer().qb64()],
+            icp.said().unwrap().to_string(),
+        )
+        .with_sn(1)
+        .with_ndigs(dig(&signers[0])?)
+        .build()?;
+        let sigers = vec![sign(&signers[1], &rot)?];
+        kever.update(
+            rot.clone(),
+            sigers,
+            None,
+            None,
+            None,
+            None,
+            None,
+            false,
+            true,
+            false,
+        )?;
+        assert_eq!(kever.sner.as_ref().unwrap().num(), 1);
+
+        // Rotating back to the inception key is rejected
+        let rot2 = RotateEventBuilder::new(
+            pre.clone(),
+            vec![signers[0].verfer().qb64()],
+            rot.said().unwrap().to_string(),
+        )
+        .with_sn(2)
+        .with_ndigs(dig(&signers[2])?)
+        .build()?;
+        let sigers = vec![sign(&signers[0], &rot2)?];
+        let result = kever.update(
+            rot2.clone(),
+            sigers,
+            None,
+            None,
+            None,
+            None,
+            None,
+            false,
+            true,
+            false,
+        );
+        assert!(matches!(result, Err(KERIError::KeyReuse(_))));
+        assert_eq!(kever.sner.as_ref().unwrap().num(), 1);
+
+        // The policy survives a reload from the stored key state
+        let reloaded = KeverBuilder::new(Arc::new(&db))
+            .with_state(kever.state()?)
+            .with_no_key_reuse(true)
+            .build()?;
+        assert!(matches!(
+            reloaded.rotate(&rot2),
+            Err(KERIError::KeyReuse(_))
+        ));
+
+        // Without the opt in the same rotation passes the reuse check
+        kever.no_key_reuse = false;
+        assert!(kever.rotate(&rot2).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_kever_rotation_toad() -> Result<(), KERIError> {
+        let salt = b"g\x15\x89\x1a@\xa4\xa47\x07\xb9Q\xb8\x18\xcdJW";
+        let salter = Salter::new(Some(salt), None, None)?;
+        let signers = salter.signers(3, 0, "", None, None, None, false)?;
+        let wits: Vec<String> = salter
+            .signers(3, 3, "", None, Some(false), None, false)?
+            .iter()
+            .map(|s| s.verfer().qb64())
+            .collect();
+
+        let lmdber = LMDBer::builder()
+            .temp(true)
+            .name("test_kever_toad")
+            .build()
+            .expect("Failed to open Baser database: {}");
+        let db = Baser::new(Arc::new(&lmdber)).expect("Failed to create manager database");
+
+        let nxt1 = vec![Diger::from_ser(&signers[1].verfer().qb64b(), None)?.qb64()];
+        let nxt2 = vec![Diger::from_ser(&signers[2].verfer().qb64b(), None)?.qb64()];
+        let icp = InceptionEventBuilder::new(vec![signers[0].verfer().qb64()])
+            .with_ndigs(nxt1)
+            .build()?;
+        let pre = icp.pre().unwrap();
+        let sig = match signers[0].sign(icp.raw(), Some(0), None, None)? {
+            Sigmat::Indexed(siger) => siger,
+            _ => {
+                return Err(KERIError::ValueError(
+                    "Expected indexed signature".to_string(),
+                ))
+            }
+        };
+        let kever = KeverBuilder::new(Arc::new(&db))
+            .with_serder(icp.clone())
+            .with_sigers(vec![sig])
+            .build()?;
+
+        // Reload as if witnessed by 3 witnesses with toad = 2
+        let mut state = kever.state()?;
+        state.b = wits.clone();
+        state.bt = "2".to_string();
+        let kever = Kever::reload(Arc::new(&db), state)?;
+
+        // Cut down to 1 witness with toad = 1 is valid
+        let rot = RotateEventBuilder::new(
+            pre.clone(),
+            vec![signers[1].verfer().qb64()],
+            icp.said().unwrap().to_string(),
+        )
+        .with_sn(1)
+        .with_ndigs(nxt2)
+        .with_wits(wits.clone())
+        .with_cuts(vec![wits[0].clone(), wits[1].clone()])
+        .with_toad(1)
+        .build()?;
+        let (_, toader, rwits, cuts, _) = kever.rotate(&rot)?;
+        assert_eq!(toader.num(), 1);
+        assert_eq!(rwits, vec![wits[2].clone()]);
+        assert_eq!(cuts, vec![wits[0].clone(), wits[1].clone()]);
+
+        // Same rotation keeping toad = 2 leaves too few witnesses
+        let mut sad = rot.sad();
+        sad.insert("bt".to_string(), SadValue::String("2".to_string()));
+        let bad = resaidify(sad)?;
+        let result = kever.rotate(&bad);
+        assert!(matches!(result, Err(KERIError::InvalidToad(_))));
+
+        assert!(Kever::validate_toad(&Number::from_num(&BigUint::from(0u32))?, &[]).is_ok());
+        assert!(Kever::validate_toad(&Number::from_num(&BigUint::from(1u32))?, &[]).is_err());
+        assert!(Kever::validate_toad(&Number::from_num(&BigUint::from(0u32))?, &wits).is_err());
+        assert!(Kever::validate_toad(&Number::from_num(&BigUint::from(3u32))?, &wits).is_ok());
+
+        Ok(())
+    }
+
     #[test]
     fn test_kever_missing_args() -> Result<(), KERIError> {
         // Test creating a Kever without required arguments should fail
@@ -3147,4 +3830,60 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_verify_self_certifying() -> Result<(), KERIError> {
+        let lmdber = LMDBer::builder()
+            .temp(true)
+            .name("test_verify_self_certifying")
+            .build()
+            .map_err(|e| KERIError::DatabaseError(format!("{}", e)))?;
+        let db =
+            Baser::new(Arc::new(&lmdber)).map_err(|e| KERIError::DatabaseError(format!("{}", e)))?;
+
+        let salter = Salter::new(Some(b"0123456789abcdef"), None, None)?;
+        let signers = salter.signers(2, 0, "", None, Some(false), None, false)?;
+        assert_eq!(signers[0].verfer().code(), mtr_dex::ED25519N);
+
+        let icp = InceptionEventBuilder::new(vec![signers[0].verfer().qb64()]).build()?;
+        assert_eq!(icp.pre().unwrap(), signers[0].verfer().qb64());
+
+        let sign = |signer: &Signer| -> Result<Siger, KERIError> {
+            match signer.sign(icp.raw(), Some(0), None, None)? {
+                Sigmat::Indexed(siger) => Ok(siger),
+                _ => panic!("Expected indexed signature"),
+            }
+        };
+        let valid = sign(&signers[0])?;
+        let invalid = sign(&signers[1])?;
+
+        let kever = KeverBuilder::new(Arc::new(&db))
+            .with_serder(icp.clone())
+            .with_sigers(vec![valid.clone()])
+            .build()?;
+
+        assert!(kever.verify_self_certifying(&icp, &[valid.clone()])?);
+        assert!(!kever.verify_self_certifying(&icp, &[invalid.clone()])?);
+        assert!(kever.verify_self_certifying(&icp, &[invalid.clone(), valid])?);
+
+        // Signed by a key other than the prefix
+        let result = KeverBuilder::new(Arc::new(&db))
+            .with_serder(icp.clone())
+            .with_sigers(vec![invalid])
+            .build();
+        assert!(matches!(result, Err(KERIError::ValidationError(_))));
+
+        // Transferable prefix is rejected
+        let trans = Salter::new(Some(b"0123456789abcdef"), None, None)?
+            .signers(1, 0, "trans", None, None, None, false)?;
+        let ticp = InceptionEventBuilder::new(vec![trans[0].verfer().qb64()])
+            .with_ndigs(vec![Diger::from_ser(&trans[0].verfer().qb64b(), None)?.qb64()])
+            .build()?;
+        assert!(matches!(
+            kever.verify_self_certifying(&ticp, &[]),
+            Err(KERIError::ValueError(_))
+        ));
+
+        Ok(())
+    }
 }
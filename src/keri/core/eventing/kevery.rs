@@ -5,17 +5,22 @@ use crate::cesr::indexing::Indexer;
 use crate::cesr::prefixer::Prefixer;
 use crate::cesr::saider::Saider;
 use crate::cesr::seqner::Seqner;
+use crate::cesr::signing::{Sigmat, Signer};
 use crate::cesr::verfer::Verfer;
-use crate::keri::core::eventing::kever::Kever;
-use crate::keri::core::eventing::{verify_sigs, ReplyEventBuilder};
+use crate::keri::acdc::Creder;
+use crate::keri::core::eventing::kever::{Kever, KeverBuilder};
+use crate::keri::core::eventing::{verify_sigs, InteractEventBuilder, ReplyEventBuilder};
 use crate::keri::core::parsing::Trqs;
 use crate::keri::core::serdering::{Rawifiable, SadValue, Serder, SerderKERI};
-use crate::keri::db::basing::Baser;
+use crate::keri::db::basing::{Baser, EventSourceRecord, LocationRecord};
 use crate::keri::db::dbing::keys::{dg_key, sn_key};
+use crate::keri::db::dbing::LMDBer;
 use crate::keri::db::subing::SuberError;
-use crate::keri::{Ilk, KERIError};
+use crate::keri::help::helping::toiso8601;
+use crate::keri::{Ilk, KERIError, Roles};
 use crate::Matter;
-use indexmap::IndexSet;
+use chrono::{DateTime, Utc};
+use indexmap::{IndexMap, IndexSet};
 use std::collections::{HashMap, VecDeque};
 use std::string::FromUtf8Error;
 use std::sync::Arc;
@@ -59,8 +64,56 @@ pub struct Kevery<'db> {
     /// updating non-idempotent first seen .fels and timestamps.
     pub check: bool,
 
+    /// True means KEL snapshots may be loaded without per-event verification.
+    /// Must be explicitly enabled since it bypasses all signature checks.
+    pub trusted: bool,
+
     /// Cache of kevers indexed by prefix
     pub kevers: HashMap<String, Kever<'db>>,
+
+    /// Source of the current datetime for future dated checks
+    pub clock: Arc<dyn Clock>,
+
+    /// Seconds an attached datetime may be ahead of the clock before the
+    /// event is rejected as future dated
+    pub skew: u64,
+
+    /// Failed reprocessing attempts after which an escrowed event is moved to
+    /// dead letters. None means escrowed events are retried forever
+    pub max_escrow_attempts: Option<u64>,
+
+    /// True means Kevers created by this Kevery reject rotations to keys used
+    /// by any prior establishment event
+    pub no_key_reuse: bool,
+}
+
+/// Clock provides the current datetime so that time dependent validation can be
+/// driven by a fixed clock in tests
+pub trait Clock: Send + Sync {
+    /// Current datetime
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// SystemClock is the Clock backed by the system time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// FixedClock is the Clock pinned to a single datetime for tests
+#[cfg(test)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FixedClock(pub DateTime<Utc>);
+
+#[cfg(test)]
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
 }
 
 /// Cue represents a notice of an event needing receipt or a request needing response
@@ -70,6 +123,19 @@ pub struct Cue {
     serder: SerderKERI,
 }
 
+/// Counts of escrowed items resolved by Kevery::reprocess_prefix
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReprocessStats {
+    /// Out of order escrowed events accepted
+    pub ooo: usize,
+    /// Partially signed escrowed events accepted
+    pub partial_sig: usize,
+    /// Escrowed witness receipt signatures promoted
+    pub receipt: usize,
+    /// Escrowed events moved to dead letters after too many failed attempts
+    pub dead: usize,
+}
+
 /// Recovery module for Kevery
 pub struct Rvy<'db> {
     pub db: Baser<'db>,
@@ -86,6 +152,7 @@ impl<'db> Kevery<'db> {
     pub const TIMEOUT_VRE: u64 = 3600; // seconds to timeout unverified transferable receipt escrows
     pub const TIMEOUT_KSN: u64 = 3600; // seconds to timeout key state notice message escrows
     pub const TIMEOUT_QNF: u64 = 300; // seconds to timeout query not found escrows
+    pub const SKEW_TOLERANCE: u64 = 300; // seconds attached datetimes may be ahead of now
 
     /// Initialize a new Kevery instance
     ///
@@ -119,7 +186,12 @@ impl<'db> Kevery<'db> {
             cloned: cloned.unwrap_or(false),
             direct: direct.unwrap_or(true),
             check: check.unwrap_or(false),
+            trusted: false,
             kevers: HashMap::new(),
+            clock: Arc::new(SystemClock),
+            skew: Self::SKEW_TOLERANCE,
+            max_escrow_attempts: None,
+            no_key_reuse: false,
         })
     }
 
@@ -151,7 +223,8 @@ impl<'db> Kevery<'db> {
     /// * `firner` - Instance of cloned first seen ordinal.
     ///             If cloned mode then firner maybe provided (not None)
     /// * `dater` - Instance of cloned replay datetime.
-    ///            If cloned mode then dater maybe provided (not None)
+    ///            If cloned mode then dater maybe provided (not None).
+    ///            Rejected with FutureDated when later than now plus .skew
     /// * `eager` - True means try harder to find validate events by walking KELs.
     ///            False means only use pre-existing information if any.
     /// * `local` - True means local (protected) event source.
@@ -204,13 +277,17 @@ impl<'db> Kevery<'db> {
             .said()
             .ok_or_else(|| KERIError::ValueError("Missing said in event".to_string()))?;
 
+        if let Some(dater) = &dater {
+            self.check_future_dated(dater)?;
+        }
+
         if !self.kevers.contains_key(&pre) {
             // First seen event for pre
             if ilk == Ilk::Icp || ilk == Ilk::Dip {
                 // First seen and inception so verify event keys
 
                 // Create kever from serder
-                let kever = Kever::new(
+                let mut kever = Kever::new(
                     Arc::new(&self.db),
                     None, // state
                     Some(serder.clone()),
@@ -225,6 +302,7 @@ impl<'db> Kevery<'db> {
                     Some(local),
                     Some(self.check),
                 )?;
+                kever.no_key_reuse = self.no_key_reuse;
 
                 // Not exception so add to kevers
                 self.kevers.insert(pre.clone(), kever);
@@ -529,6 +607,8 @@ impl<'db> Kevery<'db> {
             }
         }
 
+        self.mark_witnessed(&serder)?;
+
         Ok(())
     }
 
@@ -573,23 +653,75 @@ impl<'db> Kevery<'db> {
     }
 
     /// Fetch the witness state for a prefix at a sequence number
-    fn fetch_witness_state(&self, _pre: &str, _sn: u64) -> Result<Vec<String>, KERIError> {
-        // Implementation details would go here
-        todo!("Implement fetch_witness_state method")
+    ///
+    /// Returns the witness list in effect at sn. Witnesses are only stored with
+    /// establishment events so the list comes from the latest establishment event
+    /// accepted at or before sn.
+    fn fetch_witness_state(&self, pre: &str, sn: u64) -> Result<Vec<String>, KERIError> {
+        let est = self.fetch_est_event(pre, sn).ok_or_else(|| {
+            KERIError::MissingEntryError(format!(
+                "Missing establishment event for pre={} sn={}.",
+                pre, sn
+            ))
+        })?;
+        let dig = est.said().unwrap_or_default();
+
+        let wits = self.db.wits.get::<_, Vec<u8>>(&[&dg_key(pre, dig)])?;
+        Ok(wits
+            .iter()
+            .map(|wit| String::from_utf8_lossy(wit).to_string())
+            .collect())
     }
 
     /// Escrow an out-of-order event
+    ///
+    /// Stores the event, its signatures, source and escrow datetime stamp then
+    /// indexes it in .ooes by prefix and sequence number. Delegation seal couples
+    /// are not yet escrowed.
     fn escrow_oo_event(
         &self,
-        _serder: &SerderKERI,
-        _sigers: &[Siger],
+        serder: &SerderKERI,
+        sigers: &[Siger],
         _seqner: Option<&Seqner>,
         _saider: Option<&Saider>,
-        _wigers: Option<&[Siger]>,
-        _local: bool,
+        wigers: Option<&[Siger]>,
+        local: bool,
     ) -> Result<(), KERIError> {
-        // Implementation details would go here
-        todo!("Implement escrow_oo_event method")
+        let pre = serder
+            .pre()
+            .ok_or_else(|| KERIError::ValueError("Missing pre in event".to_string()))?;
+        let said = serder
+            .said()
+            .ok_or_else(|| KERIError::ValueError("Missing said in event".to_string()))?
+            .to_string();
+        let sn = serder.sn().unwrap_or_default();
+        let dg_keys = vec![pre.clone(), said.clone()];
+
+        let dts = toiso8601(&self.clock.now()).into_bytes();
+        self.db.dtss.add(&dg_keys, &dts)?;
+        for siger in sigers {
+            self.db
+                .sigs
+                .add(&dg_keys, &siger.qb64().into_bytes().as_slice())?;
+        }
+        if let Some(wigers) = wigers {
+            for wiger in wigers {
+                self.db
+                    .wigs
+                    .add(&dg_keys, &wiger.qb64().into_bytes().as_slice())?;
+            }
+        }
+        self.db.evts.put(&dg_keys, &serder.raw())?;
+        self.db
+            .esrs
+            .put(&dg_keys, &EventSourceRecord::with_local(local))?;
+        self.db.ooes.add_on(&[&pre], sn, &said.into_bytes())?;
+
+        info!(
+            "Kevery process: escrowed out of order event pre={} sn={}",
+            pre, sn
+        );
+        Ok(())
     }
 
     /// Escrow a likely duplicitous event
@@ -745,6 +877,8 @@ impl<'db> Kevery<'db> {
             }
         }
 
+        self.mark_witnessed(&lserder)?;
+
         Ok(())
     }
 
@@ -772,7 +906,9 @@ impl<'db> Kevery<'db> {
     /// Process one receipt serder with attached cigars
     /// may or may not be a witness receipt. If prefix matches witness then
     /// promote to indexed witness signature and store appropriately. Otherwise
-    /// signature is nontrans nonwitness endorser (watcher etc)
+    /// signature is nontrans nonwitness endorser (watcher etc).
+    /// Receipts whose receiptor prefix is transferable are rejected with
+    /// `KERIError::InvalidReceipt`.
     ///
     /// # Parameters
     /// * `serder` - Receipt instance of serialized receipt message
@@ -808,13 +944,32 @@ impl<'db> Kevery<'db> {
             .sn()
             .ok_or_else(|| KERIError::ValueError("Missing sn in receipt".to_string()))?;
 
+        // Receipt couples must come from non-transferable receiptors
+        for cigar in &cigars {
+            match &cigar.verfer {
+                Some(verfer) if !verfer.is_transferable() => {}
+                Some(verfer) => {
+                    return Err(KERIError::InvalidReceipt(format!(
+                        "Transferable receiptor = {} for receipt = {}",
+                        verfer.qb64(),
+                        serder.said().unwrap_or_default()
+                    )))
+                }
+                None => {
+                    return Err(KERIError::InvalidReceipt(format!(
+                        "Missing receiptor for receipt = {}",
+                        serder.said().unwrap_or_default()
+                    )))
+                }
+            }
+        }
+
         // Only accept receipt if for last seen version of event at sn
         let sn_key = sn_key(&pre, sn);
 
         // Retrieve dig of last event at sn
-        let dig_bytes = self.db.kels.get_on::<_, Vec<u8>>(&[&sn_key], 0)?;
-        let ldig = if dig_bytes.is_empty() {
-            String::from_utf8(dig_bytes.get(0).unwrap().to_vec())
+        let ldig = if let Some(dig) = self.db.kels.get_last::<_, Vec<u8>>(&[&sn_key])? {
+            String::from_utf8(dig)
                 .map_err(|_| KERIError::ValueError("Invalid UTF-8 in digest".to_string()))?
         } else {
             // No events to be receipted yet at that sn, so escrow
@@ -862,11 +1017,6 @@ impl<'db> Kevery<'db> {
 
         // Process each cigar, verify sig and write to db
         for cigar in cigars {
-            // Skip transferable verfers
-            if cigar.verfer.clone().unwrap().is_transferable() {
-                continue;
-            }
-
             // Handle own receiptor scenarios
             if !self.lax
                 && self
@@ -934,6 +1084,8 @@ impl<'db> Kevery<'db> {
             }
         }
 
+        self.mark_witnessed(&lserder)?;
+
         Ok(())
     }
 
@@ -955,26 +1107,304 @@ impl<'db> Kevery<'db> {
         Ok(())
     }
     /// Escrow unverified witness receipt
+    ///
+    /// Stores each indexed witness signature in .ures keyed by the receipted
+    /// event so process_receipt_escrows can verify it once the event is accepted.
     fn escrow_uw_receipt(
         &self,
-        _serder: &SerderKERI,
-        _wigers: &[Siger],
+        serder: &SerderKERI,
+        wigers: &[Siger],
         said: &str,
     ) -> Result<(), KERIError> {
-        // Implementation details would go here
-        // This would store the receipt in an escrow database to be processed later
-        // when the event it's receipting becomes available
+        let pre = serder
+            .pre()
+            .ok_or_else(|| KERIError::ValueError("Missing pre in receipt".to_string()))?;
+        let key = dg_key(&pre, said);
+        for wiger in wigers {
+            self.db.ures.add(&[&key], &wiger.qb64().as_bytes())?;
+        }
 
-        // For now we'll just log it
         debug!(
             "Escrowing unverified witness receipt for event with SAID: {}",
             said
         );
-
-        // TODO: Implement proper escrow functionality
         Ok(())
     }
 
+    /// Process escrowed unverified witness receipts
+    ///
+    /// Each escrowed witness signature whose receipted event has since been
+    /// accepted into its KEL is verified against the witness list for that event.
+    /// Verified signatures are promoted to .wigs and unverifiable ones are dropped.
+    /// Receipts for events not yet accepted stay in escrow.
+    ///
+    /// # Returns
+    /// * `Result<usize, KERIError>` - Number of witness signatures promoted
+    pub fn process_receipt_escrows(&self) -> Result<usize, KERIError> {
+        self.sweep_receipt_escrows(None)
+    }
+
+    /// Process escrowed unverified witness receipts for all prefixes or only `pre`
+    fn sweep_receipt_escrows(&self, pre: Option<&str>) -> Result<usize, KERIError> {
+        let top = pre.map(|pre| pre.as_bytes()).unwrap_or(b"");
+        let mut promoted = 0;
+        for (keys, wig) in self.db.ures.get_item_iter(&[top], pre.is_some())? {
+            if keys.len() != 2 {
+                continue;
+            }
+            let pre = String::from_utf8_lossy(&keys[0]).to_string();
+            let dig = String::from_utf8_lossy(&keys[1]).to_string();
+            let key = dg_key(&pre, &dig);
+
+            let raw = match self.db.evts.get::<_, Vec<u8>>(&[&key])? {
+                Some(raw) => raw,
+                None => continue,
+            };
+            let serder = SerderKERI::from_raw(&raw, None)?;
+            let sn = serder.sn().unwrap_or_default();
+
+            // Only promote once the receipted event is the one accepted at sn
+            match self.db.kels.get_last::<_, Vec<u8>>(&[&sn_key(&pre, sn)])? {
+                Some(ldig) if ldig == dig.as_bytes() => {}
+                _ => continue,
+            }
+
+            let wiger = Siger::from_qb64(&String::from_utf8_lossy(&wig), None)?;
+            let wits = self.fetch_witness_state(&pre, sn)?;
+            let verified = match wits.get(wiger.index() as usize) {
+                Some(wit) => {
+                    let verfer = Verfer::from_qb64(wit)?;
+                    !verfer.is_transferable() && verfer.verify(wiger.raw(), serder.raw())?
+                }
+                None => false,
+            };
+
+            if verified {
+                self.db.wigs.add(&[&key], &wiger.qb64().as_bytes())?;
+                self.mark_witnessed(&serder)?;
+                promoted += 1;
+            } else {
+                info!(
+                    "Kevery: dropped unverifiable escrowed receipt sig for event={}",
+                    dig
+                );
+            }
+            self.db.ures.rem(&[&key], Some(&wig.as_slice()))?;
+        }
+
+        Ok(promoted)
+    }
+
+    /// Re-drive all escrowed material of one identifier prefix
+    ///
+    /// Sweeps the out of order and partially signed event escrows for `pre` by
+    /// reprocessing each escrowed event with its escrowed signatures, then sweeps
+    /// the witness receipt escrows for `pre`. Events that are still out of order or
+    /// still missing signatures stay escrowed. Events failing for any other reason
+    /// are dropped from escrow.
+    ///
+    /// # Parameters
+    /// * `pre` - qb64 identifier prefix
+    ///
+    /// # Returns
+    /// * `Result<ReprocessStats, KERIError>` - Number of items resolved per escrow
+    pub fn reprocess_prefix(&mut self, pre: &str) -> Result<ReprocessStats, KERIError> {
+        let mut stats = ReprocessStats::default();
+
+        let ooes = self
+            .db
+            .ooes
+            .get_on_item_iter::<_, Vec<u8>>(&[pre.as_bytes()], 0)?
+            .collect::<Result<Vec<_>, _>>()?;
+        for (ckey, sn, dig) in ooes {
+            if ckey.first().map(|k| k.as_slice()) != Some(pre.as_bytes()) {
+                continue;
+            }
+            if self.reprocess_escrowed(pre, &dig)? {
+                self.db.ooes.rem_on(&[pre], sn, Some(&dig))?;
+                self.db.eats.rem(&[&dg_key(pre, &dig)])?;
+                stats.ooo += 1;
+            } else if self.escrow_attempt_failed(pre, &dig, "ooes")? {
+                self.db.ooes.rem_on(&[pre], sn, Some(&dig))?;
+                stats.dead += 1;
+            }
+        }
+
+        for (keys, dig) in self.db.pses.get_item_iter(&[pre.as_bytes()], true)? {
+            if self.reprocess_escrowed(pre, &dig)? {
+                self.db.pses.rem(&keys, Some(&dig))?;
+                self.db.eats.rem(&[&dg_key(pre, &dig)])?;
+                stats.partial_sig += 1;
+            } else if self.escrow_attempt_failed(pre, &dig, "pses")? {
+                self.db.pses.rem(&keys, Some(&dig))?;
+                stats.dead += 1;
+            }
+        }
+
+        stats.receipt = self.sweep_receipt_escrows(Some(pre))?;
+
+        Ok(stats)
+    }
+
+    /// Re-drive the escrowed material of every identifier prefix
+    ///
+    /// Runs `reprocess_prefix` for each prefix with an event in the out of order
+    /// or partially signed escrows. When max_escrow_attempts is set an event that
+    /// stays escrowed that many times is moved to the .dlts dead letters.
+    ///
+    /// # Returns
+    /// * `Result<ReprocessStats, KERIError>` - Totals of items resolved per escrow
+    pub fn process_escrows(&mut self) -> Result<ReprocessStats, KERIError> {
+        let mut prefixes = IndexSet::new();
+        for item in self.db.ooes.get_on_item_iter::<_, Vec<u8>>(&[b""], 0)? {
+            let (ckey, _, _) = item?;
+            if let Some(pre) = ckey.first() {
+                prefixes.insert(String::from_utf8_lossy(pre).to_string());
+            }
+        }
+        for (keys, _) in self.db.pses.get_item_iter(&[b""], false)? {
+            if let Some(pre) = keys.first() {
+                prefixes.insert(String::from_utf8_lossy(pre).to_string());
+            }
+        }
+
+        let mut stats = ReprocessStats::default();
+        for pre in prefixes {
+            let pstats = self.reprocess_prefix(&pre)?;
+            stats.ooo += pstats.ooo;
+            stats.partial_sig += pstats.partial_sig;
+            stats.receipt += pstats.receipt;
+            stats.dead += pstats.dead;
+        }
+
+        Ok(stats)
+    }
+
+    /// Count a failed reprocessing attempt of an escrowed event
+    ///
+    /// # Returns
+    /// * `Result<bool, KERIError>` - true when the event reached max_escrow_attempts
+    ///   and was recorded as a dead letter so must be removed from `escrow`
+    fn escrow_attempt_failed(
+        &self,
+        pre: &str,
+        dig: &[u8],
+        escrow: &str,
+    ) -> Result<bool, KERIError> {
+        let max = match self.max_escrow_attempts {
+            Some(max) => max,
+            None => return Ok(false),
+        };
+
+        let dig = String::from_utf8_lossy(dig);
+        if self.db.add_escrow_attempt(pre, &dig)? < max {
+            return Ok(false);
+        }
+
+        info!(
+            "Kevery: moved escrowed event pre={} dig={} from {} to dead letters",
+            pre, dig, escrow
+        );
+        self.db.put_dead_letter(pre, &dig, escrow)?;
+        Ok(true)
+    }
+
+    /// Reprocess one escrowed event with its escrowed signatures and source
+    ///
+    /// # Returns
+    /// * `Result<bool, KERIError>` - true when the event is resolved and should leave
+    ///   escrow, either accepted or failed for good, false when it must stay escrowed
+    fn reprocess_escrowed(&mut self, pre: &str, dig: &[u8]) -> Result<bool, KERIError> {
+        let key = dg_key(pre, dig);
+        let raw = match self.db.evts.get::<_, Vec<u8>>(&[&key])? {
+            Some(raw) => raw,
+            None => return Ok(true),
+        };
+        let serder = SerderKERI::from_raw(&raw, None)?;
+
+        let mut sigers = Vec::new();
+        for sig in self.db.sigs.get::<_, Vec<u8>>(&[&key])? {
+            sigers.push(Siger::from_qb64(&String::from_utf8_lossy(&sig), None)?);
+        }
+        let mut wigers = Vec::new();
+        for wig in self.db.wigs.get::<_, Vec<u8>>(&[&key])? {
+            wigers.push(Siger::from_qb64(&String::from_utf8_lossy(&wig), None)?);
+        }
+        let local = match self.db.esrs.get(&[&key]) {
+            Ok(Some(esr)) => esr.local,
+            _ => false,
+        };
+
+        match self.process_event(
+            serder,
+            sigers,
+            Some(wigers),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(local),
+        ) {
+            Ok(()) => Ok(true),
+            Err(KERIError::OutOfOrderError(_)) | Err(KERIError::MissingSignatureError(_)) => {
+                Ok(false)
+            }
+            Err(e) => {
+                info!(
+                    "Kevery: dropped escrowed event pre={} dig={}: {}",
+                    pre,
+                    String::from_utf8_lossy(dig),
+                    e
+                );
+                Ok(true)
+            }
+        }
+    }
+
+    /// Checks that a query message is authorized by its querier before replying
+    ///
+    /// Verifies the query's signatures against the current signing keys of the
+    /// querier and requires they satisfy its current signing threshold.
+    ///
+    /// # Parameters
+    /// * `serder` - Query message serder
+    /// * `source` - Identifier prefix of querier
+    /// * `sigers` - List of Siger instances of attached controller indexed sigs
+    ///
+    /// # Returns
+    /// * `Result<bool, KERIError>` - true when the querier is known and its threshold
+    ///   is satisfied, false when unknown, unsigned or under threshold
+    pub fn authorize_query(
+        &self,
+        serder: &SerderKERI,
+        source: &Prefixer,
+        sigers: &[Siger],
+    ) -> Result<bool, KERIError> {
+        if serder.ilk() != Some(Ilk::Qry) {
+            return Err(KERIError::ValueError(format!(
+                "Expected qry message got ilk={:?}.",
+                serder.ilk()
+            )));
+        }
+
+        let kever = match self.kevers.get(&source.qb64()) {
+            Some(kever) => kever,
+            None => return Ok(false),
+        };
+        let (verfers, tholder) = match (kever.verfers(), kever.tholder()) {
+            (Some(verfers), Some(tholder)) => (verfers, tholder),
+            _ => return Ok(false),
+        };
+
+        let (_, indices) = verify_sigs(&serder.raw(), sigers.to_vec(), &verfers)?;
+        if indices.is_empty() {
+            return Ok(false);
+        }
+
+        Ok(tholder.satisfy(&indices))
+    }
+
     /// Process query mode replay message for collective or single element query.
     /// Assume promiscuous mode for now.
     ///
@@ -1221,10 +1651,11 @@ impl<'db> Kevery<'db> {
                     }
                 }
 
-                // Create reply with key state
-                let _state = kever.state();
+                // Create reply with key state, signed by the cue consumer
+                let state = kever.state()?;
                 let rserder = ReplyEventBuilder::new()
                     .with_route(format!("/ksn/{}", src))
+                    .with_data(state.to_map())
                     .build()?;
                 // Add reply cue
                 let cue = Cue {
@@ -1317,195 +1748,2728 @@ impl<'db> Kevery<'db> {
         Ok(())
     }
 
-    pub fn fully_witnessed(&self, serder: &SerderKERI) -> bool {
-        let preb = serder.preb().unwrap_or_default();
-        let said = serder.said().unwrap_or_default();
+    /// Load a pre-validated KEL snapshot from a trusted source without
+    /// re-verifying each event.
+    ///
+    /// Events are logged as first seen and key state is updated directly from
+    /// the event bodies. Signatures are stored but never verified, so this is
+    /// only allowed when the Kevery was built with `trusted` set.
+    ///
+    /// # Parameters
+    /// * `events` - Events in KEL order each with its qb64 indexed controller signatures
+    ///
+    /// # Returns
+    /// * `Result<usize, KERIError>` - Number of events loaded
+    pub fn load_trusted_snapshot(
+        &mut self,
+        events: &[(SerderKERI, Vec<String>)],
+    ) -> Result<usize, KERIError> {
+        if !self.trusted {
+            return Err(KERIError::ValidationError(
+                "Refusing to load unverified KEL snapshot into untrusted Kevery".to_string(),
+            ));
+        }
 
-        let key = dg_key(preb, said);
-        match self.db.wigs.get::<_, Vec<u8>>(&[&key]) {
-            Ok(wigs) => {
-                let pre = serder.pre().unwrap();
-                let kever = &self.kevers[&pre];
-                let toad = kever.toader().unwrap().num();
-                !wigs.len() < toad as usize
+        let mut count = 0;
+        for (serder, sigs) in events {
+            let pre = serder
+                .pre()
+                .ok_or_else(|| KERIError::ValueError("Missing pre in event".to_string()))?;
+            let sigers = sigs
+                .iter()
+                .map(|sig| Siger::from_qb64(sig, None))
+                .collect::<Result<Vec<Siger>, _>>()?;
+
+            match self.kevers.get_mut(&pre) {
+                Some(kever) => kever.update_trusted(serder.clone(), sigers)?,
+                None => {
+                    let mut kever = Kever::incept_trusted(self.db.clone(), serder.clone(), sigers)?;
+                    kever.no_key_reuse = self.no_key_reuse;
+                    self.kevers.insert(pre, kever);
+                }
             }
-            Err(_) => false,
+            count += 1;
         }
-    }
 
-    /// Escrow a query that couldn't be processed because the requested event wasn't found
-    fn escrow_query_not_found_event(
-        &self,
-        serder: &SerderKERI,
-        _source: Option<&Prefixer>,
-        _sigers: Option<&[Siger]>,
-        _cigars: Option<&[Cigar]>,
-    ) -> Result<(), KERIError> {
-        // Implementation details would go here
-        // This would store the query in an escrow database to be processed later
-        // when the requested event becomes available
+        Ok(count)
+    }
 
-        // For now we'll just log it
-        debug!(
-            "Escrowing query not found event with SAID: {}",
-            serder.said().unwrap_or_default()
-        );
+    /// Process a batch of events with all or nothing semantics
+    ///
+    /// The batch is replayed in order against a scratch database seeded with a
+    /// copy of the stored KEL of every prefix in the batch, so the replay sees the
+    /// same prior key state, establishment keys and duplicity as this Kevery's
+    /// database. The first invalid event aborts the batch before anything reaches
+    /// this Kevery's database. Otherwise everything the replay wrote is merged
+    /// into this Kevery's database in one write transaction and the key state of
+    /// each prefix in the batch is reloaded from it. Events that are escrowed as
+    /// out of order or partially signed are not invalid and do not abort the batch.
+    ///
+    /// # Parameters
+    /// * `items` - Events in KEL order each with its attached controller indexed sigs
+    ///
+    /// # Returns
+    /// * `Result<usize, KERIError>` - Number of events accepted, escrowed events
+    ///   are not counted
+    pub fn process_batch(&mut self, items: &[(SerderKERI, Vec<Siger>)]) -> Result<usize, KERIError> {
+        let lmdber = LMDBer::builder().temp(true).name("batch").build()?;
+        let scratch = Baser::new(Arc::new(&lmdber))?;
+        let mut dry = KeveryBuilder::new(Arc::new(&scratch))
+            .with_lax(self.lax)
+            .with_local(self.local)
+            .with_direct(self.direct)
+            .with_clock(self.clock.clone())
+            .with_skew(self.skew)
+            .with_no_key_reuse(self.no_key_reuse)
+            .build()?;
 
-        // TODO: Implement proper escrow functionality
-        Ok(())
-    }
-}
+        for (serder, _) in items {
+            let pre = serder
+                .pre()
+                .ok_or_else(|| KERIError::ValueError("Missing pre in event".to_string()))?;
+            if dry.kevers.contains_key(&pre) {
+                continue;
+            }
+            if let Some(kever) = self.kevers.get(&pre) {
+                self.copy_kel(&scratch, &pre)?;
+                let reloaded = KeverBuilder::new(Arc::new(&scratch))
+                    .with_state(kever.state()?)
+                    .with_no_key_reuse(kever.no_key_reuse)
+                    .build()?;
+                dry.kevers.insert(pre, reloaded);
+            }
+        }
 
-/// Builder pattern for Kevery to make initialization more ergonomic
-pub struct KeveryBuilder<'db> {
-    db: Arc<&'db Baser<'db>>,
-    cues: Option<VecDeque<Cue>>,
-    rvy: Option<Rvy<'db>>,
-    lax: Option<bool>,
-    local: Option<bool>,
-    cloned: Option<bool>,
-    direct: Option<bool>,
-    check: Option<bool>,
-}
+        let mut count = 0;
+        for (serder, sigers) in items {
+            if Self::accept_or_escrow(dry.process_event(
+                serder.clone(),
+                sigers.clone(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ))? {
+                count += 1;
+            }
+        }
 
-impl<'db> KeveryBuilder<'db> {
-    /// Create a new KeveryBuilder instance
-    pub fn new(db: Arc<&'db Baser<'db>>) -> Self {
-        Self {
-            db,
-            cues: None,
-            rvy: None,
-            lax: None,
-            local: None,
-            cloned: None,
-            direct: None,
-            check: None,
+        self.db.merge_from(&scratch)?;
+        for (pre, kever) in dry.kevers.iter() {
+            let reloaded = KeverBuilder::new(self.db.clone())
+                .with_state(kever.state()?)
+                .with_no_key_reuse(kever.no_key_reuse)
+                .build()?;
+            self.kevers.insert(pre.clone(), reloaded);
         }
-    }
+        self.cues.extend(dry.cues.drain(..));
 
-    /// Set the cues for the Kevery instance
-    pub fn with_cues(mut self, cues: VecDeque<Cue>) -> Self {
-        self.cues = Some(cues);
-        self
+        Ok(count)
     }
 
-    /// Set the recovery module for the Kevery instance
-    pub fn with_rvy(mut self, rvy: Rvy<'db>) -> Self {
-        self.rvy = Some(rvy);
-        self
+    /// Maps the result of processing one batch event to whether it was accepted,
+    /// treating an escrowed event as not accepted rather than as an error
+    fn accept_or_escrow(result: Result<(), KERIError>) -> Result<bool, KERIError> {
+        match result {
+            Ok(()) => Ok(true),
+            Err(KERIError::OutOfOrderError(_)) | Err(KERIError::MissingSignatureError(_)) => {
+                Ok(false)
+            }
+            Err(e) => Err(e),
+        }
     }
 
-    /// Set the lax mode for the Kevery instance
-    pub fn with_lax(mut self, lax: bool) -> Self {
-        self.lax = Some(lax);
-        self
-    }
+    /// Copies the first seen KEL of `pre` with its signatures and indices into
+    /// `other` so a Kever reloaded there validates against the same history
+    fn copy_kel(&self, other: &Baser, pre: &str) -> Result<(), KERIError> {
+        for (fn_num, dig) in self.db.fel_digs(pre)? {
+            let key = dg_key(pre, &dig);
+            let raw = match self.db.evts.get::<_, Vec<u8>>(&[&key])? {
+                Some(raw) => raw,
+                None => continue,
+            };
+            let serder = SerderKERI::from_raw(&raw, None)?;
+            other.evts.pin(&[&key], &raw)?;
+            for sig in self.db.sigs.get::<_, Vec<u8>>(&[&key])? {
+                other.sigs.add(&[&key], &sig)?;
+            }
+            for wig in self.db.wigs.get::<_, Vec<u8>>(&[&key])? {
+                other.wigs.add(&[&key], &wig)?;
+            }
+            for wit in self.db.wits.get::<_, Vec<u8>>(&[&key])? {
+                other.wits.add(&[&key], &wit)?;
+            }
+            if let Some(fner) = self.db.fons.get(&[&key])? {
+                other.fons.pin(&[&key], &fner)?;
+            }
+            other.fels.pin_on(&[pre], fn_num, &dig)?;
+            other
+                .kels
+                .add_on(&[pre], serder.sn().unwrap_or_default(), &dig)?;
+        }
 
-    /// Set the local flag for the Kevery instance
-    pub fn with_local(mut self, local: bool) -> Self {
-        self.local = Some(local);
-        self
+        Ok(())
     }
 
-    /// Set the cloned flag for the Kevery instance
-    pub fn with_cloned(mut self, cloned: bool) -> Self {
-        self.cloned = Some(cloned);
+    /// Process the events of one KEL received tip first in a reverse sync
+    ///
+    /// Events are buffered from the tip down until the inception or the event
+    /// following the current key state of the prefix is reached. The buffer is
+    /// then applied in forward order skipping events at or below the current key
+    /// state. Nothing is applied when the events do not reach down that far.
+    /// Application stops at the first event that fails to process so the events
+    /// accepted before it remain and their count is returned.
+    ///
+    /// # Parameters
+    /// * `events` - Events of a single prefix in strictly descending sn order each
+    ///   with its attached controller indexed sigs
+    ///
+    /// # Returns
+    /// * `Result<usize, KERIError>` - Number of events accepted before any failure,
+    ///   ValidationError if the events are not one contiguous descending run that
+    ///   reaches the inception or the current key state
+    pub fn process_reverse_kel(
+        &mut self,
+        events: &[(SerderKERI, Vec<Siger>)],
+    ) -> Result<usize, KERIError> {
+        let (tip, _) = match events.first() {
+            Some(event) => event,
+            None => return Ok(0),
+        };
+        let pre = tip
+            .pre()
+            .ok_or_else(|| KERIError::ValueError("Missing pre in event".to_string()))?;
+        let known = self
+            .kevers
+            .get(&pre)
+            .and_then(|kever| kever.sner())
+            .map(|sner| sner.num() as u64);
+
+        let mut buffer = Vec::new();
+        let mut reached = false;
+        for (serder, sigers) in events {
+            let sn = serder.sn().unwrap_or_default();
+            if serder.pre().as_deref() != Some(pre.as_str()) {
+                return Err(KERIError::ValidationError(format!(
+                    "Mixed prefixes in reverse KEL for pre={}.",
+                    pre
+                )));
+            }
+            if let Some((prior, _)) = buffer.last() {
+                if sn.checked_add(1) != Some(*prior) {
+                    return Err(KERIError::ValidationError(format!(
+                        "Gap in reverse KEL for pre={} at sn={}.",
+                        pre, sn
+                    )));
+                }
+            }
+            buffer.push((sn, (serder, sigers)));
+
+            let next = known.map(|known| known.checked_add(1));
+            if sn == 0 || next.is_some_and(|next| next.map_or(true, |next| sn <= next)) {
+                reached = true;
+                break;
+            }
+        }
+
+        if !reached {
+            return Err(KERIError::ValidationError(format!(
+                "Reverse KEL for pre={} does not reach inception or key state.",
+                pre
+            )));
+        }
+
+        let mut count = 0;
+        for (sn, (serder, sigers)) in buffer.into_iter().rev() {
+            if known.is_some_and(|known| sn <= known) {
+                continue;
+            }
+            let result = self.process_event(
+                serder.clone(),
+                sigers.clone(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            );
+            if let Err(e) = result {
+                debug!("Reverse KEL for pre={} stopped at sn={}: {}", pre, sn, e);
+                break;
+            }
+            let accepted = self
+                .kevers
+                .get(&pre)
+                .and_then(|kever| kever.sner())
+                .is_some_and(|sner| sner.num() as u64 == sn);
+            if accepted {
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Anchor an ACDC issuance in the issuer's KEL
+    ///
+    /// Builds an interaction event whose `a` field holds a digest seal of the
+    /// credential SAID, signs it with `signer` and processes it as the next event
+    /// in the issuer's KEL.
+    ///
+    /// # Parameters
+    /// * `issuer_pre` - Identifier prefix of the issuer
+    /// * `creder` - Credential being issued
+    /// * `signer` - Signer holding one of the issuer's current signing keys
+    ///
+    /// # Returns
+    /// * `Result<SerderKERI, KERIError>` - The accepted anchoring interaction event
+    pub fn anchor_acdc(
+        &mut self,
+        issuer_pre: &str,
+        creder: &Creder,
+        signer: &Signer,
+    ) -> Result<SerderKERI, KERIError> {
+        if creder.issuer() != issuer_pre {
+            return Err(KERIError::ValueError(format!(
+                "ACDC issuer={} does not match pre={}.",
+                creder.issuer(),
+                issuer_pre
+            )));
+        }
+
+        let kever = self.kevers.get(issuer_pre).ok_or_else(|| {
+            KERIError::MissingEntryError(format!("Missing key state for pre={}.", issuer_pre))
+        })?;
+        let sn = kever.sner().map(|sner| sner.num() as usize).unwrap_or_default() + 1;
+        let dig = kever
+            .serder()
+            .and_then(|serder| serder.said().map(|said| said.to_string()))
+            .ok_or_else(|| {
+                KERIError::MissingEntryError(format!("Missing latest event for pre={}.", issuer_pre))
+            })?;
+        let index = kever
+            .verfers()
+            .unwrap_or_default()
+            .iter()
+            .position(|verfer| verfer.qb64() == signer.verfer().qb64())
+            .ok_or_else(|| {
+                KERIError::ValueError(format!(
+                    "Signer key={} is not a current key of pre={}.",
+                    signer.verfer().qb64(),
+                    issuer_pre
+                ))
+            })?;
+
+        let mut seal = IndexMap::new();
+        seal.insert(
+            "d".to_string(),
+            SadValue::String(creder.said().to_string()),
+        );
+        let serder = InteractEventBuilder::new(issuer_pre.to_string(), dig)
+            .with_sn(sn)
+            .with_data_list(vec![SadValue::Object(seal)])
+            .build()?;
+
+        let siger = match signer.sign(serder.raw(), Some(index as u32), None, None)? {
+            Sigmat::Indexed(siger) => siger,
+            _ => {
+                return Err(KERIError::ValueError(
+                    "Expected indexed signature".to_string(),
+                ))
+            }
+        };
+        self.process_event(
+            serder.clone(),
+            vec![siger],
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+
+        Ok(serder)
+    }
+
+    /// Reject an attached datetime that is later than now plus the skew tolerance
+    ///
+    /// # Parameters
+    /// * `dater` - Attached datetime to check
+    ///
+    /// # Returns
+    /// * `Result<(), KERIError>` - FutureDated if dater exceeds .clock now plus .skew
+    pub fn check_future_dated(&self, dater: &Dater) -> Result<(), KERIError> {
+        let dt = dater.dt()?;
+        let limit = self.clock.now() + chrono::Duration::seconds(self.skew as i64);
+        if dt > limit {
+            return Err(KERIError::FutureDated(format!(
+                "Datetime {} is later than {} allowed with skew of {}s.",
+                dater.dts(),
+                limit.to_rfc3339(),
+                self.skew
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Verify that an ACDC is anchored in its issuer's KEL
+    ///
+    /// Scans the issuer's first seen event log for an event whose `a` field holds
+    /// a seal with a `d` matching the credential SAID.
+    ///
+    /// # Parameters
+    /// * `creder` - Credential to verify
+    ///
+    /// # Returns
+    /// * `Result<bool, KERIError>` - true if an anchoring seal was found, false if
+    ///   not or if the issuer's KEL is unknown
+    pub fn verify_acdc_anchor(&self, creder: &Creder) -> Result<bool, KERIError> {
+        let pre = creder.issuer();
+        for (_, dig) in self.db.fel_digs(&pre)? {
+            let raw = match self.db.evts.get::<_, Vec<u8>>(&[&dg_key(&pre, &dig)])? {
+                Some(raw) => raw,
+                None => continue,
+            };
+            let serder = SerderKERI::from_raw(&raw, None)?;
+            if let Some(SadValue::Array(seals)) = serder.ked().get("a") {
+                let anchored = seals.iter().any(|seal| {
+                    seal.get("d").and_then(|d| d.as_str()) == Some(creder.said())
+                });
+                if anchored {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Verify a delegated event's seal source couple against its delegator's KEL
+    ///
+    /// The delegator is taken from `di` of a delegated inception or from the key
+    /// state of the delegate for a delegated rotation. The couple must name the
+    /// event accepted by the delegator at sn and that event must hold an event seal
+    /// `(i, s, d)` of the delegated event in its `a` field.
+    ///
+    /// # Parameters
+    /// * `delegated` - Delegated inception or rotation event
+    /// * `couple` - Seal source couple (sn, dig) of the delegator's anchoring event
+    ///
+    /// # Returns
+    /// * `Result<bool, KERIError>` - true if the couple matches an anchoring event,
+    ///   ValueError if the delegator cannot be determined
+    pub fn verify_delegation_couple(
+        &self,
+        delegated: &SerderKERI,
+        couple: (u64, String),
+    ) -> Result<bool, KERIError> {
+        let pre = delegated.pre().unwrap_or_default();
+        let delpre = delegated
+            .delpre()
+            .or_else(|| self.kevers.get(&pre).and_then(|kever| kever.delpre()))
+            .ok_or_else(|| {
+                KERIError::ValueError(format!("Missing delegator for pre={}.", pre))
+            })?;
+
+        let (sn, dig) = couple;
+        match self.db.kels.get_last::<_, Vec<u8>>(&[&sn_key(&delpre, sn)])? {
+            Some(ldig) if ldig == dig.as_bytes() => {}
+            _ => return Ok(false),
+        }
+
+        let raw = match self.db.evts.get::<_, Vec<u8>>(&[&dg_key(&delpre, &dig)])? {
+            Some(raw) => raw,
+            None => return Ok(false),
+        };
+        let serder = SerderKERI::from_raw(&raw, None)?;
+        let snh = delegated.snh().unwrap_or_default();
+        let said = delegated.said().unwrap_or_default();
+        if let Some(SadValue::Array(seals)) = serder.ked().get("a") {
+            return Ok(seals.iter().any(|seal| {
+                seal.get("i").and_then(|i| i.as_str()) == Some(pre.as_str())
+                    && seal.get("s").and_then(|s| s.as_str()) == Some(snh.as_str())
+                    && seal.get("d").and_then(|d| d.as_str()) == Some(said)
+            }));
+        }
+
+        Ok(false)
+    }
+
+    /// Process a `/loc/scheme` reply advertising the url at which an endpoint
+    /// controller serves in a role
+    ///
+    /// The `a` field names the controller `eid` of the endpoint, its `role`, the
+    /// `scheme` and the `url`. The reply must be signed under the key state of
+    /// `eid`, by indexed signatures satisfying the signing threshold of its Kever
+    /// when `eid` is transferable or by a signature of `eid` itself when it is
+    /// nontransferable. Replies are accepted best available data first so a reply
+    /// no later than the one already accepted for (eid, role, scheme) is rejected.
+    /// On acceptance .lans maps (eid, role, scheme) to the reply SAID and .locs
+    /// maps it to the url.
+    ///
+    /// # Parameters
+    /// * `serder` - Reply message with route `/loc/scheme`
+    /// * `sigers` - Attached indexed signatures of a transferable `eid`
+    /// * `cigars` - Attached nontransferable signatures with .verfer set
+    ///
+    /// # Returns
+    /// * `Result<(), KERIError>` - ValidationError if the route, role, scheme or
+    ///   signatures are invalid, StaleReply if the reply is no later than the one
+    ///   already accepted, FutureDated if its dt is later than now plus .skew
+    pub fn process_loc_scheme(
+        &mut self,
+        serder: SerderKERI,
+        sigers: Vec<Siger>,
+        cigars: Vec<Cigar>,
+    ) -> Result<(), KERIError> {
+        let ked = serder.ked();
+        let route = ked.get("r").and_then(|r| r.as_str()).unwrap_or_default();
+        if serder.ilk() != Some(Ilk::Rpy) || route != "/loc/scheme" {
+            return Err(KERIError::ValidationError(format!(
+                "Invalid route={} for location scheme reply.",
+                route
+            )));
+        }
+
+        let data = match ked.get("a") {
+            Some(SadValue::Object(data)) => data,
+            _ => {
+                return Err(KERIError::FieldError(
+                    "Missing data (a) in location scheme reply.".to_string(),
+                ))
+            }
+        };
+        let field = |label: &str| {
+            data.get(label)
+                .and_then(|v| v.as_str())
+                .map(|v| v.to_string())
+                .ok_or_else(|| {
+                    KERIError::FieldError(format!("Missing {} in location scheme reply.", label))
+                })
+        };
+        let eid = field("eid")?;
+        let role = field("role")?;
+        let scheme = field("scheme")?;
+        let url = field("url")?;
+        if role.parse::<Roles>().is_err() {
+            return Err(KERIError::ValidationError(format!(
+                "Invalid role={} for eid={}.",
+                role, eid
+            )));
+        }
+        if !["http", "https", "tcp"].contains(&scheme.as_str()) {
+            return Err(KERIError::ValidationError(format!(
+                "Invalid scheme={} for eid={}.",
+                scheme, eid
+            )));
+        }
+
+        let said = serder.said().unwrap_or_default().to_string();
+        let saider = Saider::from_qb64(&said)?;
+        let dater = Dater::from_dts(ked.get("dt").and_then(|v| v.as_str()).ok_or_else(|| {
+            KERIError::FieldError("Missing dt in location scheme reply.".to_string())
+        })?)?;
+        self.check_future_dated(&dater)?;
+
+        let keys = [eid.as_str(), role.as_str(), scheme.as_str()];
+        let osaider = self.db.lans.get(&keys)?;
+        if let Some(ref osaider) = osaider {
+            if let Some(odater) = self.db.sdts.get(&[&osaider.qb64()])? {
+                if dater.dt()? <= odater.dt()? {
+                    return Err(KERIError::StaleReply(format!(
+                        "Location scheme reply said={} for eid={} is not later than said={}.",
+                        said,
+                        eid,
+                        osaider.qb64()
+                    )));
+                }
+            }
+        }
+
+        let prefixer = Prefixer::from_qb64(&eid)?;
+        let skeys = [said.as_str()];
+        if prefixer.transferable() {
+            let kever = self.kevers.get(&eid).ok_or_else(|| {
+                KERIError::ValidationError(format!(
+                    "Unknown key state for eid={} on reply said={}.",
+                    eid, said
+                ))
+            })?;
+            let (verfers, tholder) = match (kever.verfers(), kever.tholder()) {
+                (Some(verfers), Some(tholder)) => (verfers, tholder),
+                _ => {
+                    return Err(KERIError::ValidationError(format!(
+                        "Missing key state for eid={}.",
+                        eid
+                    )))
+                }
+            };
+            let (sigers, indices) = verify_sigs(serder.raw(), sigers, &verfers)?;
+            if !tholder.satisfy(&indices) {
+                return Err(KERIError::ValidationError(format!(
+                    "Signatures of eid={} fail threshold on reply said={}.",
+                    eid, said
+                )));
+            }
+
+            let est = kever.last_est().ok_or_else(|| {
+                KERIError::ValidationError(format!("Missing establishment event for eid={}.", eid))
+            })?;
+            let sigers: Vec<&dyn Matter> = sigers.iter().map(|s| s as &dyn Matter).collect();
+            self.db.ssgs.put(
+                &[said.as_str(), eid.as_str(), &format!("{:032x}", est.s), &est.d],
+                &sigers,
+            )?;
+        } else {
+            let mut verified = None;
+            for cigar in cigars {
+                let verfer = match cigar.verfer() {
+                    Some(verfer) if verfer.qb64() == eid => verfer.clone(),
+                    _ => continue,
+                };
+                if verfer.verify(cigar.raw(), serder.raw())? {
+                    verified = Some((verfer, cigar));
+                    break;
+                }
+            }
+            let (verfer, cigar) = verified.ok_or_else(|| {
+                KERIError::ValidationError(format!(
+                    "Missing valid signature from eid={} on reply said={}.",
+                    eid, said
+                ))
+            })?;
+            self.db.scgs.put(&skeys, &[&verfer, &cigar])?;
+        }
+
+        self.db.sdts.put(&skeys, &dater)?;
+        self.db.rpys.put(&skeys, &serder)?;
+        self.db.lans.pin(&keys, &saider)?;
+        self.db.locs.pin(&keys, &LocationRecord::new(url))?;
+
+        if let Some(osaider) = osaider {
+            if osaider.qb64() != said {
+                let okeys = [osaider.qb64()];
+                self.db.ssgs.trim(&[okeys[0].as_str(), ""], true)?;
+                self.db.scgs.rem(&okeys, None)?;
+                self.db.rpys.rem(&okeys)?;
+                self.db.sdts.rem(&okeys)?;
+            }
+        }
+
+        info!(
+            "Kevery: accepted location scheme reply for eid={} role={} scheme={}",
+            eid, role, scheme
+        );
+
+        Ok(())
+    }
+
+    pub fn fully_witnessed(&self, serder: &SerderKERI) -> bool {
+        let preb = serder.preb().unwrap_or_default();
+        let said = serder.said().unwrap_or_default();
+
+        let key = dg_key(preb, said);
+        match self.db.wigs.get::<_, Vec<u8>>(&[&key]) {
+            Ok(wigs) => {
+                let pre = serder.pre().unwrap();
+                let kever = &self.kevers[&pre];
+                let toad = kever.toader().unwrap().num();
+                wigs.len() >= toad as usize
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Advance the witnessed sn marker of an accepted event once fully witnessed
+    ///
+    /// # Parameters
+    /// * `serder` - Event to check, ignored unless it is the event accepted at its sn
+    ///
+    /// # Returns
+    /// * `Result<bool, KERIError>` - true if the marker was advanced
+    fn mark_witnessed(&self, serder: &SerderKERI) -> Result<bool, KERIError> {
+        let (pre, sn, said) = match (serder.pre(), serder.sn(), serder.said()) {
+            (Some(pre), Some(sn), Some(said)) => (pre, sn, said),
+            _ => return Ok(false),
+        };
+        if !self.kevers.contains_key(&pre) {
+            return Ok(false);
+        }
+        match self.db.kels.get_last::<_, Vec<u8>>(&[&sn_key(&pre, sn)])? {
+            Some(ldig) if ldig == said.as_bytes() => {}
+            _ => return Ok(false),
+        }
+        if !self.fully_witnessed(serder) {
+            return Ok(false);
+        }
+
+        self.db.set_witnessed_sn(&pre, sn)
+    }
+
+    /// Escrow a query that couldn't be processed because the requested event wasn't found
+    fn escrow_query_not_found_event(
+        &self,
+        serder: &SerderKERI,
+        _source: Option<&Prefixer>,
+        _sigers: Option<&[Siger]>,
+        _cigars: Option<&[Cigar]>,
+    ) -> Result<(), KERIError> {
+        // Implementation details would go here
+        // This would store the query in an escrow database to be processed later
+        // when the requested event becomes available
+
+        // For now we'll just log it
+        debug!(
+            "Escrowing query not found event with SAID: {}",
+            serder.said().unwrap_or_default()
+        );
+
+        // TODO: Implement proper escrow functionality
+        Ok(())
+    }
+}
+
+/// Builder pattern for Kevery to make initialization more ergonomic
+pub struct KeveryBuilder<'db> {
+    db: Arc<&'db Baser<'db>>,
+    cues: Option<VecDeque<Cue>>,
+    rvy: Option<Rvy<'db>>,
+    lax: Option<bool>,
+    local: Option<bool>,
+    cloned: Option<bool>,
+    direct: Option<bool>,
+    check: Option<bool>,
+    trusted: Option<bool>,
+    clock: Option<Arc<dyn Clock>>,
+    skew: Option<u64>,
+    max_escrow_attempts: Option<u64>,
+    no_key_reuse: Option<bool>,
+}
+
+impl<'db> KeveryBuilder<'db> {
+    /// Create a new KeveryBuilder instance
+    pub fn new(db: Arc<&'db Baser<'db>>) -> Self {
+        Self {
+            db,
+            cues: None,
+            rvy: None,
+            lax: None,
+            local: None,
+            cloned: None,
+            direct: None,
+            check: None,
+            trusted: None,
+            clock: None,
+            skew: None,
+            max_escrow_attempts: None,
+            no_key_reuse: None,
+        }
+    }
+
+    /// Set the cues for the Kevery instance
+    pub fn with_cues(mut self, cues: VecDeque<Cue>) -> Self {
+        self.cues = Some(cues);
+        self
+    }
+
+    /// Set the recovery module for the Kevery instance
+    pub fn with_rvy(mut self, rvy: Rvy<'db>) -> Self {
+        self.rvy = Some(rvy);
+        self
+    }
+
+    /// Set the lax mode for the Kevery instance
+    pub fn with_lax(mut self, lax: bool) -> Self {
+        self.lax = Some(lax);
+        self
+    }
+
+    /// Set the local flag for the Kevery instance
+    pub fn with_local(mut self, local: bool) -> Self {
+        self.local = Some(local);
+        self
+    }
+
+    /// Set the cloned flag for the Kevery instance
+    pub fn with_cloned(mut self, cloned: bool) -> Self {
+        self.cloned = Some(cloned);
+        self
+    }
+
+    /// Set the direct mode for the Kevery instance
+    pub fn with_direct(mut self, direct: bool) -> Self {
+        self.direct = Some(direct);
+        self
+    }
+
+    /// Set the check mode for the Kevery instance
+    pub fn with_check(mut self, check: bool) -> Self {
+        self.check = Some(check);
+        self
+    }
+
+    /// Set the trusted mode for the Kevery instance
+    pub fn with_trusted(mut self, trusted: bool) -> Self {
+        self.trusted = Some(trusted);
         self
     }
 
-    /// Set the direct mode for the Kevery instance
-    pub fn with_direct(mut self, direct: bool) -> Self {
-        self.direct = Some(direct);
-        self
+    /// Set the clock used for time dependent validation
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Set the skew tolerance in seconds for attached datetimes
+    pub fn with_skew(mut self, skew: u64) -> Self {
+        self.skew = Some(skew);
+        self
+    }
+
+    /// Set the failed reprocessing attempts after which an escrowed event is
+    /// moved to dead letters
+    pub fn with_max_escrow_attempts(mut self, max_escrow_attempts: u64) -> Self {
+        self.max_escrow_attempts = Some(max_escrow_attempts);
+        self
+    }
+
+    /// Set the flag making Kevers reject rotations that reuse prior
+    /// establishment keys
+    pub fn with_no_key_reuse(mut self, no_key_reuse: bool) -> Self {
+        self.no_key_reuse = Some(no_key_reuse);
+        self
+    }
+
+    /// Build the Kevery instance from the provided options
+    pub fn build(self) -> Result<Kevery<'db>, KERIError> {
+        let mut kevery = Kevery::new(
+            self.cues,
+            self.db.clone(),
+            self.rvy,
+            self.lax,
+            self.local,
+            self.cloned,
+            self.direct,
+            self.check,
+        )?;
+        kevery.trusted = self.trusted.unwrap_or(false);
+        if let Some(clock) = self.clock {
+            kevery.clock = clock;
+        }
+        if let Some(skew) = self.skew {
+            kevery.skew = skew;
+        }
+        kevery.max_escrow_attempts = self.max_escrow_attempts;
+        kevery.no_key_reuse = self.no_key_reuse.unwrap_or(false);
+        Ok(kevery)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::cesr::counting::{ctr_dex_1_0, BaseCounter, Counter};
+    use crate::cesr::diger::Diger;
+    use crate::cesr::mtr_dex;
+    use crate::cesr::number::Number;
+    use crate::cesr::signing::Salter;
+    use crate::cesr::streaming::parse_indexed_sigs;
+    use crate::cesr::Parsable;
+    use crate::keri::core::eventing::query::QueryEventBuilder;
+    use crate::keri::core::eventing::receipt::ReceiptEventBuilder;
+    use crate::keri::core::eventing::rotate::RotateEventBuilder;
+    use crate::keri::core::eventing::InceptionEventBuilder;
+    use crate::keri::core::seal::{event_seal, seal_sad};
+    use crate::keri::db::basing::{EndpointRecord, KeyStateRecord};
+    use crate::keri::{Ilks, Kinds};
+
+    /// Salter behind the signers of `build_kel`
+    pub(crate) fn test_salter() -> Result<Salter, KERIError> {
+        let salt = b"g\x15\x89\x1a@\xa4\xa47\x07\xb9Q\xb8\x18\xcdJW";
+        Ok(Salter::new(Some(salt), None, None)?)
+    }
+
+    /// Salter for signers unrelated to the `build_kel` prefix
+    pub(crate) fn other_salter() -> Result<Salter, KERIError> {
+        Ok(Salter::new(Some(b"0123456789abcdef"), None, None)?)
+    }
+
+    /// First `count` transferable signers of `test_salter`
+    pub(crate) fn kel_signers(count: usize) -> Result<Vec<Signer>, KERIError> {
+        Ok(test_salter()?.signers(count, 0, "", None, None, None, false)?)
+    }
+
+    /// Sign `serder` with `signer` at key index `index`
+    pub(crate) fn sign_indexed(
+        signer: &Signer,
+        serder: &SerderKERI,
+        index: u32,
+    ) -> Result<Siger, KERIError> {
+        match signer.sign(serder.raw(), Some(index), None, None)? {
+            Sigmat::Indexed(siger) => Ok(siger),
+            _ => Err(KERIError::ValueError(
+                "Expected indexed signature".to_string(),
+            )),
+        }
+    }
+
+    /// Sign `serder` with `signer` at key index 0
+    pub(crate) fn sign_event(signer: &Signer, serder: &SerderKERI) -> Result<Siger, KERIError> {
+        sign_indexed(signer, serder, 0)
+    }
+
+    /// Sign `serder` with the nontransferable `signer`
+    pub(crate) fn sign_cigar(signer: &Signer, serder: &SerderKERI) -> Result<Cigar, KERIError> {
+        match signer.sign(serder.raw(), None, None, None)? {
+            Sigmat::NonIndexed(cigar) => Ok(cigar),
+            _ => Err(KERIError::ValueError(
+                "Expected non-indexed signature".to_string(),
+            )),
+        }
+    }
+
+    /// Build a signed icp, rot, ixn sequence for a single transferable prefix
+    pub(crate) fn build_kel() -> Result<Vec<(SerderKERI, Vec<Siger>)>, KERIError> {
+        let signers = kel_signers(3)?;
+
+        let keys0 = vec![signers[0].verfer().qb64()];
+        let keys1 = vec![signers[1].verfer().qb64()];
+        let nxt1 = vec![Diger::from_ser(&signers[1].verfer().qb64b(), None)?.qb64()];
+        let nxt2 = vec![Diger::from_ser(&signers[2].verfer().qb64b(), None)?.qb64()];
+
+        let icp = InceptionEventBuilder::new(keys0).with_ndigs(nxt1).build()?;
+        let pre = icp.pre().unwrap();
+        let rot = RotateEventBuilder::new(pre.clone(), keys1, icp.said().unwrap().to_string())
+            .with_sn(1)
+            .with_ndigs(nxt2)
+            .build()?;
+        let ixn = InteractEventBuilder::new(pre, rot.said().unwrap().to_string())
+            .with_sn(2)
+            .build()?;
+
+        Ok(vec![
+            (icp.clone(), vec![sign_event(&signers[0], &icp)?]),
+            (rot.clone(), vec![sign_event(&signers[1], &rot)?]),
+            (ixn.clone(), vec![sign_event(&signers[1], &ixn)?]),
+        ])
+    }
+
+    /// Open a temporary database named `name`
+    pub(crate) fn setup_db(name: &str) -> Result<LMDBer, KERIError> {
+        LMDBer::builder()
+            .temp(true)
+            .name(name)
+            .build()
+            .map_err(|e| KERIError::DatabaseError(format!("{}", e)))
+    }
+
+    /// Open a Baser over `lmdber`
+    pub(crate) fn open_baser(lmdber: &LMDBer) -> Result<Baser<'_>, KERIError> {
+        Baser::new(Arc::new(lmdber)).map_err(|e| KERIError::DatabaseError(format!("{}", e)))
+    }
+
+    /// Build a lax nonlocal Kevery over `db`
+    pub(crate) fn setup_kevery<'db>(db: &'db Baser<'db>) -> Result<Kevery<'db>, KERIError> {
+        KeveryBuilder::new(Arc::new(db))
+            .with_lax(true)
+            .with_local(false)
+            .build()
+    }
+
+    /// Process each signed event of `kel` in order with no other attachments
+    pub(crate) fn ingest(
+        kevery: &mut Kevery,
+        kel: &[(SerderKERI, Vec<Siger>)],
+    ) -> Result<(), KERIError> {
+        for (serder, sigers) in kel {
+            kevery.process_event(
+                serder.clone(),
+                sigers.clone(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Build a compact ACDC issued by `pre` with salty nonce `u`
+    pub(crate) fn make_creder(pre: &str, u: &str) -> Result<Creder, KERIError> {
+        let mut sad = IndexMap::new();
+        sad.insert("v".to_string(), SadValue::String("ACDC10JSON000000_".to_string()));
+        sad.insert("d".to_string(), SadValue::String("".to_string()));
+        sad.insert("u".to_string(), SadValue::String(u.to_string()));
+        sad.insert("i".to_string(), SadValue::String(pre.to_string()));
+        sad.insert(
+            "s".to_string(),
+            SadValue::String("EBG9LuUbFzV4OV5cGS9IeQWzy9SuyVFyVrpRc4l1xzPA".to_string()),
+        );
+        let (_, sad) = Saider::saidify(sad, None, None, None, None)?;
+        Creder::from_json(&SadValue::dumps(&sad, &Kinds::Json)?)
+    }
+
+    #[test]
+    fn test_kevery_new() -> Result<(), KERIError> {
+        // Create a temporary database
+        let lmdber = setup_db("test_kevery")?;
+
+        let db = open_baser(&lmdber)?;
+
+        // Create Kevery using the new function
+        let kevery = Kevery::new(
+            None,
+            Arc::new(&db),
+            None,
+            Some(true),
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(false),
+        )?;
+
+        assert!(kevery.lax);
+        assert!(!kevery.local);
+        assert!(!kevery.cloned);
+        assert!(kevery.direct);
+        assert!(!kevery.check);
+        assert!(kevery.kevers().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_kevery_builder() -> Result<(), KERIError> {
+        // Create a temporary database
+        let lmdber = setup_db("test_kevery_builder")?;
+
+        let db = open_baser(&lmdber)?;
+
+        // Create Kevery using the builder pattern
+        let kevery = KeveryBuilder::new(Arc::new(&db))
+            .with_lax(true)
+            .with_local(false)
+            .with_cloned(false)
+            .with_direct(true)
+            .with_check(false)
+            .build()?;
+
+        assert!(kevery.lax);
+        assert!(!kevery.local);
+        assert!(!kevery.cloned);
+        assert!(kevery.direct);
+        assert!(!kevery.check);
+        assert!(kevery.kevers().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_kevery_load_trusted_snapshot() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        // Fully verified load
+        let vlmdber = setup_db("test_kevery_verified")?;
+        let vdb = open_baser(&vlmdber)?;
+        let mut verified = setup_kevery(&vdb)?;
+        ingest(&mut verified, &kel)?;
+
+        let snapshot: Vec<(SerderKERI, Vec<String>)> = kel
+            .iter()
+            .map(|(serder, sigers)| (serder.clone(), sigers.iter().map(|s| s.qb64()).collect()))
+            .collect();
+
+        // Trusted load
+        let tlmdber = setup_db("test_kevery_trusted")?;
+        let tdb = open_baser(&tlmdber)?;
+
+        let mut untrusted = KeveryBuilder::new(Arc::new(&tdb)).build()?;
+        assert!(untrusted.load_trusted_snapshot(&snapshot).is_err());
+        assert!(untrusted.kevers().is_empty());
+
+        let mut trusted = KeveryBuilder::new(Arc::new(&tdb))
+            .with_trusted(true)
+            .build()?;
+        assert_eq!(trusted.load_trusted_snapshot(&snapshot)?, 3);
+
+        let vstate = verified.kevers().get(&pre).unwrap().state()?;
+        let tstate = trusted.kevers().get(&pre).unwrap().state()?;
+        assert_eq!(tstate.i, vstate.i);
+        assert_eq!(tstate.s, vstate.s);
+        assert_eq!(tstate.d, vstate.d);
+        assert_eq!(tstate.f, vstate.f);
+        assert_eq!(tstate.k, vstate.k);
+        assert_eq!(tstate.n, vstate.n);
+        assert_eq!(tstate.ee, vstate.ee);
+
+        let dbstate = tdb.states.get(&[&pre])?.unwrap();
+        assert_eq!(dbstate, tstate);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rebuild_state() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_rebuild_state")?;
+        let db = open_baser(&lmdber)?;
+
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+        let expected = kevery.kevers().get(&pre).unwrap().state()?;
+
+        // Corrupt the cached key state
+        let mut corrupt = expected.clone();
+        corrupt.k = vec![kel[0].0.verfers().unwrap()[0].qb64()];
+        corrupt.s = "0".to_string();
+        db.states.pin(&[&pre], &corrupt)?;
+
+        let keys: Vec<String> = kel[1].0.verfers().unwrap().iter().map(|v| v.qb64()).collect();
+
+        let state = db.rebuild_state(&pre, false)?;
+        assert_eq!(state.k, keys);
+        assert_eq!(state.i, expected.i);
+        assert_eq!(state.s, expected.s);
+        assert_eq!(state.d, expected.d);
+        assert_eq!(state.f, expected.f);
+        assert_eq!(state.n, expected.n);
+        assert_eq!(state.ee, expected.ee);
+        assert_eq!(db.states.get(&[&pre])?.unwrap(), corrupt);
+
+        let state = db.rebuild_state(&pre, true)?;
+        assert_eq!(state.k, keys);
+        assert_eq!(db.states.get(&[&pre])?.unwrap(), state);
+
+        assert!(db.rebuild_state("EUnknownPrefix", false).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_kel_chain_hash() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_kel_chain_hash")?;
+        let db = open_baser(&lmdber)?;
+
+        let mut kevery = setup_kevery(&db)?;
+
+        let mut hashes = Vec::new();
+        for event in kel.chunks(1) {
+            ingest(&mut kevery, event)?;
+            hashes.push(db.kel_chain_hash(&pre)?);
+        }
+
+        // Each accepted event advances the accumulator
+        assert_ne!(hashes[0], hashes[1]);
+        assert_ne!(hashes[1], hashes[2]);
+        assert_eq!(db.recompute_kel_chain_hash(&pre)?, hashes[2]);
+
+        // Alter the stored rotation event out of band
+        let rot = &kel[1].0;
+        let key = dg_key(&pre, rot.said().unwrap());
+        let mut raw = rot.raw().to_vec();
+        let last = raw.len() - 2;
+        raw[last] = b' ';
+        db.evts.pin(&[&key], &raw)?;
+
+        let tampered = db.recompute_kel_chain_hash(&pre)?;
+        assert_ne!(tampered, hashes[2]);
+        assert_eq!(db.kel_chain_hash(&pre)?, hashes[2]);
+
+        // Restoring the original event restores the chain hash
+        db.evts.pin(&[&key], &rot.raw().to_vec())?;
+        assert_eq!(db.recompute_kel_chain_hash(&pre)?, hashes[2]);
+
+        assert!(db.kel_chain_hash("EUnknownPrefix").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_event() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let (icp, sigers) = &kel[0];
+        let pre = icp.pre().unwrap();
+
+        let lmdber = setup_db("test_export_source")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &[(icp.clone(), sigers.clone())])?;
+
+        let msg = db.export_event(&pre, 0)?;
+        assert!(msg.starts_with(icp.raw()));
+        assert!(db.export_event(&pre, 1).is_err());
+
+        // Unframe the exported message
+        let serder = SerderKERI::from_raw(&msg, None)?;
+        let mut atc = msg[serder.raw().len()..].to_vec();
+        let counter = BaseCounter::from_qb64b(&mut atc, Some(true))?;
+        assert_eq!(counter.code(), ctr_dex_1_0::CONTROLLER_IDX_SIGS);
+        let parsed = parse_indexed_sigs(&mut atc, counter.count())?;
+        assert!(atc.is_empty());
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].qb64(), sigers[0].qb64());
+
+        // Re-ingest into a fresh Kevery
+        let flmdber = setup_db("test_export_dest")?;
+        let fdb = open_baser(&flmdber)?;
+        let mut fresh = setup_kevery(&fdb)?;
+        ingest(&mut fresh, &[(serder, parsed)])?;
+
+        let state = fresh.kevers().get(&pre).unwrap().state()?;
+        assert_eq!(state.d, icp.said().unwrap());
+        assert_eq!(state.k, kevery.kevers().get(&pre).unwrap().state()?.k);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_batch() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_process_batch")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+
+        // The ixn at sn 2 names the icp as its prior so it fails validation
+        let signers = kel_signers(2)?;
+        let bad = InteractEventBuilder::new(pre.clone(), kel[0].0.said().unwrap().to_string())
+            .with_sn(2)
+            .build()?;
+        let mut batch = kel.clone();
+        batch[2] = (bad.clone(), vec![sign_event(&signers[1], &bad)?]);
+
+        assert!(kevery.process_batch(&batch).is_err());
+        assert!(kevery.kevers().is_empty());
+        assert!(db.states.get(&[&pre])?.is_none());
+        for (serder, _) in &kel[..2] {
+            let key = dg_key(&pre, serder.said().unwrap());
+            assert!(db.evts.get::<_, Vec<u8>>(&[&key])?.is_none());
+            assert!(db.sigs.get::<_, Vec<u8>>(&[&key])?.is_empty());
+            assert!(db.fons.get(&[&key])?.is_none());
+        }
+        assert!(db.kels.get_on::<_, Vec<u8>>(&[&pre], 0)?.is_empty());
+        assert!(db.get_fel_item_all_pre_iter()?.is_empty());
+
+        // Valid prefix of the KEL then the remainder as a second batch
+        assert_eq!(kevery.process_batch(&kel[..1])?, 1);
+        assert_eq!(kevery.process_batch(&kel[1..])?, 2);
+        let state = kevery.kevers().get(&pre).unwrap().state()?;
+        assert_eq!(state.s, "2");
+        assert_eq!(state.d, kel[2].0.said().unwrap());
+
+        // An out of order event is escrowed without aborting the rest of the batch
+        let lmdber = setup_db("test_process_batch_escrow")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        let batch = vec![kel[0].clone(), kel[2].clone()];
+        assert_eq!(kevery.process_batch(&batch)?, 1);
+        assert_eq!(kevery.kevers().get(&pre).unwrap().state()?.s, "0");
+        assert_eq!(db.ooes.get_on::<_, Vec<u8>>(&[&pre], 2)?.len(), 1);
+
+        assert_eq!(kevery.process_batch(&kel[1..2])?, 1);
+        assert_eq!(kevery.reprocess_prefix(&pre)?.ooo, 1);
+        assert_eq!(kevery.kevers().get(&pre).unwrap().state()?.s, "2");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_kevery_no_key_reuse() -> Result<(), KERIError> {
+        let signers = kel_signers(3)?;
+        let dig = |signer: &Signer| -> Result<Vec<String>, KERIError> {
+            Ok(vec![Diger::from_ser(&signer.verfer().qb64b(), None)?.qb64()])
+        };
+
+        // Rotate to a fresh key committing back to the inception key, then reuse it
+        let icp = InceptionEventBuilder::new(vec![signers[0].verfer().qb64()])
+            .with_ndigs(dig(&signers[1])?)
+            .build()?;
+        let pre = icp.pre().unwrap();
+        let rot = RotateEventBuilder::new(
+            pre.clone(),
+            vec![signers[1].verfer().qb64()],
+            icp.said().unwrap().to_string(),
+        )
+        .with_sn(1)
+        .with_ndigs(dig(&signers[0])?)
+        .build()?;
+        let reuse = RotateEventBuilder::new(
+            pre.clone(),
+            vec![signers[0].verfer().qb64()],
+            rot.said().unwrap().to_string(),
+        )
+        .with_sn(2)
+        .with_ndigs(dig(&signers[2])?)
+        .build()?;
+        let kel = vec![
+            (icp.clone(), vec![sign_event(&signers[0], &icp)?]),
+            (rot.clone(), vec![sign_event(&signers[1], &rot)?]),
+            (reuse.clone(), vec![sign_event(&signers[0], &reuse)?]),
+        ];
+
+        let lmdber = setup_db("test_kevery_no_key_reuse")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = KeveryBuilder::new(Arc::new(&db))
+            .with_lax(true)
+            .with_local(false)
+            .with_no_key_reuse(true)
+            .build()?;
+        assert!(kevery.no_key_reuse);
+
+        ingest(&mut kevery, &kel[..2])?;
+        assert!(kevery.kevers().get(&pre).unwrap().no_key_reuse);
+        assert!(kevery.process_batch(&kel[2..]).is_err());
+        let result = ingest(&mut kevery, &kel[2..]);
+        assert!(matches!(result, Err(KERIError::KeyReuse(_))));
+        assert_eq!(kevery.kevers().get(&pre).unwrap().state()?.s, "1");
+
+        // Without the opt in the same rotation is accepted
+        let lmdber = setup_db("test_kevery_key_reuse")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+        assert_eq!(kevery.kevers().get(&pre).unwrap().state()?.s, "2");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_reverse_kel() -> Result<(), KERIError> {
+        let mut kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let signers = kel_signers(2)?;
+        let ixn = InteractEventBuilder::new(pre.clone(), kel[2].0.said().unwrap().to_string())
+            .with_sn(3)
+            .build()?;
+        kel.push((ixn.clone(), vec![sign_event(&signers[1], &ixn)?]));
+        let tip_first: Vec<_> = kel.iter().rev().cloned().collect();
+
+        let lmdber = setup_db("test_process_reverse_kel")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+
+        // Stopping short of the inception applies nothing
+        assert!(matches!(
+            kevery.process_reverse_kel(&tip_first[..3]),
+            Err(KERIError::ValidationError(_))
+        ));
+        assert!(kevery.kevers().is_empty());
+
+        // Gaps are rejected
+        let gapped = vec![
+            tip_first[0].clone(),
+            tip_first[2].clone(),
+            tip_first[3].clone(),
+        ];
+        assert!(kevery.process_reverse_kel(&gapped).is_err());
+        assert!(kevery.kevers().is_empty());
+
+        assert_eq!(kevery.process_reverse_kel(&tip_first)?, 4);
+        let state = kevery.kevers().get(&pre).unwrap().state()?;
+        assert_eq!(state.s, "3");
+        assert_eq!(state.d, ixn.said().unwrap());
+        for (sn, (serder, _)) in kel.iter().enumerate() {
+            let dig = db
+                .kels
+                .get_last::<_, Vec<u8>>(&[&sn_key(&pre, sn as u64)])?
+                .unwrap();
+            assert_eq!(dig, serder.said().unwrap().as_bytes());
+        }
+
+        // Resyncing from the tip stops at the current key state
+        assert_eq!(kevery.process_reverse_kel(&tip_first[..1])?, 0);
+
+        // A bad signature on the tip keeps the events accepted below it
+        let lmdber = setup_db("test_process_reverse_kel_partial")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        let mut forged = tip_first.clone();
+        forged[0].1 = vec![sign_event(&signers[0], &ixn)?];
+        assert_eq!(kevery.process_reverse_kel(&forged)?, 3);
+        let state = kevery.kevers().get(&pre).unwrap().state()?;
+        assert_eq!(state.s, "2");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oldest_escrow() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_oldest_escrow")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+
+        assert!(db.oldest_escrow()?.is_none());
+
+        // Without the icp both the ixn and rot land in out of order escrow,
+        // the ixn stamped a second ahead of the rot
+        let start = Utc::now();
+        for (secs, (serder, sigers)) in [(0, &kel[2]), (1, &kel[1])] {
+            kevery.clock = Arc::new(FixedClock(start + chrono::Duration::seconds(secs)));
+            let result = kevery.process_event(
+                serder.clone(),
+                sigers.clone(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            );
+            assert!(matches!(result, Err(KERIError::OutOfOrderError(_))));
+        }
+
+        let ixn_key = dg_key(&pre, kel[2].0.said().unwrap());
+        let ixn_dts = db.dtss.get::<_, Vec<u8>>(&[&ixn_key])?;
+        let ixn_dts = String::from_utf8_lossy(&ixn_dts[0]).to_string();
+
+        assert_eq!(ixn_dts, toiso8601(&start));
+
+        let (opre, osn, odts) = db.oldest_escrow()?.unwrap();
+        assert_eq!(opre, pre);
+        assert_eq!(osn, 2);
+        assert_eq!(odts, ixn_dts);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_authorize_query() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_authorize_query")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+
+        let mut query = IndexMap::new();
+        query.insert("i".to_string(), SadValue::String(pre.clone()));
+        let qry = QueryEventBuilder::new()
+            .with_route("logs".to_string())
+            .with_query(query)
+            .build()
+            .map_err(|e| KERIError::ValueError(e.to_string()))?;
+
+        let signers = kel_signers(2)?;
+        let source = Prefixer::from_qb64(&pre)?;
+
+        // Signed with the current key after rotation
+        let sigers = vec![sign_event(&signers[1], &qry)?];
+        assert!(kevery.authorize_query(&qry, &source, &sigers)?);
+
+        // Unsigned, signed with a rotated out key or from an unknown querier
+        assert!(!kevery.authorize_query(&qry, &source, &[])?);
+        let stale = vec![sign_event(&signers[0], &qry)?];
+        assert!(!kevery.authorize_query(&qry, &source, &stale)?);
+        let other = other_salter()?.signers(1, 0, "oth", None, None, None, false)?;
+        let unknown = Prefixer::from_qb64(&other[0].verfer().qb64())?;
+        assert!(!kevery.authorize_query(&qry, &unknown, &sigers)?);
+
+        // Not a query message
+        assert!(kevery.authorize_query(&kel[2].0, &source, &kel[2].1).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_query_ksn() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_process_query_ksn")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+        kevery.cues.clear();
+
+        let mut query = IndexMap::new();
+        query.insert("i".to_string(), SadValue::String(pre.clone()));
+        query.insert("src".to_string(), SadValue::String(pre.clone()));
+        let qry = QueryEventBuilder::new()
+            .with_route("ksn".to_string())
+            .with_query(query)
+            .build()
+            .map_err(|e| KERIError::ValueError(e.to_string()))?;
+        kevery.process_query(qry, Some(Prefixer::from_qb64(&pre)?), None, None)?;
+
+        // The reply cue carries the key state for the consumer to sign
+        let cue = kevery.cues.pop_back().unwrap();
+        assert_eq!(cue.kin, "reply");
+        let ksn = match &cue.serder.ked()["a"] {
+            SadValue::Object(map) => KeyStateRecord::from_map(map)?,
+            _ => panic!("Expected key state in reply"),
+        };
+        assert_eq!(ksn.i, pre);
+        assert_eq!(ksn.s, "2");
+        assert_eq!(ksn.ee.d, kel[1].0.said().unwrap());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_prefixes() -> Result<(), KERIError> {
+        let lmdber = setup_db("test_list_prefixes")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+
+        assert!(db.list_prefixes()?.is_empty());
+
+        // One multi-event KEL plus two single inception KELs
+        let mut events = build_kel()?;
+        let salter = other_salter()?;
+        for signer in salter.signers(2, 0, "", None, None, None, false)? {
+            let icp = InceptionEventBuilder::new(vec![signer.verfer().qb64()]).build()?;
+            events.push((icp.clone(), vec![sign_event(&signer, &icp)?]));
+        }
+        ingest(&mut kevery, &events)?;
+
+        let mut expected = vec![
+            events[0].0.pre().unwrap(),
+            events[3].0.pre().unwrap(),
+            events[4].0.pre().unwrap(),
+        ];
+        expected.sort();
+        let mut prefixes = db.list_prefixes()?;
+        prefixes.sort();
+        assert_eq!(prefixes, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_prefixes_paged() -> Result<(), KERIError> {
+        let lmdber = setup_db("test_list_prefixes_paged")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+
+        assert!(db.list_prefixes_paged(None, 2)?.is_empty());
+
+        // One multi-event KEL plus four single inception KELs
+        let mut events = build_kel()?;
+        let salter = other_salter()?;
+        for signer in salter.signers(4, 0, "", None, None, None, false)? {
+            let icp = InceptionEventBuilder::new(vec![signer.verfer().qb64()]).build()?;
+            events.push((icp.clone(), vec![sign_event(&signer, &icp)?]));
+        }
+        ingest(&mut kevery, &events)?;
+
+        let mut expected: Vec<String> = events[2..]
+            .iter()
+            .map(|(serder, _)| serder.pre().unwrap())
+            .collect();
+        expected.sort();
+        assert_eq!(expected.len(), 5);
+
+        let mut pages = Vec::new();
+        let mut after: Option<String> = None;
+        loop {
+            let page = db.list_prefixes_paged(after.as_deref(), 2)?;
+            if page.is_empty() {
+                break;
+            }
+            after = page.last().cloned();
+            pages.push(page);
+        }
+        let sizes: Vec<usize> = pages.iter().map(Vec::len).collect();
+        assert_eq!(sizes, vec![2, 2, 1]);
+        assert_eq!(pages.concat(), expected);
+
+        // Cursor need not be a stored prefix
+        assert_eq!(db.list_prefixes_paged(Some(""), 5)?, expected);
+        assert_eq!(
+            db.list_prefixes_paged(Some(&expected[4]), 2)?,
+            Vec::<String>::new()
+        );
+        assert!(db.list_prefixes_paged(None, 0)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_kel_gaps() -> Result<(), KERIError> {
+        let lmdber = setup_db("test_kel_gaps")?;
+        let db = open_baser(&lmdber)?;
+
+        let pre = "EBfxc4RiVY6saIFmUfEtETs1FcqmktZW88UkbnOg0Qen";
+        assert!(db.kel_gaps(pre)?.is_empty());
+
+        let dig = "EBG9LuUbFzV4OV5cGS9IeQWzy9SuyVFyVrpRc4l1xzPA".to_string();
+        for sn in [0, 1, 3, 5] {
+            db.kels.add_on(&[pre], sn, &dig)?;
+        }
+        // Events of another prefix do not fill the gaps
+        db.kels
+            .add_on(&["EAf2Ka7iygSgmJEtnpZX8B1qxWsCB66QaG-_ibYcW5kk"], 2, &dig)?;
+
+        assert_eq!(db.kel_gaps(pre)?, vec![2, 4]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_current_verfers() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_current_verfers")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        assert!(matches!(
+            db.current_verfers(&pre),
+            Err(KERIError::MissingEntryError(_))
+        ));
+
+        let keys = |verfers: Vec<Verfer>| verfers.iter().map(|v| v.qb64()).collect::<Vec<_>>();
+        for (i, event) in kel.chunks(1).enumerate() {
+            ingest(&mut kevery, event)?;
+
+            // Keys follow the last establishment event, so the ixn keeps the rotated keys
+            let est = if i == 0 { &kel[0].0 } else { &kel[1].0 };
+            assert_eq!(keys(db.current_verfers(&pre)?), est.keys().unwrap());
+        }
+        assert_ne!(kel[0].0.keys(), kel[1].0.keys());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_orphaned_events() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_orphans")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+        assert!(db.find_orphaned_events()?.is_empty());
+
+        // Event body written without a kels index entry
+        let orphan = "EBG9LuUbFzV4OV5cGS9IeQWzy9SuyVFyVrpRc4l1xzPA";
+        let key = dg_key(&pre, orphan);
+        db.evts.pin(&[&key], &kel[2].0.raw().to_vec())?;
+
+        assert_eq!(db.find_orphaned_events()?, vec![orphan.to_string()]);
+
+        // An event waiting in the partially signed escrow is not an orphan
+        let escrowed = "EAvR3p8V95W8J7Ui4-mEzZ79S-A1esAnJo1Kmzq80Jkc";
+        let key = dg_key(&pre, escrowed);
+        db.evts.pin(&[&key], &kel[1].0.raw().to_vec())?;
+        let snkey = sn_key(&pre, 1);
+        db.pses.add(&[&snkey], &escrowed.as_bytes().to_vec())?;
+        assert_eq!(db.find_orphaned_events()?, vec![orphan.to_string()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_event_at_fn() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        // Superseding recovery rotation at the sn of the ixn
+        let signers = kel_signers(4)?;
+        let nxt3 = vec![Diger::from_ser(&signers[3].verfer().qb64b(), None)?.qb64()];
+        let rec = RotateEventBuilder::new(
+            pre.clone(),
+            vec![signers[2].verfer().qb64()],
+            kel[1].0.said().unwrap().to_string(),
+        )
+        .with_sn(2)
+        .with_ndigs(nxt3)
+        .build()?;
+        let rec_sigs = vec![sign_event(&signers[2], &rec)?];
+
+        let lmdber = setup_db("test_event_at_fn")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+        ingest(&mut kevery, &[(rec.clone(), rec_sigs)])?;
+
+        // The recovery is first seen at fn 3 while its sn is 2
+        let said = |fn_num| -> Result<Option<String>, KERIError> {
+            Ok(db
+                .event_at_fn(&pre, fn_num)?
+                .map(|serder| serder.said().unwrap().to_string()))
+        };
+        assert_eq!(said(2)?.as_deref(), kel[2].0.said());
+        assert_eq!(said(3)?.as_deref(), rec.said());
+        assert_eq!(db.event_at_fn(&pre, 3)?.unwrap().sn(), Some(2));
+        assert_eq!(said(0)?.as_deref(), kel[0].0.said());
+        assert!(db.event_at_fn(&pre, 4)?.is_none());
+        assert!(db
+            .event_at_fn("EAf2Ka7iygSgmJEtnpZX8B1qxWsCB66QaG-_ibYcW5kk", 0)?
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_receipt_nontrans() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+        let said = kel[0].0.said().unwrap().to_string();
+
+        let lmdber = setup_db("test_process_receipt_nontrans")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+
+        let salter = other_salter()?;
+        let cigar = |transferable: bool| -> Result<Cigar, KERIError> {
+            let signer = &salter.signers(1, 0, "wat", None, Some(transferable), None, false)?[0];
+            sign_cigar(signer, &kel[0].0)
+        };
+        let rct = ReceiptEventBuilder::new(pre.clone(), 0, said.clone())
+            .build()
+            .map_err(|e| KERIError::ValueError(e.to_string()))?;
+        let key = dg_key(&pre, &said);
+
+        // Non-transferable receiptor (code B) is accepted
+        let valid = cigar(false)?;
+        assert_eq!(valid.verfer.as_ref().unwrap().code(), mtr_dex::ED25519N);
+        kevery.process_receipt(rct.clone(), vec![valid], None)?;
+        assert_eq!(db.rcts.cnt(&[&key])?, 1);
+
+        // Transferable receiptor (code D) is rejected and nothing is stored
+        let invalid = cigar(true)?;
+        assert_eq!(invalid.verfer.as_ref().unwrap().code(), mtr_dex::ED25519);
+        let result = kevery.process_receipt(rct, vec![invalid], None);
+        assert!(matches!(result, Err(KERIError::InvalidReceipt(_))));
+        assert_eq!(db.rcts.cnt(&[&key])?, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gc_orphaned_events() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_gc_orphans")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+
+        // Orphaned event body with its signature and datetime stamp
+        let orphan = "EBG9LuUbFzV4OV5cGS9IeQWzy9SuyVFyVrpRc4l1xzPA";
+        let key = dg_key(&pre, orphan);
+        db.evts.pin(&[&key], &kel[2].0.raw().to_vec())?;
+        db.sigs.pin(&[&key], &[&kel[2].1[0].qb64b()])?;
+        let dts = b"2020-01-01T00:00:00.000000+00:00".to_vec();
+        db.dtss.pin(&[&key], &[&dts])?;
+        db.wigs.pin(&[&key], &[&kel[2].1[0].qb64b()])?;
+        db.rcts.pin(&[&key], &[&kel[2].1[0].qb64b()])?;
+        db.wits.pin(&[&key], &[&pre.as_bytes().to_vec()])?;
+        let esr = EventSourceRecord { local: false };
+        db.esrs.pin(&[&key], &esr)?;
+
+        // A dry run reports without deleting
+        assert_eq!(db.gc_orphaned_events(true)?, vec![orphan.to_string()]);
+        assert!(db.evts.get::<_, Vec<u8>>(&[&key])?.is_some());
+
+        assert_eq!(db.gc_orphaned_events(false)?, vec![orphan.to_string()]);
+        assert!(db.evts.get::<_, Vec<u8>>(&[&key])?.is_none());
+        assert!(db.sigs.get::<_, Vec<u8>>(&[&key])?.is_empty());
+        assert!(db.dtss.get::<_, Vec<u8>>(&[&key])?.is_empty());
+        assert!(db.wigs.get::<_, Vec<u8>>(&[&key])?.is_empty());
+        assert!(db.rcts.get::<_, Vec<u8>>(&[&key])?.is_empty());
+        assert!(db.wits.get::<_, Vec<u8>>(&[&key])?.is_empty());
+        assert!(db.esrs.get(&[&key])?.is_none());
+        assert!(db.find_orphaned_events()?.is_empty());
+
+        // Referenced events are untouched
+        for (serder, _) in &kel {
+            let key = dg_key(&pre, serder.said().unwrap());
+            assert!(db.evts.get::<_, Vec<u8>>(&[&key])?.is_some());
+            assert!(!db.sigs.get::<_, Vec<u8>>(&[&key])?.is_empty());
+            assert!(!db.dtss.get::<_, Vec<u8>>(&[&key])?.is_empty());
+        }
+
+        Ok(())
     }
 
-    /// Set the check mode for the Kevery instance
-    pub fn with_check(mut self, check: bool) -> Self {
-        self.check = Some(check);
-        self
+    #[test]
+    fn test_gc_keeps_escrowed_events() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_gc_escrowed")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel[..1])?;
+
+        // ixn at sn 2 is escrowed out of order with no kels entry
+        let (ixn, sigers) = kel[2].clone();
+        let result = kevery.process_event(ixn, sigers, None, None, None, None, None, None, None);
+        assert!(matches!(result, Err(KERIError::OutOfOrderError(_))));
+        let key = dg_key(&pre, kel[2].0.said().unwrap());
+        assert!(db.evts.get::<_, Vec<u8>>(&[&key])?.is_some());
+
+        assert!(db.find_orphaned_events()?.is_empty());
+        assert!(db.gc_orphaned_events(false)?.is_empty());
+        assert!(db.evts.get::<_, Vec<u8>>(&[&key])?.is_some());
+        assert!(!db.sigs.get::<_, Vec<u8>>(&[&key])?.is_empty());
+
+        // The escrowed event is still accepted once the rot arrives
+        ingest(&mut kevery, &kel[1..2])?;
+        assert_eq!(kevery.reprocess_prefix(&pre)?.ooo, 1);
+        assert_eq!(kevery.kevers[&pre].sner().unwrap().num(), 2);
+
+        Ok(())
     }
 
-    /// Build the Kevery instance from the provided options
-    pub fn build(self) -> Result<Kevery<'db>, KERIError> {
-        Kevery::new(
-            self.cues,
-            self.db.clone(),
-            self.rvy,
-            self.lax,
-            self.local,
-            self.cloned,
-            self.direct,
-            self.check,
-        )
+    #[test]
+    fn test_audit_dts() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_audit_dts")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+
+        assert_eq!(db.audit_dts(&pre)?, vec![(0, true), (1, true), (2, true)]);
+
+        // Backdate the rot before the icp
+        let key = dg_key(&pre, kel[1].0.said().unwrap());
+        let dts = b"2020-01-01T00:00:00.000000+00:00".to_vec();
+        db.dtss.pin(&[&key], &[&dts])?;
+
+        assert_eq!(db.audit_dts(&pre)?, vec![(0, true), (1, false), (2, true)]);
+
+        Ok(())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::keri::db::dbing::LMDBer;
+    #[test]
+    fn test_self_check() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_self_check")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+
+        let report = db.self_check()?;
+        assert!(report.is_clean());
+
+        // sn index entry pointing at an event that was never stored
+        let bogus = "EBG9LuUbFzV4OV5cGS9IeQWzy9SuyVFyVrpRc4l1xzPA".to_string();
+        db.kels.add_on(&[pre.as_str()], 5, &bogus)?;
+        let report = db.self_check()?;
+        assert!(!report.is_clean());
+        assert_eq!(report.index.len(), 1);
+        assert!(report.index[0].contains("sn=5"));
+        assert!(report.escrows.is_empty());
+        assert!(report.states.is_empty());
+
+        // sn index entry at the wrong sn for a stored event
+        db.kels
+            .add_on(&[pre.as_str()], 6, &kel[1].0.said().unwrap().to_string())?;
+        let report = db.self_check()?;
+        assert_eq!(report.index.len(), 2);
+
+        // Corrupt event body is reported and the scan carries on
+        let corrupt = "EDtWPSh2NfL1qDuGgQNJOgbw0TyXVBB7xydiW3jMSj0n".to_string();
+        let key = dg_key(&pre, &corrupt);
+        db.evts.pin(&[&key], &b"not an event".to_vec())?;
+        db.kels.add_on(&[pre.as_str()], 7, &corrupt)?;
+        let report = db.self_check()?;
+        assert_eq!(report.index.len(), 3);
+        assert!(report.index.iter().any(|a| a.starts_with("Corrupt event")));
+
+        // Stale cached key state
+        let mut state = db.states.get(&[pre.as_str()])?.unwrap();
+        state.s = "1".to_string();
+        db.states.pin(&[pre.as_str()], &state)?;
+        let report = db.self_check()?;
+        assert_eq!(report.states.len(), 1);
+
+        Ok(())
+    }
 
     #[test]
-    fn test_kevery_new() -> Result<(), KERIError> {
-        // Create a temporary database
-        let lmdber = &LMDBer::builder()
-            .temp(true)
-            .name("test_kevery")
+    fn test_first_seen_couple() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_first_seen_couple")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+
+        let (fn_num, dts) = db.first_seen_couple(&pre, 1)?.unwrap();
+        assert_eq!(fn_num, 1);
+        let key = dg_key(&pre, kel[1].0.said().unwrap());
+        let stored = db.dtss.get::<_, Vec<u8>>(&[&key])?;
+        assert_eq!(dts.as_bytes(), stored[0].as_slice());
+
+        assert!(db.first_seen_couple(&pre, 7)?.is_none());
+
+        let mut atc = Baser::first_seen_couple_qb64b(fn_num, &dts)?;
+        let counter = BaseCounter::from_qb64b(&mut atc, Some(true))?;
+        assert_eq!(counter.code(), ctr_dex_1_0::FIRST_SEEN_REPLAY_COUPLES);
+        assert_eq!(counter.count(), 1);
+        let firner = Number::from_qb64b(&mut atc, Some(true))?;
+        assert_eq!(firner.num(), 1);
+        let dater = Dater::from_qb64b(&mut atc, Some(true))?;
+        assert_eq!(dater.dt()?, DateTime::parse_from_rfc3339(&dts).unwrap());
+        assert!(atc.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_event_ilk_counts() -> Result<(), KERIError> {
+        let mut kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let signers = kel_signers(2)?;
+        let ixn = InteractEventBuilder::new(pre.clone(), kel[2].0.said().unwrap().to_string())
+            .with_sn(3)
+            .build()?;
+        kel.push((ixn.clone(), vec![sign_event(&signers[1], &ixn)?]));
+
+        let lmdber = setup_db("test_event_ilk_counts")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+
+        let counts = db.event_ilk_counts(&pre)?;
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[Ilks::ICP], 1);
+        assert_eq!(counts[Ilks::ROT], 1);
+        assert_eq!(counts[Ilks::IXN], 2);
+
+        assert!(db.event_ilk_counts("EBG9LuUbFzV4OV5cGS9IeQWzy9SuyVFyVrpRc4l1xzPA")?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_key_state_proof() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_key_state_proof_source")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+
+        let signers = kel_signers(2)?;
+
+        assert!(db.key_state_proof("unknown", &signers[1]).is_err());
+        assert!(db.key_state_proof(&pre, &signers[0]).is_err());
+        let proof = db.key_state_proof(&pre, &signers[1])?;
+
+        // Replay the establishment chain into a fresh Kevery
+        let flmdber = setup_db("test_key_state_proof_dest")?;
+        let fdb = open_baser(&flmdber)?;
+        let mut fresh = setup_kevery(&fdb)?;
+
+        let mut buf = proof;
+        let mut ilks = Vec::new();
+        let rpy = loop {
+            let serder = SerderKERI::from_raw(&buf, None)?;
+            buf = buf[serder.raw().len()..].to_vec();
+            if serder.ilk() == Some(Ilk::Rpy) {
+                break serder;
+            }
+
+            ilks.push(serder.ilk().unwrap().to_string());
+            let counter = BaseCounter::from_qb64b(&mut buf, Some(true))?;
+            let sigers = parse_indexed_sigs(&mut buf, counter.count())?;
+            ingest(&mut fresh, &[(serder, sigers)])?;
+        };
+        assert_eq!(ilks, vec![Ilks::ICP.to_string(), Ilks::ROT.to_string()]);
+
+        // The ksn reply matches the key state proven by the establishment chain
+        let ksn = match &rpy.ked()["a"] {
+            SadValue::Object(map) => KeyStateRecord::from_map(map)?,
+            _ => panic!("Expected key state in reply"),
+        };
+        let proven = fresh.kevers().get(&pre).unwrap().state()?;
+        assert_eq!(ksn.i, pre);
+        assert_eq!(ksn.s, "2");
+        assert_eq!(ksn.k, proven.k);
+        assert_eq!(ksn.n, proven.n);
+        assert_eq!(ksn.ee.s, proven.s);
+        assert_eq!(ksn.ee.d, proven.d);
+        assert_eq!(ksn.ee.d, kel[1].0.said().unwrap());
+
+        // The reply is endorsed by the current key anchored to the latest rotation
+        let counter = BaseCounter::from_qb64b(&mut buf, Some(true))?;
+        assert_eq!(counter.code(), ctr_dex_1_0::TRANS_IDX_SIG_GROUPS);
+        let prefixer = Prefixer::from_qb64b(&mut buf, Some(true))?;
+        let seqner = Seqner::from_qb64b(&mut buf, Some(true))?;
+        let diger = Diger::from_qb64b(&mut buf, Some(true))?;
+        assert_eq!(prefixer.qb64(), pre);
+        assert_eq!(seqner.sn(), 1);
+        assert_eq!(diger.qb64(), proven.d);
+        let counter = BaseCounter::from_qb64b(&mut buf, Some(true))?;
+        let sigers = parse_indexed_sigs(&mut buf, counter.count())?;
+        assert!(buf.is_empty());
+        assert!(signers[1].verfer().verify(sigers[0].raw(), rpy.raw())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_receipt_escrows() -> Result<(), KERIError> {
+        let salter = other_salter()?;
+        let signer = &salter.signers(1, 0, "ctl", None, None, None, false)?[0];
+        let wits = salter.signers(2, 0, "wit", None, Some(false), None, false)?;
+
+        let icp = InceptionEventBuilder::new(vec![signer.verfer().qb64()])
+            .with_ndigs(vec![Diger::from_ser(&signer.verfer().qb64b(), None)?.qb64()])
+            .with_wits(wits.iter().map(|w| w.verfer().qb64()).collect())
+            .with_toad(1)
+            .build()?;
+        let pre = icp.pre().unwrap();
+        let said = icp.said().unwrap().to_string();
+        let key = dg_key(&pre, &said);
+
+        let wigers = wits
+            .iter()
+            .enumerate()
+            .map(|(i, wit)| sign_indexed(wit, &icp, i as u32))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let lmdber = setup_db("test_process_receipt_escrows")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+
+        // Receipt from the second witness arrives before the event
+        let rct = ReceiptEventBuilder::new(pre.clone(), 0, said.clone())
             .build()
-            .map_err(|e| KERIError::DatabaseError(format!("{}", e)))?;
+            .map_err(|e| KERIError::ValueError(e.to_string()))?;
+        let result = kevery.process_receipt_witness(rct, vec![wigers[1].clone()], None);
+        assert!(matches!(
+            result,
+            Err(KERIError::UnverifiedWitnessReceiptError(_))
+        ));
+        assert_eq!(db.ures.cnt(&[&key])?, 1);
+
+        // Nothing to promote until the event lands
+        assert_eq!(kevery.process_receipt_escrows()?, 0);
+        assert_eq!(db.ures.cnt(&[&key])?, 1);
+
+        kevery.process_event(
+            icp.clone(),
+            vec![sign_event(signer, &icp)?],
+            Some(vec![wigers[0].clone()]),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+        assert_eq!(db.wigs.get::<_, Vec<u8>>(&[&key])?.len(), 1);
 
-        let db =
-            Baser::new(Arc::new(lmdber)).map_err(|e| KERIError::DatabaseError(format!("{}", e)))?;
+        assert_eq!(kevery.process_receipt_escrows()?, 1);
+        assert_eq!(db.ures.cnt(&[&key])?, 0);
+        let wigs = db.wigs.get::<_, Vec<u8>>(&[&key])?;
+        assert_eq!(wigs.len(), 2);
+        assert!(wigs.contains(&wigers[1].qb64b()));
 
-        // Create Kevery using the new function
-        let kevery = Kevery::new(
+        // Receipts of an ixn verify against the witnesses of the icp before it
+        let ixn = InteractEventBuilder::new(pre.clone(), said.clone())
+            .with_sn(1)
+            .build()?;
+        let isaid = ixn.said().unwrap().to_string();
+        let ikey = dg_key(&pre, &isaid);
+        let iwigers = wits
+            .iter()
+            .enumerate()
+            .map(|(i, wit)| sign_indexed(wit, &ixn, i as u32))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let rct = ReceiptEventBuilder::new(pre.clone(), 1, isaid.clone())
+            .build()
+            .map_err(|e| KERIError::ValueError(e.to_string()))?;
+        let result = kevery.process_receipt_witness(rct, vec![iwigers[1].clone()], None);
+        assert!(matches!(
+            result,
+            Err(KERIError::UnverifiedWitnessReceiptError(_))
+        ));
+        assert_eq!(db.ures.cnt(&[&ikey])?, 1);
+
+        kevery.process_event(
+            ixn.clone(),
+            vec![sign_event(signer, &ixn)?],
+            Some(vec![iwigers[0].clone()]),
+            None,
+            None,
+            None,
+            None,
             None,
-            Arc::new(&db),
             None,
-            Some(true),
-            Some(false),
-            Some(false),
-            Some(true),
-            Some(false),
         )?;
+        assert!(db.wits.get::<_, Vec<u8>>(&[&ikey])?.is_empty());
 
-        assert!(kevery.lax);
-        assert!(!kevery.local);
-        assert!(!kevery.cloned);
-        assert!(kevery.direct);
-        assert!(!kevery.check);
-        assert!(kevery.kevers().is_empty());
+        assert_eq!(kevery.process_receipt_escrows()?, 1);
+        assert_eq!(db.ures.cnt(&[&ikey])?, 0);
+        let wigs = db.wigs.get::<_, Vec<u8>>(&[&ikey])?;
+        assert_eq!(wigs.len(), 2);
+        assert!(wigs.contains(&iwigers[1].qb64b()));
 
         Ok(())
     }
 
     #[test]
-    fn test_kevery_builder() -> Result<(), KERIError> {
-        // Create a temporary database
-        let lmdber = &LMDBer::builder()
-            .temp(true)
-            .name("test_kevery_builder")
-            .build()
-            .map_err(|e| KERIError::DatabaseError(format!("{}", e)))?;
+    fn test_escrow_oo_event() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+        let now: DateTime<Utc> = "2026-01-01T00:00:00+00:00".parse().unwrap();
+
+        let lmdber = setup_db("test_escrow_oo_event")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = KeveryBuilder::new(Arc::new(&db))
+            .with_lax(true)
+            .with_local(false)
+            .with_clock(Arc::new(FixedClock(now)))
+            .build()?;
+        ingest(&mut kevery, &kel[..1])?;
 
-        let db =
-            Baser::new(Arc::new(lmdber)).map_err(|e| KERIError::DatabaseError(format!("{}", e)))?;
+        // An sn beyond u32 is escrowed at its own ordinal, stamped by the clock
+        let signers = kel_signers(1)?;
+        let sn = u32::MAX as u64 + 3;
+        let ixn = InteractEventBuilder::new(pre.clone(), kel[0].0.said().unwrap().to_string())
+            .with_sn(sn as usize)
+            .build()?;
+        let sigers = vec![sign_event(&signers[0], &ixn)?];
+        let result = ingest(&mut kevery, &[(ixn.clone(), sigers)]);
+        assert!(matches!(result, Err(KERIError::OutOfOrderError(_))));
+
+        let ons = db
+            .ooes
+            .get_on_item_iter::<_, Vec<u8>>(&[&pre], 0)?
+            .map(|item| item.map(|(_, on, _)| on))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(ons, vec![sn]);
+        let key = dg_key(&pre, ixn.said().unwrap());
+        let dts = db.dtss.get::<_, Vec<u8>>(&[&key])?;
+        assert_eq!(dts, vec![toiso8601(&now).into_bytes()]);
 
-        // Create Kevery using the builder pattern
-        let kevery = KeveryBuilder::new(Arc::new(&db))
+        Ok(())
+    }
+
+    #[test]
+    fn test_reprocess_prefix() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_reprocess_prefix")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+
+        let (icp, sigers) = kel[0].clone();
+        ingest(&mut kevery, &[(icp, sigers)])?;
+
+        // ixn at sn 2 arrives before the rot it depends on
+        let (ixn, sigers) = kel[2].clone();
+        let result = kevery.process_event(ixn, sigers, None, None, None, None, None, None, None);
+        assert!(matches!(result, Err(KERIError::OutOfOrderError(_))));
+        assert_eq!(db.ooes.get_on::<_, Vec<u8>>(&[&pre], 2)?.len(), 1);
+
+        // Still stuck without the rot
+        assert_eq!(kevery.reprocess_prefix(&pre)?, ReprocessStats::default());
+        assert_eq!(db.ooes.get_on::<_, Vec<u8>>(&[&pre], 2)?.len(), 1);
+
+        let (rot, sigers) = kel[1].clone();
+        ingest(&mut kevery, &[(rot, sigers)])?;
+
+        let stats = kevery.reprocess_prefix(&pre)?;
+        assert_eq!(stats.ooo, 1);
+        assert_eq!(stats.partial_sig, 0);
+        assert_eq!(stats.receipt, 0);
+        assert!(db.ooes.get_on::<_, Vec<u8>>(&[&pre], 2)?.is_empty());
+        assert_eq!(kevery.kevers[&pre].sner().unwrap().num(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reprocess_prefix_wide_sn() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_reprocess_prefix_wide_sn")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = KeveryBuilder::new(Arc::new(&db))
             .with_lax(true)
             .with_local(false)
-            .with_cloned(false)
-            .with_direct(true)
-            .with_check(false)
+            .with_max_escrow_attempts(1)
             .build()?;
+        ingest(&mut kevery, &kel[..1])?;
 
-        assert!(kevery.lax);
-        assert!(!kevery.local);
-        assert!(!kevery.cloned);
-        assert!(kevery.direct);
-        assert!(!kevery.check);
-        assert!(kevery.kevers().is_empty());
+        // Escrows at sn 2 and at an sn that truncates to 2 as u32
+        let signers = kel_signers(1)?;
+        let sn = u32::MAX as u64 + 3;
+        let wide = InteractEventBuilder::new(pre.clone(), kel[0].0.said().unwrap().to_string())
+            .with_sn(sn as usize)
+            .build()?;
+        let sigers = vec![sign_event(&signers[0], &wide)?];
+        assert!(ingest(&mut kevery, &kel[2..]).is_err());
+        assert!(ingest(&mut kevery, &[(wide, sigers)]).is_err());
+        let ons = |db: &Baser| -> Result<Vec<u64>, KERIError> {
+            Ok(db
+                .ooes
+                .get_on_item_iter::<_, Vec<u8>>(&[&pre], 0)?
+                .map(|item| item.map(|(_, on, _)| on))
+                .collect::<Result<Vec<_>, _>>()?)
+        };
+        assert_eq!(ons(&db)?, vec![2, sn]);
+
+        // Both give up and each leaves escrow from its own ordinal
+        let stats = kevery.reprocess_prefix(&pre)?;
+        assert_eq!(stats.dead, 2);
+        assert!(ons(&db)?.is_empty());
+
+        Ok(())
+    }
+    #[test]
+    fn test_process_escrows_dead_letters() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_process_escrows_dead_letters")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = KeveryBuilder::new(Arc::new(&db))
+            .with_lax(true)
+            .with_local(false)
+            .with_max_escrow_attempts(2)
+            .build()?;
+
+        let (icp, sigers) = kel[0].clone();
+        ingest(&mut kevery, &[(icp, sigers)])?;
+
+        // ixn at sn 2 whose rot never arrives
+        let (ixn, sigers) = kel[2].clone();
+        let dig = ixn.said().unwrap().to_string();
+        let result = kevery.process_event(ixn, sigers, None, None, None, None, None, None, None);
+        assert!(matches!(result, Err(KERIError::OutOfOrderError(_))));
+
+        let stats = kevery.process_escrows()?;
+        assert_eq!(stats.dead, 0);
+        assert_eq!(db.ooes.get_on::<_, Vec<u8>>(&[&pre], 2)?.len(), 1);
+        assert!(db.dead_letters()?.is_empty());
+
+        let stats = kevery.process_escrows()?;
+        assert_eq!(stats.dead, 1);
+        assert!(db.ooes.get_on::<_, Vec<u8>>(&[&pre], 2)?.is_empty());
+        assert_eq!(
+            db.dead_letters()?,
+            vec![(pre.clone(), dig.clone(), "ooes".to_string())]
+        );
+        assert!(db.eats.get(&[&dg_key(&pre, &dig)])?.is_none());
+
+        // Nothing left to sweep
+        assert_eq!(kevery.process_escrows()?, ReprocessStats::default());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_receipts_digest() -> Result<(), KERIError> {
+        let salter = other_salter()?;
+        let signer = &salter.signers(1, 0, "ctl", None, None, None, false)?[0];
+        let wits = salter.signers(3, 0, "wit", None, Some(false), None, false)?;
+        let wpres: Vec<String> = wits.iter().map(|w| w.verfer().qb64()).collect();
+
+        let icp = InceptionEventBuilder::new(vec![signer.verfer().qb64()])
+            .with_ndigs(vec![Diger::from_ser(&signer.verfer().qb64b(), None)?.qb64()])
+            .with_wits(wpres.clone())
+            .with_toad(3)
+            .build()?;
+        let pre = icp.pre().unwrap();
+        let said = icp.said().unwrap().to_string();
+
+        let wigers = wits
+            .iter()
+            .enumerate()
+            .map(|(i, wit)| sign_indexed(wit, &icp, i as u32))
+            .collect::<Result<Vec<_>, _>>()?;
+        let watcher = &salter.signers(1, 0, "wat", None, Some(false), None, false)?[0];
+        let mut couple = watcher.verfer().qb64b();
+        couple.extend_from_slice(&sign_cigar(watcher, &icp)?.qb64b());
+
+        let mut digests = Vec::new();
+        for (name, order) in [
+            ("test_receipts_digest_a", [0, 1, 2]),
+            ("test_receipts_digest_b", [2, 0, 1]),
+        ] {
+            let lmdber = setup_db(name)?;
+            let db = open_baser(&lmdber)?;
+            let mut kevery = setup_kevery(&db)?;
+            assert_eq!(db.receipts_digest(&pre, &said)?, None);
+            kevery.process_event(
+                icp.clone(),
+                vec![sign_event(signer, &icp)?],
+                Some(order.iter().map(|i| wigers[*i].clone()).collect()),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )?;
+            let wdigest = db.receipts_digest(&pre, &said)?.unwrap();
+
+            db.rcts.add(&[&dg_key(&pre, &said)], &couple)?;
+            let digest = db.receipts_digest(&pre, &said)?.unwrap();
+            assert_ne!(digest, wdigest);
+            digests.push(digest);
+        }
+
+        // Same receipts in a different order commit to the same digest
+        assert_eq!(digests[0], digests[1]);
+        assert!(digests[0].starts_with(crate::cesr::mtr_dex::BLAKE3_256));
+
+        // Witness receipts on an interaction event resolve against the inception wits
+        let ixn = InteractEventBuilder::new(pre.clone(), said.clone())
+            .with_sn(1)
+            .build()?;
+        let iwigers = wits
+            .iter()
+            .enumerate()
+            .map(|(i, wit)| sign_indexed(wit, &ixn, i as u32))
+            .collect::<Result<Vec<_>, _>>()?;
+        let lmdber = setup_db("test_receipts_digest_ixn")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        kevery.process_event(
+            icp.clone(),
+            vec![sign_event(signer, &icp)?],
+            Some(wigers.clone()),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+        let isaid = ixn.said().unwrap().to_string();
+        assert_eq!(db.receipts_digest(&pre, &isaid)?, None);
+        kevery.process_event(
+            ixn.clone(),
+            vec![sign_event(signer, &ixn)?],
+            Some(iwigers),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+        let idigest = db.receipts_digest(&pre, &isaid)?;
+        assert!(idigest.is_some());
+        assert_ne!(idigest, db.receipts_digest(&pre, &said)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_key_state_reply() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_key_state_reply")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+
+        let signers = kel_signers(2)?;
+
+        // Signed by the current controller key
+        let msg = db.key_state_reply(&pre, &signers[1])?;
+        let rserder = SerderKERI::from_raw(&msg, None)?;
+        assert_eq!(rserder.ilk(), Some(Ilk::Rpy));
+        assert_eq!(rserder.ked()["r"].as_str().unwrap(), format!("/ksn/{}", pre));
+        let ksn = match &rserder.ked()["a"] {
+            SadValue::Object(map) => KeyStateRecord::from_map(map)?,
+            _ => panic!("Expected key state in reply"),
+        };
+        assert_eq!(ksn.i, pre);
+        assert_eq!(ksn.s, "2");
+        assert_eq!(ksn.k, vec![signers[1].verfer().qb64()]);
+
+        let mut atc = msg[rserder.raw().len()..].to_vec();
+        let counter = BaseCounter::from_qb64b(&mut atc, Some(true))?;
+        assert_eq!(counter.code(), ctr_dex_1_0::TRANS_IDX_SIG_GROUPS);
+        let prefixer = Prefixer::from_qb64b(&mut atc, Some(true))?;
+        let seqner = Seqner::from_qb64b(&mut atc, Some(true))?;
+        let diger = Diger::from_qb64b(&mut atc, Some(true))?;
+        assert_eq!(prefixer.qb64(), pre);
+        assert_eq!(seqner.sn(), 1);
+        assert_eq!(diger.qb64(), kel[1].0.said().unwrap());
+        let counter = BaseCounter::from_qb64b(&mut atc, Some(true))?;
+        let sigers = parse_indexed_sigs(&mut atc, counter.count())?;
+        assert!(atc.is_empty());
+        assert!(signers[1].verfer().verify(sigers[0].raw(), rserder.raw())?);
+
+        // Rotated out keys may not endorse
+        assert!(db.key_state_reply(&pre, &signers[0]).is_err());
+
+        // Nontransferable endorser attaches a receipt couple
+        let salter = other_salter()?;
+        let watcher = &salter.signers(1, 0, "wat", None, Some(false), None, false)?[0];
+        let msg = db.key_state_reply(&pre, watcher)?;
+        let rserder = SerderKERI::from_raw(&msg, None)?;
+        let mut atc = msg[rserder.raw().len()..].to_vec();
+        let counter = BaseCounter::from_qb64b(&mut atc, Some(true))?;
+        assert_eq!(counter.code(), ctr_dex_1_0::NON_TRANS_RECEIPT_COUPLES);
+        let verfer = Verfer::from_qb64b(&mut atc, Some(true))?;
+        let cigar = Cigar::from_qb64b(&mut atc, Some(true))?;
+        assert!(atc.is_empty());
+        assert_eq!(verfer.qb64(), watcher.verfer().qb64());
+        assert!(verfer.verify(cigar.raw(), rserder.raw())?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_anchor_acdc() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_anchor_acdc")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+
+        let creder = make_creder(&pre, "0AAxyHwW6htOZ_rANOaZb2N2")?;
+
+        let signers = kel_signers(2)?;
+
+        // Rotated out key cannot anchor
+        assert!(kevery.anchor_acdc(&pre, &creder, &signers[0]).is_err());
+
+        let serder = kevery.anchor_acdc(&pre, &creder, &signers[1])?;
+        assert_eq!(serder.ilk(), Some(Ilk::Ixn));
+        assert_eq!(serder.sn(), Some(3));
+        match &serder.ked()["a"] {
+            SadValue::Array(seals) => {
+                assert_eq!(seals.len(), 1);
+                assert_eq!(seals[0].get("d").unwrap().as_str().unwrap(), creder.said());
+            }
+            _ => panic!("Expected seal list"),
+        }
+
+        let state = kevery.kevers().get(&pre).unwrap().state()?;
+        assert_eq!(state.s, "3");
+        assert_eq!(state.d, serder.said().unwrap());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_acdc_anchor() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_verify_acdc_anchor")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+
+        let signers = kel_signers(2)?;
+
+        let anchored = make_creder(&pre, "0AAxyHwW6htOZ_rANOaZb2N2")?;
+        let unanchored = make_creder(&pre, "0ABxyHwW6htOZ_rANOaZb2N2")?;
+        assert!(!kevery.verify_acdc_anchor(&anchored)?);
+
+        kevery.anchor_acdc(&pre, &anchored, &signers[1])?;
+        assert!(kevery.verify_acdc_anchor(&anchored)?);
+        assert!(!kevery.verify_acdc_anchor(&unanchored)?);
+
+        // Unknown issuer
+        let orphan = make_creder(
+            "EBG9LuUbFzV4OV5cGS9IeQWzy9SuyVFyVrpRc4l1xzPA",
+            "0AAxyHwW6htOZ_rANOaZb2N2",
+        )?;
+        assert!(!kevery.verify_acdc_anchor(&orphan)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_delegation_couple() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let delpre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_verify_delegation_couple")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+
+        let signers = kel_signers(3)?;
+
+        // Delegated inception and the delegator's anchoring interaction at sn 3
+        let dip = InceptionEventBuilder::new(vec![signers[2].verfer().qb64()])
+            .with_delpre(delpre.clone())
+            .build()?;
+        let seal = event_seal(&dip.pre().unwrap(), 0, dip.said().unwrap());
+        let ixn = InteractEventBuilder::new(delpre.clone(), kel[2].0.said().unwrap().to_string())
+            .with_sn(3)
+            .with_data_list(vec![SadValue::Object(seal_sad(&seal))])
+            .build()?;
+        let sigers = vec![sign_event(&signers[1], &ixn)?];
+        ingest(&mut kevery, &[(ixn.clone(), sigers)])?;
+
+        let adig = ixn.said().unwrap().to_string();
+        assert!(kevery.verify_delegation_couple(&dip, (3, adig.clone()))?);
+
+        // Couple naming an event that does not anchor the delegate
+        let pdig = kel[2].0.said().unwrap().to_string();
+        assert!(!kevery.verify_delegation_couple(&dip, (2, pdig))?);
+
+        // Couple whose dig is not the event accepted at sn
+        assert!(!kevery.verify_delegation_couple(&dip, (2, adig))?);
+
+        // Non-delegated event has no delegator
+        let result = kevery.verify_delegation_couple(&kel[0].0, (0, delpre));
+        assert!(matches!(result, Err(KERIError::ValueError(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_loc_scheme() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_process_loc_scheme")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+
+        let salter = other_salter()?;
+        let witness = &salter.signers(1, 0, "wit", None, Some(false), None, false)?[0];
+        let wit = witness.verfer().qb64();
+
+        let reply = |eid: &str, role: &str, url: &str| -> Result<SerderKERI, KERIError> {
+            let mut data = IndexMap::new();
+            data.insert("eid".to_string(), SadValue::String(eid.to_string()));
+            data.insert("role".to_string(), SadValue::String(role.to_string()));
+            data.insert("scheme".to_string(), SadValue::String("http".to_string()));
+            data.insert("url".to_string(), SadValue::String(url.to_string()));
+            ReplyEventBuilder::new()
+                .with_route("/loc/scheme".to_string())
+                .with_data(data)
+                .with_stamp(Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Micros, false))
+                .build()
+        };
+
+        // A nontransferable witness signs its own http endpoint
+        let serder = reply(&wit, "witness", "http://127.0.0.1:5632/")?;
+        kevery.process_loc_scheme(serder.clone(), vec![], vec![sign_cigar(witness, &serder)?])?;
+        let loc = db.locs.get(&[wit.as_str(), "witness", "http"])?.unwrap();
+        assert_eq!(loc.url, "http://127.0.0.1:5632/");
+        let saider = db.lans.get(&[wit.as_str(), "witness", "http"])?.unwrap();
+        assert_eq!(saider.qb64(), serder.said().unwrap());
+        assert!(db.rpys.get(&[saider.qb64()])?.is_some());
+
+        // Signed by some other nontransferable key
+        let other = &salter.signers(1, 0, "oth", None, Some(false), None, false)?[0];
+        let serder = reply(&wit, "witness", "http://127.0.0.1:9999/")?;
+        assert!(matches!(
+            kevery.process_loc_scheme(serder.clone(), vec![], vec![sign_cigar(other, &serder)?]),
+            Err(KERIError::ValidationError(_))
+        ));
+        let loc = db.locs.get(&[wit.as_str(), "witness", "http"])?.unwrap();
+        assert_eq!(loc.url, "http://127.0.0.1:5632/");
+
+        // A transferable controller signs under its current key state
+        let signers = kel_signers(2)?;
+        let serder = reply(&pre, "mailbox", "http://127.0.0.1:7723/")?;
+        kevery.process_loc_scheme(
+            serder.clone(),
+            vec![sign_event(&signers[1], &serder)?],
+            vec![],
+        )?;
+        let loc = db.locs.get(&[pre.as_str(), "mailbox", "http"])?.unwrap();
+        assert_eq!(loc.url, "http://127.0.0.1:7723/");
+        assert!(db.locs.get(&[pre.as_str(), "witness", "http"])?.is_none());
+
+        // Rotated out key, unknown role and unknown controller are rejected
+        let serder = reply(&pre, "mailbox", "http://127.0.0.1:9999/")?;
+        let stale = vec![sign_event(&signers[0], &serder)?];
+        assert!(kevery.process_loc_scheme(serder, stale, vec![]).is_err());
+        let serder = reply(&pre, "janitor", "http://127.0.0.1:9999/")?;
+        let sigers = vec![sign_event(&signers[1], &serder)?];
+        assert!(kevery.process_loc_scheme(serder, sigers, vec![]).is_err());
+        let unknown = other_salter()?.signers(1, 0, "unk", None, None, None, false)?;
+        let upre = unknown[0].verfer().qb64();
+        let serder = reply(&upre, "mailbox", "http://127.0.0.1:9999/")?;
+        let sigers = vec![sign_event(&unknown[0], &serder)?];
+        assert!(kevery.process_loc_scheme(serder, sigers, vec![]).is_err());
+        let loc = db.locs.get(&[pre.as_str(), "mailbox", "http"])?.unwrap();
+        assert_eq!(loc.url, "http://127.0.0.1:7723/");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ends_locs_sub_dbs() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_ends_locs_sub_dbs")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+
+        // Locations keyed by (eid, role, scheme) and endpoints keyed by
+        // (cid, role, eid) each live in their own sub db apart from the key states
+        let eid = "BAd2pQf3bD6KkVRmPzc-6JLNG0U8ZTlvUgPQK4pmlWIe";
+        db.locs.pin(
+            &[eid, "witness", "http"],
+            &LocationRecord::new("http://127.0.0.1:5632/".to_string()),
+        )?;
+        db.ends.pin(
+            &[pre.as_str(), "witness", eid],
+            &EndpointRecord::with_values(Some(true), None, String::new()),
+        )?;
+
+        assert_eq!(db.states.cnt_all()?, 1);
+        assert_eq!(db.locs.cnt_all()?, 1);
+        assert_eq!(db.ends.cnt_all()?, 1);
+        let states = db.states.get_item_iter(&[] as &[&str])?;
+        assert_eq!(states.len(), 1);
+        assert_eq!(states[0].1.i, pre);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stale_reply() -> Result<(), KERIError> {
+        let lmdber = setup_db("test_stale_reply")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+
+        let salter = other_salter()?;
+        let witness = &salter.signers(1, 0, "wit", None, Some(false), None, false)?[0];
+        let wit = witness.verfer().qb64();
+
+        let reply = |url: &str, stamp: &str| -> Result<(SerderKERI, Cigar), KERIError> {
+            let mut data = IndexMap::new();
+            data.insert("eid".to_string(), SadValue::String(wit.clone()));
+            data.insert("role".to_string(), SadValue::String("witness".to_string()));
+            data.insert("scheme".to_string(), SadValue::String("http".to_string()));
+            data.insert("url".to_string(), SadValue::String(url.to_string()));
+            let serder = ReplyEventBuilder::new()
+                .with_route("/loc/scheme".to_string())
+                .with_data(data)
+                .with_stamp(stamp.to_string())
+                .build()?;
+            let cigar = sign_cigar(witness, &serder)?;
+            Ok((serder, cigar))
+        };
+
+        let (older, ocigar) = reply("http://127.0.0.1:5632/", "2026-01-01T00:00:00.000000+00:00")?;
+        kevery.process_loc_scheme(older.clone(), vec![], vec![ocigar.clone()])?;
+
+        // Newer reply supersedes the older one and its artifacts
+        let (newer, ncigar) = reply("http://127.0.0.1:5642/", "2026-01-02T00:00:00.000000+00:00")?;
+        kevery.process_loc_scheme(newer.clone(), vec![], vec![ncigar.clone()])?;
+        let loc = db.locs.get(&[wit.as_str(), "witness", "http"])?.unwrap();
+        assert_eq!(loc.url, "http://127.0.0.1:5642/");
+        let osaid = older.said().unwrap();
+        assert!(db.rpys.get(&[osaid])?.is_none());
+        assert!(db.sdts.get(&[osaid])?.is_none());
+
+        // Replaying the older reply or the accepted one is stale
+        assert!(matches!(
+            kevery.process_loc_scheme(older, vec![], vec![ocigar]),
+            Err(KERIError::StaleReply(_))
+        ));
+        assert!(matches!(
+            kevery.process_loc_scheme(newer.clone(), vec![], vec![ncigar]),
+            Err(KERIError::StaleReply(_))
+        ));
+        let loc = db.locs.get(&[wit.as_str(), "witness", "http"])?.unwrap();
+        assert_eq!(loc.url, "http://127.0.0.1:5642/");
+        let saider = db.lans.get(&[wit.as_str(), "witness", "http"])?.unwrap();
+        assert_eq!(saider.qb64(), newer.said().unwrap());
+
+        // A reply dated beyond the skew tolerance is rejected before it is stored
+        let now: DateTime<Utc> = "2026-01-02T00:00:00+00:00".parse().unwrap();
+        kevery.clock = Arc::new(FixedClock(now));
+        let (future, fcigar) = reply("http://127.0.0.1:5652/", "2026-01-02T00:10:00.000000+00:00")?;
+        assert!(matches!(
+            kevery.process_loc_scheme(future, vec![], vec![fcigar]),
+            Err(KERIError::FutureDated(_))
+        ));
+        let loc = db.locs.get(&[wit.as_str(), "witness", "http"])?.unwrap();
+        assert_eq!(loc.url, "http://127.0.0.1:5642/");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_future_dated() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let (icp, sigers) = kel[0].clone();
+        let now: DateTime<Utc> = "2026-01-01T00:00:00+00:00".parse().unwrap();
+
+        let lmdber = setup_db("test_future_dated")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = KeveryBuilder::new(Arc::new(&db))
+            .with_lax(true)
+            .with_local(false)
+            .with_cloned(true)
+            .with_clock(Arc::new(FixedClock(now)))
+            .with_skew(300)
+            .build()?;
+        assert_eq!(kevery.skew, 300);
+
+        // Ten minutes ahead is beyond the five minute tolerance
+        let dater = Dater::from_dt(now + chrono::Duration::minutes(10))?;
+        let result = kevery.process_event(
+            icp.clone(),
+            sigers.clone(),
+            None,
+            None,
+            None,
+            None,
+            Some(dater),
+            None,
+            None,
+        );
+        assert!(matches!(result, Err(KERIError::FutureDated(_))));
+        assert!(!kevery.kevers.contains_key(&icp.pre().unwrap()));
+
+        // Four minutes ahead is within tolerance
+        let dater = Dater::from_dt(now + chrono::Duration::minutes(4))?;
+        kevery.check_future_dated(&dater)?;
+        kevery.process_event(icp.clone(), sigers, None, None, None, None, Some(dater), None, None)?;
+        assert!(kevery.kevers.contains_key(&icp.pre().unwrap()));
 
         Ok(())
     }
@@ -3,7 +3,9 @@ use std::collections::HashSet;
 use std::error::Error;
 
 use crate::cesr::cigar::Cigar;
+use crate::cesr::diger::Diger;
 use crate::cesr::non_trans_dex;
+use crate::cesr::prefixer::Prefixer;
 use crate::cesr::seqner::Seqner;
 use crate::keri::core::serdering::{Serder, SerderKERI};
 
@@ -20,8 +22,11 @@ pub mod state;
 use crate::cesr::counting::{ctr_dex_1_0, BaseCounter, Counter};
 use crate::cesr::indexing::siger::Siger;
 use crate::cesr::indexing::Indexer;
-use crate::cesr::tholder::Tholder;
+use crate::cesr::tholder::{Tholder, TholderSith};
 use crate::cesr::verfer::Verfer;
+use crate::errors::MatterError;
+use crate::keri::core::serdering::SadValue;
+use crate::keri::db::basing::KeyStateRecord;
 use crate::keri::KERIError;
 pub use incept::*;
 pub use interact::*;
@@ -144,6 +149,114 @@ pub fn validate_sigs(
     Ok((verified_sigers, valid))
 }
 
+/// Verify the signatures of an event against a key state received out of band
+///
+/// Uses the signing keys `k` and signing threshold `kt` of `state` instead of the
+/// key state of a local Kever, so `state` must be the key state the event was
+/// signed under.
+///
+/// # Parameters
+/// * `serder` - Event to verify
+/// * `sigers` - Indexed signatures on the event
+/// * `state` - External key state of the event's identifier
+///
+/// # Returns
+/// * `Result<bool, KERIError>` - true if the verified signatures satisfy the state's
+///   threshold, false if they do not or `state` is for another identifier
+pub fn verify_event_with_state(
+    serder: &SerderKERI,
+    sigers: Vec<Siger>,
+    state: &KeyStateRecord,
+) -> Result<bool, KERIError> {
+    if serder.pre().as_deref() != Some(state.i.as_str()) {
+        return Ok(false);
+    }
+
+    let verfers = state
+        .k
+        .iter()
+        .map(|key| Verfer::from_qb64(key))
+        .collect::<Result<Vec<Verfer>, _>>()?;
+    let sith = TholderSith::from_sad_value(SadValue::String(state.kt.clone()))?;
+    let tholder = Tholder::new(None, None, Some(sith))?;
+    if verfers.len() < tholder.size() {
+        return Err(KERIError::ValidationError(format!(
+            "Invalid sith = {} for keys = {:?}",
+            tholder.sith(),
+            state.k
+        )));
+    }
+
+    let (_, indices) = verify_sigs(serder.raw(), sigers, &verfers)?;
+    Ok(!indices.is_empty() && tholder.satisfy(&indices))
+}
+
+/// Check that every primitive field of an event is well formed qb64
+///
+/// Parses the identifier `i` and `di`, SAID `d`, signing keys `k`, next key
+/// digests `n` and witness entries `b`, `br` and `ba` with the primitive type each
+/// holds and requires the parse to round trip, which rejects characters outside
+/// the base64url alphabet and values that are too short or too long for their code.
+///
+/// # Parameters
+/// * `serder` - Event to check
+///
+/// # Returns
+/// * `Result<(), KERIError>` - FieldError naming the first offending field
+pub fn validate_primitives(serder: &SerderKERI) -> Result<(), KERIError> {
+    type Parse = fn(&str) -> Result<String, MatterError>;
+    let prefix: Parse = |v| Ok(Prefixer::from_qb64(v)?.qb64());
+    let digest: Parse = |v| Ok(Diger::from_qb64(v)?.qb64());
+    let key: Parse = |v| Ok(Verfer::from_qb64(v)?.qb64());
+    let fields: [(&str, Parse); 8] = [
+        ("i", prefix),
+        ("d", digest),
+        ("k", key),
+        ("n", digest),
+        ("b", prefix),
+        ("br", prefix),
+        ("ba", prefix),
+        ("di", prefix),
+    ];
+
+    let ked = serder.ked();
+    for (field, parse) in fields {
+        let values = match ked.get(field) {
+            Some(SadValue::String(v)) => vec![(field.to_string(), Some(v.as_str()))],
+            Some(SadValue::Array(items)) => items
+                .iter()
+                .enumerate()
+                .map(|(idx, item)| (format!("{}[{}]", field, idx), item.as_str()))
+                .collect(),
+            Some(_) => vec![(field.to_string(), None)],
+            None => continue,
+        };
+
+        for (name, value) in values {
+            let value = value.ok_or_else(|| {
+                KERIError::FieldError(format!("Invalid {} = not a qb64 string", name))
+            })?;
+            match parse(value) {
+                Ok(qb64) if qb64 == value => {}
+                Ok(_) => {
+                    return Err(KERIError::FieldError(format!(
+                        "Invalid {} = {}: wrong size for code",
+                        name, value
+                    )))
+                }
+                Err(e) => {
+                    return Err(KERIError::FieldError(format!(
+                        "Invalid {} = {}: {}",
+                        name, value, e
+                    )))
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 pub fn messagize(
     serder: &SerderKERI,
     sigers: Option<&[Siger]>,
@@ -1048,4 +1161,92 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_verify_event_with_state() -> Result<(), Box<dyn Error>> {
+        let salter = Salter::new(Some(b"0123456789abcdef"), None, None)?;
+        let signers = salter.signers(2, 0, "", None, None, None, false)?;
+
+        let icp = InceptionEventBuilder::new(vec![signers[0].verfer().qb64()]).build()?;
+        let pre = icp.pre().unwrap();
+        let ixn = InteractEventBuilder::new(pre.clone(), icp.said().unwrap().to_string())
+            .with_sn(1)
+            .build()?;
+        let sigers = match signers[0].sign(ixn.raw(), Some(0), None, None)? {
+            Sigmat::Indexed(siger) => vec![siger],
+            Sigmat::NonIndexed(_) => panic!("Unexpected non-indexed signature"),
+        };
+
+        let state = KeyStateRecord {
+            i: pre.clone(),
+            kt: "1".to_string(),
+            k: vec![signers[0].verfer().qb64()],
+            ..Default::default()
+        };
+        assert!(verify_event_with_state(&ixn, sigers.clone(), &state)?);
+
+        // State with other keys
+        let other = KeyStateRecord {
+            k: vec![signers[1].verfer().qb64()],
+            ..state.clone()
+        };
+        assert!(!verify_event_with_state(&ixn, sigers.clone(), &other)?);
+
+        // State of another identifier
+        let other = KeyStateRecord {
+            i: "EBG9LuUbFzV4OV5cGS9IeQWzy9SuyVFyVrpRc4l1xzPA".to_string(),
+            ..state.clone()
+        };
+        assert!(!verify_event_with_state(&ixn, sigers, &other)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_primitives() -> Result<(), Box<dyn Error>> {
+        let salter = Salter::new(Some(b"0123456789abcdef"), None, None)?;
+        let signers = salter.signers(2, 0, "", None, None, None, false)?;
+
+        let nxt = vec![Diger::from_ser(&signers[1].verfer().qb64b(), None)?.qb64()];
+        let icp = InceptionEventBuilder::new(vec![signers[0].verfer().qb64()])
+            .with_ndigs(nxt.clone())
+            .build()?;
+        validate_primitives(&icp)?;
+        let pre = icp.pre().unwrap();
+        let prior = icp.said().unwrap().to_string();
+
+        let key = signers[1].verfer().qb64();
+        let rot = RotateEventBuilder::new(pre.clone(), vec![key.clone()], prior.clone())
+            .with_sn(1)
+            .with_ndigs(nxt.clone())
+            .build()?;
+        validate_primitives(&rot)?;
+
+        // Truncated verkey is reported by field and index
+        let rot = RotateEventBuilder::new(
+            pre.clone(),
+            vec![key.clone(), key[..key.len() - 4].to_string()],
+            prior.clone(),
+        )
+        .with_sn(1)
+        .with_ndigs(nxt.clone())
+        .build()?;
+        match validate_primitives(&rot) {
+            Err(KERIError::FieldError(msg)) => assert!(msg.starts_with("Invalid k[1] = ")),
+            other => panic!("Expected FieldError for k[1], got {:?}", other),
+        }
+
+        // Character outside the base64url alphabet
+        let bad = format!("{}+", &nxt[0][..nxt[0].len() - 1]);
+        let rot = RotateEventBuilder::new(pre, vec![key], prior)
+            .with_sn(1)
+            .with_ndigs(vec![bad])
+            .build()?;
+        match validate_primitives(&rot) {
+            Err(KERIError::FieldError(msg)) => assert!(msg.starts_with("Invalid n[0] = ")),
+            other => panic!("Expected FieldError for n[0], got {:?}", other),
+        }
+
+        Ok(())
+    }
 }
@@ -1,7 +1,7 @@
 use crate::cesr::Versionage;
 use crate::keri::core::serdering::{SadValue, SerderKERI};
-use crate::keri::{versify, Ilks, Kinds};
-use chrono::{DateTime, Utc};
+use crate::keri::help::helping::nowiso8601;
+use crate::keri::{versify, Ilks, KERIError, Kinds};
 use indexmap::IndexMap;
 use std::error::Error;
 
@@ -87,10 +87,7 @@ impl QueryEventBuilder {
         // Generate timestamp if not provided
         let timestamp = match self.stamp {
             Some(ts) => ts,
-            None => {
-                let now: DateTime<Utc> = Utc::now();
-                now.to_rfc3339()
-            }
+            None => nowiso8601(),
         };
 
         // Create the key event dict (ked)
@@ -115,6 +112,39 @@ impl QueryEventBuilder {
     }
 }
 
+/// Build a `logs` query for the events of `pre` in an inclusive sn range
+///
+/// The range is carried in the `q` field as hex sns `s` (first) and `e` (last)
+/// alongside the identifier `i`, such as to fill gaps found with `Baser::kel_gaps`.
+///
+/// # Parameters
+/// * `pre` - qb64 identifier prefix whose events are requested
+/// * `from_sn` - First sn of the range
+/// * `to_sn` - Last sn of the range
+///
+/// # Returns
+/// * `Result<SerderKERI, KERIError>` - Query event, ValueError if `from_sn` is
+///   greater than `to_sn`
+pub fn query_range(pre: &str, from_sn: u64, to_sn: u64) -> Result<SerderKERI, KERIError> {
+    if from_sn > to_sn {
+        return Err(KERIError::ValueError(format!(
+            "Invalid sn range from={} to={}.",
+            from_sn, to_sn
+        )));
+    }
+
+    let mut query = IndexMap::new();
+    query.insert("i".to_string(), SadValue::String(pre.to_string()));
+    query.insert("s".to_string(), SadValue::String(format!("{:x}", from_sn)));
+    query.insert("e".to_string(), SadValue::String(format!("{:x}", to_sn)));
+
+    QueryEventBuilder::new()
+        .with_route("logs".to_string())
+        .with_query(query)
+        .build()
+        .map_err(|e| KERIError::ValueError(format!("Failed to build query event: {}", e)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -301,6 +331,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_query_range() -> Result<(), Box<dyn Error>> {
+        let pre = "EaU6JR2nmwyZ-i0d8JZAoTNZH3ULvYAfSVPzhzS6b5CM";
+        let serder = query_range(pre, 2, 26)?;
+
+        let ked = serder.ked();
+        assert_eq!(ked["t"].as_str().unwrap(), Ilks::QRY);
+        assert_eq!(ked["r"].as_str().unwrap(), "logs");
+
+        let q = match &ked["q"] {
+            SadValue::Object(obj) => obj,
+            _ => panic!("Expected q field to be an object"),
+        };
+        assert_eq!(q["i"].as_str().unwrap(), pre);
+        assert_eq!(q["s"].as_str().unwrap(), "2");
+        assert_eq!(q["e"].as_str().unwrap(), "1a");
+
+        assert!(query_range(pre, 3, 3).is_ok());
+        assert!(query_range(pre, 4, 3).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_query_event_builder_serialization() -> Result<(), Box<dyn Error>> {
         // Create query data
@@ -1,7 +1,7 @@
 use crate::cesr::Versionage;
 use crate::keri::core::serdering::{SadValue, SerderKERI};
+use crate::keri::help::helping::nowiso8601;
 use crate::keri::{versify, Ilks, KERIError, Kinds};
-use chrono::{DateTime, Utc};
 use indexmap::IndexMap;
 
 /// Builder for creating KERI reply events
@@ -78,10 +78,7 @@ impl ReplyEventBuilder {
         let vs = versify("KERI", &Versionage::from(self.version), &self.kind, 0)?;
 
         // Generate timestamp if not provided
-        let timestamp = self.stamp.unwrap_or_else(|| {
-            let now: DateTime<Utc> = Utc::now();
-            now.to_rfc3339()
-        });
+        let timestamp = self.stamp.unwrap_or_else(nowiso8601);
 
         // Create the key event dict (ked)
         let mut ked = IndexMap::new();
@@ -3,6 +3,7 @@ pub mod eventing;
 pub mod filing;
 pub mod parsing;
 pub mod routing;
+pub mod seal;
 pub mod serdering;
 
 use regex::bytes::Regex;
@@ -91,7 +92,7 @@ pub fn sizeify(
     };
 
     // Update version string with latest kind and size
-    let vs = versify("KERI", &vrsn, &kind.to_string(), size as u64)?;
+    let vs = versify(&proto, &vrsn, &kind.to_string(), size as u64)?;
 
     // Find version string in raw
     let fore = &raw[..fore];
@@ -13,7 +13,7 @@ use crate::cesr::{sniff, Parsable, Versionage, VRSN_1_0};
 use crate::errors::MatterError;
 use crate::keri::core::eventing::Kevery;
 use crate::keri::core::serdering::{Serder, SerderACDC, SerderKERI, Serdery};
-use crate::keri::{Ilk, KERIError};
+use crate::keri::{smell, Ilk, KERIError, SMELLSIZE};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use tokio::io::{AsyncRead, AsyncReadExt};
@@ -205,11 +205,15 @@ pub trait MessageHandler: Send + Sync {
     async fn handle(&self, msg: Message) -> Result<(), KERIError>;
 }
 
+/// Default maximum size in bytes of an event body accepted by the parser
+pub const MAX_EVENT_SIZE: usize = 1 << 20;
+
 pub struct Parser<'a, R> {
     reader: R,
     buffer: Vec<u8>,
     framed: bool,
     pipeline: bool,
+    max_event_size: usize,
     handlers: Handlers<'a>,
     attachment_processing: bool, // Flag to mark if we're in the middle of attachments
     current_serder: Option<Box<dyn Serder>>,
@@ -235,7 +239,9 @@ impl<'a, R> Parser<'a, R> {
         let original_buffer = std::mem::replace(&mut self.buffer, temp_buffer);
 
         // Process the message
-        let result = self.try_parse_one_message();
+        let result = self
+            .check_event_size()
+            .and_then(|_| self.try_parse_one_message());
 
         // Restore original buffer state
         self.buffer = original_buffer;
@@ -243,6 +249,28 @@ impl<'a, R> Parser<'a, R> {
         result
     }
 
+    /// Reject the message at the front of the buffer if its body is too large
+    ///
+    /// The body size is read from the version string so an oversized event is
+    /// rejected before its body has been buffered. Buffers positioned at
+    /// attachments or too short to hold a version string are left to the parser.
+    fn check_event_size(&self) -> Result<(), KERIError> {
+        if self.buffer.first() == Some(&b'-') || self.buffer.len() < SMELLSIZE {
+            return Ok(());
+        }
+
+        if let Ok(smellage) = smell(&self.buffer[..SMELLSIZE]) {
+            if smellage.size > self.max_event_size {
+                return Err(KERIError::EventTooLarge {
+                    size: smellage.size,
+                    max: self.max_event_size,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     /// Internal method to process one complete message from the current buffer
     fn try_parse_one_message(&mut self) -> Result<(), KERIError> {
         // Parse one message from the buffer
@@ -809,6 +837,7 @@ impl<'a, R> Parser<'a, R> {
             buffer: Vec::new(),
             framed,
             pipeline,
+            max_event_size: MAX_EVENT_SIZE,
             handlers,
             attachment_processing: true,
             current_serder: None,
@@ -816,6 +845,12 @@ impl<'a, R> Parser<'a, R> {
         }
     }
 
+    /// Set the maximum event body size in bytes, larger events fail with EventTooLarge
+    pub fn with_max_event_size(mut self, max_event_size: usize) -> Self {
+        self.max_event_size = max_event_size;
+        self
+    }
+
     // Helper method to process a single counter and its data
     fn process_attachments(
         &mut self,
@@ -1512,7 +1547,7 @@ impl<'a, R> Parser<'a, R> {
 
         return match result {
             Ok(instance) => Ok(instance),
-            Err(MatterError::ShortageError(_)) if !abort => {
+            Err(MatterError::ShortageError { .. }) if !abort => {
                 // In Python, this would yield control back to caller
                 // In Rust, we need to signal that more data is needed
                 Err(MatterError::NeedMoreDataError(
@@ -1791,6 +1826,12 @@ impl<'a, R: AsyncRead + Unpin + Send> Parser<'a, R> {
                     }
                 }
 
+                if let Err(e) = self.check_event_size() {
+                    self.attachment_processing = false;
+                    self.current_serder = None;
+                    return Err(e);
+                }
+
                 match self.try_parse_message() {
                     Ok((msg, _size)) => {
                         made_progress = true;
@@ -1892,10 +1933,11 @@ mod tests {
     use crate::keri::core::eventing::{
         InceptionEventBuilder, InteractEventBuilder, Kever, KeveryBuilder, RotateEventBuilder,
     };
-    use crate::keri::core::serdering::Rawifiable;
+    use crate::keri::core::serdering::{Rawifiable, SadValue};
     use crate::keri::db::basing::Baser;
     use crate::keri::db::dbing::LMDBer;
     use crate::Matter;
+    use indexmap::IndexMap;
 
     #[tokio::test]
     async fn test_parse_kel_file() -> Result<(), KERIError> {
@@ -2162,6 +2204,55 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_parser_max_event_size() -> Result<(), KERIError> {
+        let salter = Salter::new(Some(b"0123456789abcdef"), None, None)?;
+        let signers = salter.signers(1, 0, "", None, None, None, false)?;
+
+        // Inception padded with an 11KB anchor
+        let filler = "x".repeat(11 * 1024);
+        let mut seal = IndexMap::new();
+        seal.insert("d".to_string(), SadValue::String(filler));
+        let icp = InceptionEventBuilder::new(vec![signers[0].verfer().qb64()])
+            .with_data(vec![SadValue::Object(seal)])
+            .build()?;
+        assert!(icp.size() > 11 * 1024);
+
+        let lmdber = &LMDBer::builder()
+            .temp(true)
+            .name("test_parser_max_event_size")
+            .build()
+            .map_err(|e| KERIError::DatabaseError(format!("{}", e)))?;
+        let db =
+            Baser::new(Arc::new(lmdber)).map_err(|e| KERIError::DatabaseError(format!("{}", e)))?;
+        let kevery = KeveryBuilder::new(Arc::new(&db))
+            .with_lax(true)
+            .with_local(false)
+            .build()?;
+        let handlers = Handlers {
+            kevery: Arc::new(kevery.into()),
+            tevery: Arc::new(MockHandler { serder: None }),
+            exchanger: Arc::new(MockHandler { serder: None }),
+            revery: Arc::new(MockHandler { serder: None }),
+            verifier: Arc::new(MockHandler { serder: None }),
+            local: false,
+        };
+
+        let reader = tokio::io::BufReader::new(icp.raw());
+        let mut parser =
+            Parser::new(reader, true, false, handlers).with_max_event_size(10 * 1024);
+        let result = parser.parse_stream(Some(true)).await;
+        assert!(matches!(
+            result,
+            Err(KERIError::EventTooLarge { size, max: 10240 }) if size == icp.size()
+        ));
+
+        let result = parser.parse_one(icp.raw());
+        assert!(matches!(result, Err(KERIError::EventTooLarge { .. })));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_parser_valid_message() {
         // Provide CESR-encoded message bytes matching KERIpy tests
@@ -7,6 +7,7 @@ use crate::cesr::seqner::Seqner;
 use crate::cesr::verfer::Verfer;
 use crate::cesr::Matter;
 use crate::keri::core::eventing;
+use crate::keri::core::eventing::{Clock, Kevery, SystemClock};
 use crate::keri::core::serdering::{Rawifiable, Serder, SerderKERI};
 use crate::keri::db::basing::Baser;
 use crate::keri::db::dbing::keys::{dg_key, sn_key};
@@ -36,6 +37,13 @@ pub struct Revery<'db> {
 
     /// Local vs nonlocal restrictions
     pub local: bool,
+
+    /// Source of the current datetime for future dated checks
+    pub clock: Arc<dyn Clock>,
+
+    /// Seconds a reply dt may be ahead of the clock before the reply is
+    /// rejected as future dated
+    pub skew: u64,
 }
 
 /// Cue for reply message processing
@@ -60,6 +68,8 @@ impl<'db> Revery<'db> {
             cues: cues.unwrap_or_else(VecDeque::new),
             lax: lax.unwrap_or(true),
             local: local.unwrap_or(false),
+            clock: Arc::new(SystemClock),
+            skew: Kevery::SKEW_TOLERANCE,
         }
     }
 
@@ -80,6 +90,7 @@ impl<'db> Revery<'db> {
     /// BADA (Best Available Data Acceptance) model for each reply message.
     /// Latest-Seen-Signed Pairwise comparison of new update reply compared to
     /// old already accepted reply from same source for same route (same data).
+    /// A reply whose dt is later than now plus .skew is rejected with FutureDated.
     pub fn process_reply(
         &self,
         serder: SerderKERI,
@@ -103,6 +114,21 @@ impl<'db> Revery<'db> {
             )));
         }
 
+        let dater =
+            Dater::from_dts(ked.get("dt").and_then(|v| v.as_str()).ok_or_else(|| {
+                KERIError::ValueError("Missing 'dt' field in reply".to_string())
+            })?)?;
+        let limit = self.clock.now() + chrono::Duration::seconds(self.skew as i64);
+        if dater.dt()? > limit {
+            return Err(KERIError::FutureDated(format!(
+                "Reply said={} dt={} is later than {} allowed with skew of {}s.",
+                saider.qb64(),
+                dater.dts(),
+                limit.to_rfc3339(),
+                self.skew
+            )));
+        }
+
         // Dispatch to appropriate route handler
         self.rtr
             .dispatch(&serder, &saider, cigars.as_deref(), tsgs.as_deref())?;
@@ -114,6 +140,8 @@ impl<'db> Revery<'db> {
     ///
     /// # Returns
     /// * `true` if successfully accepted, `false` otherwise
+    /// * StaleReply if no signature was accepted and at least one was skipped
+    ///   for being no later than the previously accepted reply
     ///
     /// # Parameters
     /// * `serder` - Instance of reply msg (SAD)
@@ -135,6 +163,7 @@ impl<'db> Revery<'db> {
         tsgs: Option<&[(Prefixer, Seqner, Saider, Vec<Siger>)]>, // Third element is Saider
     ) -> Result<bool, KERIError> {
         let mut accepted = false;
+        let mut stale = false;
         let cigars = cigars.unwrap_or(&[]);
         let tsgs = tsgs.unwrap_or(&[]);
 
@@ -213,6 +242,7 @@ impl<'db> Revery<'db> {
                             .unwrap_or("unknown")
                     );
                     trace!("Reply Body=\n{}\n", serder.pretty(None));
+                    stale = true;
                     continue;
                 }
             }
@@ -280,6 +310,7 @@ impl<'db> Revery<'db> {
                             serder.said().unwrap_or_default()
                         );
                         debug!("event=\n{}\n", serder.pretty(None));
+                        stale = true;
                         continue;
                     }
 
@@ -291,6 +322,7 @@ impl<'db> Revery<'db> {
                                     aid, serder.said().unwrap_or_default()
                                 );
                                 debug!("event=\n{}\n", serder.pretty(None));
+                                stale = true;
                                 continue;
                             }
                         }
@@ -299,10 +331,14 @@ impl<'db> Revery<'db> {
             }
 
             // Retrieve last event at sequence number for signer
-            let sdig = self
-                .db
-                .kels
-                .get_last::<_, Vec<u8>>(&[&sn_key(spre, seqner.sn())])?;
+            let sn = u64::try_from(seqner.sn()).map_err(|_| {
+                KERIError::ValidationError(format!(
+                    "Signer sn={} out of range on reply said={}",
+                    seqner.sn(),
+                    serder.said().unwrap_or_default()
+                ))
+            })?;
+            let sdig = self.db.kels.get_last::<_, Vec<u8>>(&[&sn_key(spre, sn)])?;
 
             let sdig = match sdig {
                 Some(dig_bytes) => String::from_utf8(dig_bytes)
@@ -437,6 +473,15 @@ impl<'db> Revery<'db> {
             }
         }
 
+        if !accepted && stale {
+            return Err(KERIError::StaleReply(format!(
+                "Reply said={} at route={} is not later than said={}.",
+                saider.qb64(),
+                route,
+                osaider.map(|osaider| osaider.qb64()).unwrap_or_default()
+            )));
+        }
+
         Ok(accepted)
     }
 
@@ -657,7 +702,12 @@ impl<'db> Revery<'db> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cesr::signing::{Salter, Sigmat};
+    use crate::keri::core::eventing::{FixedClock, ReplyEventBuilder};
+    use crate::keri::core::serdering::SadValue;
     use crate::keri::db::dbing::LMDBer;
+    use chrono::{DateTime, Utc};
+    use indexmap::IndexMap;
 
     #[test]
     fn test_revery_new() -> Result<(), KERIError> {
@@ -678,4 +728,156 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_accept_reply_stale() -> Result<(), KERIError> {
+        let lmdber = &LMDBer::builder()
+            .temp(true)
+            .name("test_accept_reply_stale")
+            .build()
+            .map_err(|e| KERIError::DatabaseError(format!("{}", e)))?;
+        let db =
+            Baser::new(Arc::new(lmdber)).map_err(|e| KERIError::DatabaseError(format!("{}", e)))?;
+        let mut revery = Revery::new(Arc::new(&db), None, None, Some(true), Some(false));
+
+        let salter = Salter::new(Some(b"0123456789abcdef"), None, None)?;
+        let signer = &salter.signers(1, 0, "wit", None, Some(false), None, false)?[0];
+        let aid = signer.verfer().qb64();
+        let route = "/loc/scheme";
+
+        let reply = |stamp: &str| -> Result<(SerderKERI, Saider, Siger), KERIError> {
+            let mut data = IndexMap::new();
+            data.insert("eid".to_string(), SadValue::String(aid.clone()));
+            data.insert("scheme".to_string(), SadValue::String("http".to_string()));
+            let serder = ReplyEventBuilder::new()
+                .with_route(route.to_string())
+                .with_data(data)
+                .with_stamp(stamp.to_string())
+                .build()?;
+            let saider = Saider::from_qb64(&serder.said().unwrap())?;
+            let mut cigar = match signer.sign(serder.raw(), Some(0), None, None)? {
+                Sigmat::Indexed(siger) => siger,
+                _ => panic!("Expected indexed signature"),
+            };
+            cigar.set_verfer(signer.verfer().clone());
+            Ok((serder, saider, cigar))
+        };
+
+        let (newer, nsaider, ncigar) = reply("2026-01-02T00:00:00.000000+00:00")?;
+        let cigars = [ncigar];
+        assert!(revery.accept_reply(
+            &newer,
+            &nsaider,
+            route,
+            &aid,
+            None,
+            Some(&cigars[..]),
+            None
+        )?);
+
+        // An older dt after the newer reply was accepted is stale
+        let (older, osaider, ocigar) = reply("2026-01-01T00:00:00.000000+00:00")?;
+        let cigars = [ocigar];
+        assert!(matches!(
+            revery.accept_reply(
+                &older,
+                &osaider,
+                route,
+                &aid,
+                Some(&nsaider),
+                Some(&cigars[..]),
+                None
+            ),
+            Err(KERIError::StaleReply(_))
+        ));
+        assert!(db.sdts.get(&[&nsaider.qb64()])?.is_some());
+        assert!(db.sdts.get(&[&osaider.qb64()])?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_accept_reply_sn_overflow() -> Result<(), KERIError> {
+        let lmdber = &LMDBer::builder()
+            .temp(true)
+            .name("test_accept_reply_sn_overflow")
+            .build()
+            .map_err(|e| KERIError::DatabaseError(format!("{}", e)))?;
+        let db =
+            Baser::new(Arc::new(lmdber)).map_err(|e| KERIError::DatabaseError(format!("{}", e)))?;
+        let mut revery = Revery::new(Arc::new(&db), None, None, Some(true), Some(false));
+
+        let salter = Salter::new(Some(b"0123456789abcdef"), None, None)?;
+        let signer = &salter.signers(1, 0, "ctl", None, None, None, false)?[0];
+        let aid = signer.verfer().qb64();
+        let route = "/end/role/add";
+        let mut data = IndexMap::new();
+        data.insert("cid".to_string(), SadValue::String(aid.clone()));
+        let serder = ReplyEventBuilder::new()
+            .with_route(route.to_string())
+            .with_data(data)
+            .with_stamp("2026-01-01T00:00:00.000000+00:00".to_string())
+            .build()?;
+        let saider = Saider::from_qb64(&serder.said().unwrap())?;
+        let siger = match signer.sign(serder.raw(), Some(0), None, None)? {
+            Sigmat::Indexed(siger) => siger,
+            _ => panic!("Expected indexed signature"),
+        };
+
+        // Signer sn past u64 cannot name a KEL entry
+        let tsgs = [(
+            Prefixer::from_qb64(&aid)?,
+            Seqner::from_sn(u64::MAX as u128 + 1),
+            saider.clone(),
+            vec![siger],
+        )];
+        assert!(matches!(
+            revery.accept_reply(&serder, &saider, route, &aid, None, None, Some(&tsgs[..])),
+            Err(KERIError::ValidationError(_))
+        ));
+        assert!(db.rpes.get(&[route])?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_reply_future_dated() -> Result<(), KERIError> {
+        let lmdber = &LMDBer::builder()
+            .temp(true)
+            .name("test_process_reply_future_dated")
+            .build()
+            .map_err(|e| KERIError::DatabaseError(format!("{}", e)))?;
+        let db =
+            Baser::new(Arc::new(lmdber)).map_err(|e| KERIError::DatabaseError(format!("{}", e)))?;
+        let mut revery = Revery::new(Arc::new(&db), None, None, Some(true), Some(false));
+        let now: DateTime<Utc> = "2026-01-01T00:00:00+00:00".parse().unwrap();
+        revery.clock = Arc::new(FixedClock(now));
+        revery.skew = 300;
+
+        let salter = Salter::new(Some(b"0123456789abcdef"), None, None)?;
+        let signer = &salter.signers(1, 0, "wit", None, Some(false), None, false)?[0];
+        let mut data = IndexMap::new();
+        data.insert("eid".to_string(), SadValue::String(signer.verfer().qb64()));
+        data.insert("scheme".to_string(), SadValue::String("http".to_string()));
+        let serder = ReplyEventBuilder::new()
+            .with_route("/loc/scheme".to_string())
+            .with_data(data)
+            .with_stamp("2026-01-01T00:10:00.000000+00:00".to_string())
+            .build()?;
+        let mut cigar = match signer.sign(serder.raw(), Some(0), None, None)? {
+            Sigmat::Indexed(siger) => siger,
+            _ => panic!("Expected indexed signature"),
+        };
+        cigar.set_verfer(signer.verfer().clone());
+        let said = serder.said().unwrap().to_string();
+
+        // Ten minutes ahead is beyond the five minute tolerance
+        assert!(matches!(
+            revery.process_reply(serder, Some(vec![cigar]), None),
+            Err(KERIError::FutureDated(_))
+        ));
+        assert!(db.rpys.get(&[&said])?.is_none());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,72 @@
+use crate::keri::core::eventing::{Seal, SealEvent, SealLast};
+use crate::keri::core::serdering::{SadValue, Sadder};
+use indexmap::IndexMap;
+
+/// Build an event seal `(i, s, d)` referencing an event of another KEL
+///
+/// # Parameters
+/// * `pre` - Identifier prefix of the sealed event
+/// * `sn` - Sequence number of the sealed event, stored as lowercase hex
+/// * `dig` - SAID of the sealed event
+pub fn event_seal(pre: &str, sn: u64, dig: &str) -> Seal {
+    Seal::SealEvent(SealEvent::new(
+        pre.to_string(),
+        format!("{:x}", sn),
+        dig.to_string(),
+    ))
+}
+
+/// Field map of a seal in the shape it takes inside an event's `a` list
+///
+/// # Parameters
+/// * `seal` - Seal to convert
+pub fn seal_sad(seal: &Seal) -> Sadder {
+    let mut sad = IndexMap::new();
+    match seal {
+        Seal::SealEvent(SealEvent { i, s, d }) => {
+            sad.insert("i".to_string(), SadValue::String(i.clone()));
+            sad.insert("s".to_string(), SadValue::String(s.clone()));
+            sad.insert("d".to_string(), SadValue::String(d.clone()));
+        }
+        Seal::SealLast(SealLast { i }) => {
+            sad.insert("i".to_string(), SadValue::String(i.clone()));
+        }
+    }
+
+    sad
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keri::{KERIError, Kinds};
+
+    #[test]
+    fn test_event_seal() -> Result<(), KERIError> {
+        let pre = "EBG9LuUbFzV4OV5cGS9IeQWzy9SuyVFyVrpRc4l1xzPA";
+        let dig = "EHuTbDjh_o0ZxSYkXW9Xz7x-UA4lnmGfnO1xjRMAL-Hj";
+
+        let seal = event_seal(pre, 26, dig);
+        match &seal {
+            Seal::SealEvent(seal) => {
+                assert_eq!(seal.i, pre);
+                assert_eq!(seal.s, "1a");
+                assert_eq!(seal.d, dig);
+            }
+            _ => panic!("Expected event seal"),
+        }
+
+        let sad = seal_sad(&seal);
+        let raw = SadValue::dumps(&sad, &Kinds::Json)?;
+        assert_eq!(
+            raw,
+            format!(r#"{{"i":"{}","s":"1a","d":"{}"}}"#, pre, dig).into_bytes()
+        );
+
+        // Fits the anchor list of an interaction event
+        let data = vec![SadValue::Object(sad)];
+        assert_eq!(data[0].get("s").and_then(|s| s.as_str()), Some("1a"));
+
+        Ok(())
+    }
+}
@@ -45,6 +45,22 @@ fn get_version_span(vrsn: &Versionage, kind: &Kinds) -> Result<usize, KERIError>
     }
 }
 
+/// Serialize a field map to compact JSON in its insertion order
+///
+/// SAIDs are computed over the serialized fields in the order they were inserted
+/// so the fields must never be sorted. Nested objects keep their order as well
+/// since they are IndexMaps too.
+///
+/// # Parameters
+/// * `map` - Field map such as the sad of a message
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - JSON bytes without whitespace
+/// * `Err(KERIError)` - JsonError if the map fails to serialize
+pub fn canonical_json(map: &IndexMap<String, SadValue>) -> Result<Vec<u8>, KERIError> {
+    serde_json::to_vec(map).map_err(|e| KERIError::JsonError(e.to_string()))
+}
+
 /// Base implementation of the Serder trait for serializable/deserializable entities
 #[derive(Debug, Clone)]
 pub struct BaseSerder {
@@ -356,10 +372,7 @@ impl BaseSerder {
     /// Returns a KERIError if serialization fails
     pub fn dumps(sad: &Sadder, kind: &Kinds) -> Result<Vec<u8>, KERIError> {
         match kind {
-            Kinds::Json => match serde_json::to_string(sad) {
-                Ok(json_str) => Ok(json_str.into_bytes()),
-                Err(e) => Err(KERIError::DeserializeError(e.to_string())),
-            },
+            Kinds::Json => canonical_json(sad),
             Kinds::Mgpk => match rmp_serde::to_vec(sad) {
                 Ok(mgpk_bytes) => Ok(mgpk_bytes),
                 Err(e) => Err(KERIError::DeserializeError(e.to_string())),
@@ -1695,6 +1708,39 @@ mod tests {
         assert!(validate(&icp_event).is_ok());
     }
 
+    #[test]
+    fn test_canonical_json() -> Result<(), KERIError> {
+        let map = indexmap! {
+            "z".to_string() => SadValue::from_string("1"),
+            "a".to_string() => SadValue::Object(indexmap! {
+                "y".to_string() => SadValue::from_string("2"),
+                "b".to_string() => SadValue::from_array(Vec::new()),
+            }),
+            "m".to_string() => SadValue::Bool(true),
+        };
+        assert_eq!(
+            canonical_json(&map)?,
+            br#"{"z":"1","a":{"y":"2","b":[]},"m":true}"#.to_vec()
+        );
+
+        // Round tripped event keeps its field order and SAID
+        let icp = crate::keri::core::eventing::InceptionEventBuilder::new(vec![
+            "DQbYDpQRN5cmkQ94mR69N_c98C0-SIVYEj2LM2VAGUhZ".to_string(),
+        ])
+        .build()?;
+        let serder = SerderKERI::from_raw(icp.raw(), None)?;
+        let sad = serder.sad();
+        let labels: Vec<&String> = sad.keys().collect();
+        assert_eq!(
+            labels,
+            vec!["v", "t", "d", "i", "s", "kt", "k", "nt", "n", "bt", "b", "c", "a"]
+        );
+        assert_eq!(canonical_json(&sad)?, icp.raw());
+        assert_eq!(serder.said(), icp.said());
+
+        Ok(())
+    }
+
     #[test]
     fn test_invalid_icp_event() {
         // Missing k field which is required for icp events
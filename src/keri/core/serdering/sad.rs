@@ -1,4 +1,5 @@
 use crate::cesr::{dig_dex, get_sizes, mtr_dex, BaseMatter};
+use crate::keri::core::serdering::canonical_json;
 use crate::keri::{Ilk, Ilks, KERIError, Kinds, Said};
 use crate::Matter;
 use indexmap::IndexMap;
@@ -88,10 +89,7 @@ impl SadValue {
     /// Returns a KERIError if serialization fails
     pub fn dumps(sad: &Sadder, kind: &Kinds) -> Result<Vec<u8>, KERIError> {
         match kind {
-            Kinds::Json => match serde_json::to_string(sad) {
-                Ok(json_str) => Ok(json_str.into_bytes()),
-                Err(e) => Err(KERIError::DeserializeError(e.to_string())),
-            },
+            Kinds::Json => canonical_json(sad),
             Kinds::Mgpk => match rmp_serde::to_vec(sad) {
                 Ok(mgpk_bytes) => Ok(mgpk_bytes),
                 Err(e) => Err(KERIError::DeserializeError(e.to_string())),
@@ -5,16 +5,20 @@ use crate::cesr::counting::{ctr_dex_1_0, BaseCounter, Counter};
 use crate::cesr::dater::Dater;
 use crate::cesr::diger::Diger;
 use crate::cesr::indexing::siger::Siger;
+use crate::cesr::indexing::Indexer;
+use crate::cesr::mtr_dex;
 use crate::cesr::num_dex;
 use crate::cesr::number::Number;
 use crate::cesr::prefixer::Prefixer;
 use crate::cesr::saider::Saider;
 use crate::cesr::seqner::Seqner;
+use crate::cesr::signing::{Sigmat, Signer};
 use crate::cesr::verfer::Verfer;
-use crate::keri::core::eventing::Kever;
+use crate::cesr::Parsable;
+use crate::keri::core::eventing::{messagize, Kever, ReplyEventBuilder, Seal, SealEvent};
 use crate::keri::core::filing::{BaseFiler, Filer, FilerDefaults};
-use crate::keri::core::serdering::{Serder, SerderKERI};
-use crate::keri::db::dbing::keys::dg_key;
+use crate::keri::core::serdering::{Rawifiable, SadValue, Serder, SerderKERI};
+use crate::keri::db::dbing::keys::{dg_key, sn_key, split_on_key};
 use crate::keri::db::dbing::LMDBer;
 use crate::keri::db::errors::DBError;
 use crate::keri::db::koming::{Komer, SerialKind};
@@ -37,7 +41,7 @@ pub use key_state_record::KeyStateRecord;
 pub use key_state_record::StateEERecord;
 use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -263,6 +267,24 @@ impl EndpointKey {
     }
 }
 
+/// Anomalies found by `Baser::self_check`, each described with the keys involved
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SelfCheckReport {
+    /// sn index entries in .kels whose event is missing or does not match pre, sn or dig
+    pub index: Vec<String>,
+    /// Escrow keys whose hidden ordinal proems are out of order
+    pub escrows: Vec<String>,
+    /// Prefixes whose cached key state differs from the state replayed from the KEL
+    pub states: Vec<String>,
+}
+
+impl SelfCheckReport {
+    /// True when no anomalies were found
+    pub fn is_clean(&self) -> bool {
+        self.index.is_empty() && self.escrows.is_empty() && self.states.is_empty()
+    }
+}
+
 /// Baser struct for key event log and escrow storage (DB)
 /// Sets up named sub databases for key event logs and escrow storage.
 pub struct Baser<'db> {
@@ -442,15 +464,77 @@ pub struct Baser<'db> {
     /// Value is serialized parameter dict of public key situation
     pub states: Komer<'db, KeyStateRecord>,
 
+    /// .locs is named sub DB of endpoint provider locations
+    ///     Key is (eid, role, scheme)
+    ///     Value is LocationRecord with the url for the scheme
     pub locs: Komer<'db, LocationRecord>,
 
+    /// .ends is named sub DB of endpoint provider authorizations
+    ///     Key is (cid, role, eid)
+    ///     Value is EndpointRecord with the allowed and enabled flags
     pub ends: Komer<'db, EndpointRecord>,
 
     pub eans: CesrSuber<'db, Saider>,
 
+    /// .lans is named sub DB of accepted `/loc/scheme` reply SAIDs
+    ///     Key is (eid, role, scheme)
     pub lans: CesrSuber<'db, Saider>,
 
     pub pses: IoDupSuber<'db>,
+
+    /// .kchs is named sub DB of KEL chain hashes for tamper evidence
+    ///     Key is identifier prefix (fully qualified qb64)
+    ///     Value is Diger of the running accumulator where each first seen
+    ///     event folds the digest of its serialization into the prior value
+    pub kchs: CesrSuber<'db, Diger>,
+
+    /// .ooes is named sub DB of out of order escrowed event digests
+    ///     Uses sequence number or sn.
+    ///     snKey
+    ///     DB is keyed by identifier prefix plus sequence number of key event
+    ///     Values are digests used to lookup event in .evts sub DB
+    ///     The datetime each event was escrowed is stored in .dtss
+    ///     More than one value per DB key is allowed
+    pub ooes: OnIoDupSuber<'db, Utf8Codec>,
+
+    /// .ures is named sub DB of unverified witness receipt escrows
+    ///     Holds indexed witness signatures from receipts that arrived before
+    ///     the event they receipt was accepted.
+    ///     dgKey
+    ///     DB is keyed by identifier prefix plus digest of the receipted event
+    ///     Values are qb64 indexed witness signatures
+    ///     More than one value per DB key is allowed
+    pub ures: IoDupSuber<'db>,
+
+    /// .wsns is named sub DB of witnessed sequence number markers
+    ///     Key is identifier prefix (fully qualified qb64)
+    ///     Value is Number of the highest sn whose event is fully witnessed
+    pub wsns: CesrSuber<'db, Number>,
+
+    /// .eats is named sub DB of failed reprocessing attempts of escrowed events
+    ///     dgKey
+    ///     DB is keyed by identifier prefix plus digest of the escrowed event
+    ///     Value is Number of attempts that left the event in escrow
+    pub eats: CesrSuber<'db, Number>,
+
+    /// .dlts is named sub DB of dead letter escrowed events
+    ///     dgKey
+    ///     DB is keyed by identifier prefix plus digest of the dropped event
+    ///     Value is the name of the escrow the event was dropped from
+    pub dlts: Suber<'db>,
+
+    /// .gsigs is named sub DB of member signatures collected for group events
+    ///     dgKey
+    ///     DB is keyed by group identifier prefix plus digest of the group event
+    ///     Values are qb64 indexed member signatures, at most one per index
+    ///     More than one value per DB key is allowed
+    pub gsigs: IoDupSuber<'db>,
+
+    /// .anis is named sub DB index of anchored SAIDs
+    ///     Key is the SAID of a digest or event seal in the `a` field of an event
+    ///     Values are snKeys of the pre and sn of each first seen anchoring event
+    ///     More than one value per DB key is allowed
+    pub anis: IoDupSuber<'db>,
 }
 
 impl<'db> Filer for Baser<'db> {
@@ -563,10 +647,10 @@ impl<'db> Baser<'db> {
             states: Komer::new(lmdber.clone(), "stts.", SerialKind::Json)
                 .map_err(|e| DBError::DatabaseError(format!("SuberError: {}", e)))?,
 
-            ends: Komer::new(lmdber.clone(), "stts.", SerialKind::Json)
+            ends: Komer::new(lmdber.clone(), "ends.", SerialKind::Json)
                 .map_err(|e| DBError::DatabaseError(format!("SuberError: {}", e)))?,
 
-            locs: Komer::new(lmdber.clone(), "stts.", SerialKind::Json)
+            locs: Komer::new(lmdber.clone(), "locs.", SerialKind::Json)
                 .map_err(|e| DBError::DatabaseError(format!("SuberError: {}", e)))?,
 
             habs: Komer::new(lmdber.clone(), "habs.", SerialKind::Json)
@@ -583,6 +667,30 @@ impl<'db> Baser<'db> {
 
             pses: IoDupSuber::new(lmdber.clone(), "pses.", None, false)
                 .map_err(|e| DBError::DatabaseError(format!("SuberError: {}", e)))?,
+
+            kchs: CesrSuber::new(lmdber.clone(), "kchs.", None, false)
+                .map_err(|e| DBError::DatabaseError(format!("SuberError: {}", e)))?,
+
+            ooes: OnIoDupSuber::new(lmdber.clone(), "ooes.", None, false)
+                .map_err(|e| DBError::DatabaseError(format!("SuberError: {}", e)))?,
+
+            ures: IoDupSuber::new(lmdber.clone(), "ures.", None, false)
+                .map_err(|e| DBError::DatabaseError(format!("SuberError: {}", e)))?,
+
+            wsns: CesrSuber::new(lmdber.clone(), "wsns.", None, false)
+                .map_err(|e| DBError::DatabaseError(format!("SuberError: {}", e)))?,
+
+            eats: CesrSuber::new(lmdber.clone(), "eats.", None, false)
+                .map_err(|e| DBError::DatabaseError(format!("SuberError: {}", e)))?,
+
+            dlts: Suber::new(lmdber.clone(), "dlts.", None, false)
+                .map_err(|e| DBError::DatabaseError(format!("SuberError: {}", e)))?,
+
+            gsigs: IoDupSuber::new(lmdber.clone(), "gsigs.", None, false)
+                .map_err(|e| DBError::DatabaseError(format!("SuberError: {}", e)))?,
+
+            anis: IoDupSuber::new(lmdber.clone(), "anis.", None, false)
+                .map_err(|e| DBError::DatabaseError(format!("SuberError: {}", e)))?,
         };
 
         Ok(baser)
@@ -698,7 +806,7 @@ impl<'db> Baser<'db> {
         // Use the low-level interface to get items from the fels database
         let on_items: Vec<(Vec<Vec<u8>>, u64, Vec<u8>)> = self
             .fels
-            .get_on_item_iter(&[&key_prefix], start_fn as u32)
+            .get_on_item_iter(&[&key_prefix], start_fn)
             .map_err(|e| DBError::DatabaseError(format!("Error getting items: {}", e)))?;
 
         for (ckey, cn, cval) in on_items {
@@ -894,7 +1002,9 @@ impl<'db> Baser<'db> {
         // Add datetime
         let dt = DateTime::parse_from_rfc3339(&String::from_utf8_lossy(&dts[0]))
             .map_err(|e| DBError::ValueError(format!("{}", e)))?;
-        let dater = Dater::from_dt(DateTime::from(dt)).qb64b();
+        let dater = Dater::from_dt(DateTime::from(dt))
+            .map_err(|e| DBError::ValueError(format!("{}", e)))?
+            .qb64b();
         atc.extend_from_slice(&dater);
 
         // Check if attachments size is valid (multiple of 4)
@@ -982,6 +1092,1284 @@ impl<'db> Baser<'db> {
 
         Ok(msgs)
     }
+
+    /// Export the accepted event of `pre` at sequence number `sn` as a standalone
+    /// CESR message framed with its stored indexed controller and witness signatures
+    ///
+    /// # Parameters
+    /// * `pre` - Identifier prefix as string
+    /// * `sn` - Sequence number of event in the KEL
+    ///
+    /// # Returns
+    /// * `Result<Vec<u8>, KERIError>` - Event body followed by its signature attachments
+    pub fn export_event(&self, pre: &str, sn: u64) -> Result<Vec<u8>, KERIError> {
+        let dig: Vec<u8> = self.kels.get_last(&[&sn_key(pre, sn)])?.ok_or_else(|| {
+            KERIError::MissingEntryError(format!("Missing event for pre={} sn={}.", pre, sn))
+        })?;
+
+        let key = dg_key(pre, &dig);
+        let raw = self.evts.get::<_, Vec<u8>>(&[&key])?.ok_or_else(|| {
+            KERIError::MissingEntryError(format!(
+                "Missing event for dig={}.",
+                String::from_utf8_lossy(&dig)
+            ))
+        })?;
+        let serder = SerderKERI::from_raw(&raw, None)?;
+
+        let sigers = self
+            .sigs
+            .get::<_, Vec<u8>>(&[&key])?
+            .iter()
+            .map(|sig| Siger::from_qb64(&String::from_utf8_lossy(sig), None))
+            .collect::<Result<Vec<Siger>, _>>()?;
+        if sigers.is_empty() {
+            return Err(KERIError::MissingSignatureError(format!(
+                "Missing sigs for dig={}.",
+                String::from_utf8_lossy(&dig)
+            )));
+        }
+
+        let wigers = self
+            .wigs
+            .get::<_, Vec<u8>>(&[&key])?
+            .iter()
+            .map(|wig| Siger::from_qb64(&String::from_utf8_lossy(wig), None))
+            .collect::<Result<Vec<Siger>, _>>()?;
+        let wigers = if wigers.is_empty() { None } else { Some(wigers) };
+
+        messagize(&serder, Some(&sigers), None, wigers.as_deref(), None, false)
+            .map_err(|e| KERIError::SerializationError(e.to_string()))
+    }
+
+    /// Export a compact proof of the current key state of `pre`
+    ///
+    /// The proof is every establishment event in first seen order, each framed with
+    /// its attached signatures as by `export_event`, followed by the `/ksn` reply
+    /// signed by `signer` as by `key_state_reply`. Interaction events are omitted so
+    /// a verifier replays only the establishment chain and checks it against the reply.
+    ///
+    /// # Parameters
+    /// * `pre` - Identifier prefix as string
+    /// * `signer` - Signer endorsing the ksn reply
+    ///
+    /// # Returns
+    /// * `Result<Vec<u8>, KERIError>` - Framed establishment events then the signed ksn reply
+    pub fn key_state_proof(&self, pre: &str, signer: &Signer) -> Result<Vec<u8>, KERIError> {
+        let mut proof = Vec::new();
+        for (_, dig) in self.fel_digs(pre)? {
+            let raw = self
+                .evts
+                .get::<_, Vec<u8>>(&[&dg_key(pre, &dig)])?
+                .ok_or_else(|| {
+                    KERIError::MissingEntryError(format!(
+                        "Missing event for dig={}.",
+                        String::from_utf8_lossy(&dig)
+                    ))
+                })?;
+            let serder = SerderKERI::from_raw(&raw, None)?;
+            if !serder.estive() {
+                continue;
+            }
+
+            let sn = serder.sn().ok_or_else(|| {
+                KERIError::ValueError(format!(
+                    "Missing sn in event dig={}.",
+                    serder.said().unwrap_or_default()
+                ))
+            })?;
+            proof.extend_from_slice(&self.export_event(pre, sn)?);
+        }
+
+        proof.extend_from_slice(&self.key_state_reply(pre, signer)?);
+
+        Ok(proof)
+    }
+
+    /// Build a signed `/ksn` reply carrying the current key state of `pre`
+    ///
+    /// A transferable signer must hold one of the current signing keys of `pre` and
+    /// its indexed signature is attached in a trans indexed sig group anchored to the
+    /// latest establishment event. A nontransferable signer such as a witness or
+    /// watcher attaches a receipt couple instead.
+    ///
+    /// # Parameters
+    /// * `pre` - Identifier prefix as string
+    /// * `signer` - Signer endorsing the reply
+    ///
+    /// # Returns
+    /// * `Result<Vec<u8>, KERIError>` - Reply body followed by its signature attachments
+    pub fn key_state_reply(&self, pre: &str, signer: &Signer) -> Result<Vec<u8>, KERIError> {
+        let state = self.states.get(&[pre])?.ok_or_else(|| {
+            KERIError::MissingEntryError(format!("Missing key state for pre={}.", pre))
+        })?;
+
+        let rserder = ReplyEventBuilder::new()
+            .with_route(format!("/ksn/{}", pre))
+            .with_data(state.to_map())
+            .build()?;
+
+        let verfer = signer.verfer();
+        let msg = if verfer.is_transferable() {
+            let index = state
+                .k
+                .iter()
+                .position(|key| *key == verfer.qb64())
+                .ok_or_else(|| {
+                    KERIError::ValueError(format!(
+                        "Signer key={} is not a current key of pre={}.",
+                        verfer.qb64(),
+                        pre
+                    ))
+                })?;
+            let siger = match signer.sign(rserder.raw(), Some(index as u32), None, None)? {
+                Sigmat::Indexed(siger) => siger,
+                _ => {
+                    return Err(KERIError::ValueError(
+                        "Expected indexed signature".to_string(),
+                    ))
+                }
+            };
+            let seal = Seal::SealEvent(SealEvent {
+                i: pre.to_string(),
+                s: state.ee.s.clone(),
+                d: state.ee.d.clone(),
+            });
+            messagize(&rserder, Some(&[siger]), Some(seal), None, None, false)
+        } else {
+            let cigar = match signer.sign(rserder.raw(), None, None, None)? {
+                Sigmat::NonIndexed(cigar) => cigar,
+                _ => {
+                    return Err(KERIError::ValueError(
+                        "Expected non-indexed signature".to_string(),
+                    ))
+                }
+            };
+            messagize(&rserder, None, None, None, Some(&[cigar]), false)
+        };
+
+        msg.map_err(|e| KERIError::SerializationError(e.to_string()))
+    }
+
+    /// Recompute the key state of `pre` by replaying its first seen event log
+    ///
+    /// Every event in the FEL is loaded with its attached controller and witness
+    /// signatures and run through a fresh Kever in check mode so no new first seen
+    /// entries are created. The cached key state in `.states` is ignored.
+    ///
+    /// # Parameters
+    /// * `pre` - Identifier prefix as string
+    /// * `persist` - When true, overwrite the cached key state with the result
+    ///
+    /// # Returns
+    /// * `Result<KeyStateRecord, KERIError>` - Key state after the last event in the KEL
+    pub fn rebuild_state(&self, pre: &str, persist: bool) -> Result<KeyStateRecord, KERIError> {
+        let mut kever: Option<Kever> = None;
+        let mut last_key = None;
+        for (_, dig) in self.fel_digs(pre)? {
+            let key = dg_key(pre, &dig);
+            let raw = self.evts.get::<_, Vec<u8>>(&[&key])?.ok_or_else(|| {
+                KERIError::MissingEntryError(format!(
+                    "Missing event for dig={}.",
+                    String::from_utf8_lossy(&dig)
+                ))
+            })?;
+            let serder = SerderKERI::from_raw(&raw, None)?;
+
+            let sigers = self
+                .sigs
+                .get::<_, Vec<u8>>(&[&key])?
+                .iter()
+                .map(|sig| Siger::from_qb64(&String::from_utf8_lossy(sig), None))
+                .collect::<Result<Vec<Siger>, _>>()?;
+            let wigers = self
+                .wigs
+                .get::<_, Vec<u8>>(&[&key])?
+                .iter()
+                .map(|wig| Siger::from_qb64(&String::from_utf8_lossy(wig), None))
+                .collect::<Result<Vec<Siger>, _>>()?;
+            let wigers = if wigers.is_empty() { None } else { Some(wigers) };
+
+            match kever.as_mut() {
+                Some(kever) => {
+                    kever.update(serder, sigers, wigers, None, None, None, None, false, true, true)?
+                }
+                None => {
+                    kever = Some(Kever::new(
+                        Arc::new(self),
+                        None,
+                        Some(serder),
+                        Some(sigers),
+                        wigers,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        Some(true),
+                        Some(true),
+                    )?);
+                }
+            }
+            last_key = Some(key);
+        }
+
+        let (mut kever, key) = match (kever, last_key) {
+            (Some(kever), Some(key)) => (kever, key),
+            _ => {
+                return Err(KERIError::MissingEntryError(format!(
+                    "No events in KEL for pre={}.",
+                    pre
+                )))
+            }
+        };
+
+        // Check mode does not assign first seen data so restore it from the FEL
+        kever.fner = self.fons.get(&[&key])?;
+        let dts = self.dtss.get::<_, Vec<u8>>(&[&key])?;
+        if let Some(dts) = dts.first() {
+            kever.dater = Some(Dater::from_dts(&String::from_utf8_lossy(dts))?);
+        }
+
+        let state = kever.state()?;
+        if persist {
+            self.states.pin(&[pre], &state)?;
+        }
+
+        Ok(state)
+    }
+
+    /// Audits that first seen datetimes never decrease along the first seen log of `pre`
+    ///
+    /// # Parameters
+    /// * `pre` - Identifier prefix as string
+    ///
+    /// # Returns
+    /// * `Result<Vec<(u64, bool)>, KERIError>` - (fn, ordered) for each FEL entry where
+    ///   ordered is false when the event's dts is earlier than the prior event's dts
+    pub fn audit_dts(&self, pre: &str) -> Result<Vec<(u64, bool)>, KERIError> {
+        let mut audit = Vec::new();
+        let mut prior: Option<DateTime<chrono::FixedOffset>> = None;
+        for (fn_num, dig) in self.fel_digs(pre)? {
+            let dtss = self.dtss.get::<_, Vec<u8>>(&[&dg_key(pre, &dig)])?;
+            let dts = dtss.first().ok_or_else(|| {
+                KERIError::MissingEntryError(format!(
+                    "Missing dts for fn={} dig={}.",
+                    fn_num,
+                    String::from_utf8_lossy(&dig)
+                ))
+            })?;
+            let dts = String::from_utf8_lossy(dts).to_string();
+            let dt = DateTime::parse_from_rfc3339(&dts).map_err(|e| {
+                KERIError::DeserializationError(format!("Invalid dts={}: {}", dts, e))
+            })?;
+
+            audit.push((fn_num, prior.map_or(true, |p| dt >= p)));
+            prior = Some(dt);
+        }
+
+        Ok(audit)
+    }
+
+    /// Fetch the first seen replay couple (fn, dts) of the event accepted at sn
+    ///
+    /// # Parameters
+    /// * `pre` - Identifier prefix as string
+    /// * `sn` - Sequence number of event in the KEL
+    ///
+    /// # Returns
+    /// * `Result<Option<(u64, String)>, KERIError>` - First seen ordinal and datetime
+    ///   stamp, None if no event has been accepted at sn
+    pub fn first_seen_couple(
+        &self,
+        pre: &str,
+        sn: u64,
+    ) -> Result<Option<(u64, String)>, KERIError> {
+        let dig: Vec<u8> = match self.kels.get_last(&[&sn_key(pre, sn)])? {
+            Some(dig) => dig,
+            None => return Ok(None),
+        };
+
+        let key = dg_key(pre, &dig);
+        let fner = self.fons.get(&[&key])?.ok_or_else(|| {
+            KERIError::MissingEntryError(format!(
+                "Missing fn for dig={}.",
+                String::from_utf8_lossy(&dig)
+            ))
+        })?;
+        let dtss = self.dtss.get::<_, Vec<u8>>(&[&key])?;
+        let dts = dtss.first().ok_or_else(|| {
+            KERIError::MissingEntryError(format!(
+                "Missing dts for dig={}.",
+                String::from_utf8_lossy(&dig)
+            ))
+        })?;
+
+        let fn_num = u64::try_from(fner.num()).map_err(|_| {
+            KERIError::ValidationError(format!(
+                "First seen ordinal fn={} out of range for dig={}",
+                fner.num(),
+                String::from_utf8_lossy(&dig)
+            ))
+        })?;
+
+        Ok(Some((fn_num, String::from_utf8_lossy(dts).to_string())))
+    }
+
+    /// Serialize a first seen replay couple as a `-E` counted CESR attachment
+    ///
+    /// # Parameters
+    /// * `fn_num` - First seen ordinal
+    /// * `dts` - RFC-3339 first seen datetime stamp
+    ///
+    /// # Returns
+    /// * `Result<Vec<u8>, KERIError>` - Counter followed by the fn Number and Dater
+    pub fn first_seen_couple_qb64b(fn_num: u64, dts: &str) -> Result<Vec<u8>, KERIError> {
+        let dt = DateTime::parse_from_rfc3339(dts).map_err(|e| {
+            KERIError::DeserializationError(format!("Invalid dts={}: {}", dts, e))
+        })?;
+
+        let mut atc = BaseCounter::from_code_and_count(
+            Some(ctr_dex_1_0::FIRST_SEEN_REPLAY_COUPLES),
+            Some(1),
+            Some("1.0"),
+        )?
+        .qb64b();
+        atc.extend_from_slice(
+            &Number::from_num_and_code(&BigUint::from(fn_num), num_dex::HUGE)?.qb64b(),
+        );
+        atc.extend_from_slice(&Dater::from_dt(DateTime::from(dt))?.qb64b());
+
+        Ok(atc)
+    }
+
+    /// Scans the store for inconsistencies left behind by a crash
+    ///
+    /// Checks that every sn index entry in .kels names a stored, parseable event with
+    /// the same pre, sn and SAID, that the ordinal proems of the .ooes, .pses and .ures escrows
+    /// are strictly increasing, and that the cached key state of each prefix matches
+    /// the state replayed from its first seen event log. Nothing is repaired.
+    ///
+    /// # Returns
+    /// * `Result<SelfCheckReport, KERIError>` - Anomalies found, empty on a clean store
+    pub fn self_check(&self) -> Result<SelfCheckReport, KERIError> {
+        let mut report = SelfCheckReport::default();
+
+        for item in self.kels.get_on_item_iter::<_, Vec<u8>>(&[b""], 0)? {
+            let (ckey, sn, dig) = item?;
+            let pre = match ckey.first() {
+                Some(pre) => String::from_utf8_lossy(pre).to_string(),
+                None => continue,
+            };
+            let said = String::from_utf8_lossy(&dig).to_string();
+
+            let raw = match self.evts.get::<_, Vec<u8>>(&[&dg_key(&pre, &dig)])? {
+                Some(raw) => raw,
+                None => {
+                    report.index.push(format!(
+                        "Missing event for pre={} sn={} dig={}.",
+                        pre, sn, said
+                    ));
+                    continue;
+                }
+            };
+            let serder = match SerderKERI::from_raw(&raw, None) {
+                Ok(serder) => serder,
+                Err(e) => {
+                    report.index.push(format!(
+                        "Corrupt event for pre={} sn={} dig={}: {}",
+                        pre, sn, said, e
+                    ));
+                    continue;
+                }
+            };
+            if serder.pre().as_deref() != Some(pre.as_str())
+                || serder.sn() != Some(sn)
+                || serder.said() != Some(said.as_str())
+            {
+                report.index.push(format!(
+                    "Mismatched event for pre={} sn={} dig={}.",
+                    pre, sn, said
+                ));
+            }
+        }
+
+        let escrows = [
+            ("ooes", &self.ooes.io_dup_suber),
+            ("pses", &self.pses),
+            ("ures", &self.ures),
+        ];
+        for (name, escrow) in escrows {
+            for key in escrow.disordered_keys()? {
+                report.escrows.push(format!(
+                    "Disordered proems in {} at key={}.",
+                    name,
+                    String::from_utf8_lossy(&key)
+                ));
+            }
+        }
+
+        for pre in self.list_prefixes()? {
+            let cached = self.states.get(&[pre.as_str()])?;
+            let replayed = match self.rebuild_state(&pre, false) {
+                Ok(state) => state,
+                Err(e) => {
+                    report
+                        .states
+                        .push(format!("Unreplayable KEL for pre={}: {}", pre, e));
+                    continue;
+                }
+            };
+            match cached {
+                Some(state) if state.s == replayed.s && state.d == replayed.d => {}
+                Some(state) => report.states.push(format!(
+                    "Cached state for pre={} at sn={} dig={} differs from replayed sn={} dig={}.",
+                    pre, state.s, state.d, replayed.s, replayed.d
+                )),
+                None => report
+                    .states
+                    .push(format!("Missing cached state for pre={}.", pre)),
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Tallies the events in the KEL of an identifier by message type
+    ///
+    /// Replays the first seen event log for `pre` and counts each event by its
+    /// ilk `t` field.
+    ///
+    /// # Parameters
+    /// * `pre` - qb64 identifier prefix
+    ///
+    /// # Returns
+    /// * `Result<HashMap<String, usize>, KERIError>` - Count of events per ilk,
+    ///   empty for an unknown prefix
+    pub fn event_ilk_counts(&self, pre: &str) -> Result<HashMap<String, usize>, KERIError> {
+        let mut counts = HashMap::new();
+        for (fn_num, dig) in self.fel_digs(pre)? {
+            let raw = self
+                .evts
+                .get::<_, Vec<u8>>(&[&dg_key(pre, &dig)])?
+                .ok_or_else(|| {
+                    KERIError::MissingEntryError(format!(
+                        "Missing event for fn={} dig={}.",
+                        fn_num,
+                        String::from_utf8_lossy(&dig)
+                    ))
+                })?;
+            let serder = SerderKERI::from_raw(&raw, None)?;
+            if let Some(ilk) = serder.ilk() {
+                *counts.entry(ilk.to_string()).or_insert(0) += 1;
+            }
+        }
+
+        Ok(counts)
+    }
+
+    /// Returns the highest sn of an identifier whose event is fully witnessed
+    ///
+    /// # Parameters
+    /// * `pre` - qb64 identifier prefix
+    ///
+    /// # Returns
+    /// * `Result<Option<u64>, KERIError>` - Witnessed sn or None if no event of
+    ///   `pre` has been fully witnessed yet
+    pub fn get_witnessed_sn(&self, pre: &str) -> Result<Option<u64>, KERIError> {
+        match self.wsns.get(&[pre])? {
+            Some(number) => {
+                let sn = u64::try_from(number.num()).map_err(|_| {
+                    KERIError::ValidationError(format!(
+                        "Witnessed sn={} out of range for pre={}",
+                        number.num(),
+                        pre
+                    ))
+                })?;
+                Ok(Some(sn))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Advances the witnessed sn marker of an identifier
+    ///
+    /// The marker only moves forward so an sn at or below the stored one is ignored.
+    ///
+    /// # Parameters
+    /// * `pre` - qb64 identifier prefix
+    /// * `sn` - Sequence number of an event that is now fully witnessed
+    ///
+    /// # Returns
+    /// * `Result<bool, KERIError>` - true if the marker was advanced
+    pub fn set_witnessed_sn(&self, pre: &str, sn: u64) -> Result<bool, KERIError> {
+        if let Some(prior) = self.get_witnessed_sn(pre)? {
+            if sn <= prior {
+                return Ok(false);
+            }
+        }
+
+        self.wsns.pin(&[pre], &Number::from_num(&BigUint::from(sn))?)?;
+        Ok(true)
+    }
+
+    /// Returns the receipter prefixes of an event's stored receipts that a peer lacks
+    ///
+    /// Receipts are keyed by the nontransferable prefix of the receipter. Witness
+    /// receipts in .wigs are resolved to the witness at their index in the witness
+    /// list of the latest establishment event at or before the receipted one and
+    /// nontransferable receipt couples in .rcts by their prefix.
+    ///
+    /// # Parameters
+    /// * `pre` - qb64 identifier prefix of the receipted event
+    /// * `dig` - SAID of the receipted event
+    /// * `peer_has` - Receipter prefixes the peer already holds for the event
+    ///
+    /// # Returns
+    /// * `Result<Vec<String>, KERIError>` - Receipter prefixes not in `peer_has`
+    pub fn receipt_diff(
+        &self,
+        pre: &str,
+        dig: &str,
+        peer_has: &[String],
+    ) -> Result<Vec<String>, KERIError> {
+        let key = dg_key(pre, dig);
+        let mut receipters = IndexSet::new();
+
+        let wigs = self.wigs.get::<_, Vec<u8>>(&[&key])?;
+        if !wigs.is_empty() {
+            let wits = self.est_wits(pre, dig)?;
+            for wig in wigs {
+                let wiger = Siger::from_qb64(&String::from_utf8_lossy(&wig), None)?;
+                if let Some(wit) = wits.get(wiger.index() as usize) {
+                    receipters.insert(String::from_utf8_lossy(wit).to_string());
+                }
+            }
+        }
+
+        for mut couple in self.rcts.get::<_, Vec<u8>>(&[&key])? {
+            let prefixer = Prefixer::from_qb64b(&mut couple, Some(true))?;
+            receipters.insert(prefixer.qb64());
+        }
+
+        Ok(receipters
+            .into_iter()
+            .filter(|pre| !peer_has.contains(pre))
+            .collect())
+    }
+
+    /// Returns a Blake3 digest committing to every stored receipt of an event
+    ///
+    /// Witness receipts in .wigs become the couple of the witness prefix at the
+    /// signature's index and the indexed signature. Witnesses are only stored with
+    /// establishment events so the list is resolved from the latest establishment
+    /// event at or before the receipted one. Nontransferable receipt couples in
+    /// .rcts are used as stored. The qb64 couples are sorted before hashing so the
+    /// digest does not depend on the order receipts arrived in.
+    ///
+    /// # Parameters
+    /// * `pre` - qb64 identifier prefix of the receipted event
+    /// * `dig` - SAID of the receipted event
+    ///
+    /// # Returns
+    /// * `Result<Option<String>, KERIError>` - qb64 Blake3_256 digest or None if the
+    ///   event has no receipts
+    pub fn receipts_digest(&self, pre: &str, dig: &str) -> Result<Option<String>, KERIError> {
+        let key = dg_key(pre, dig);
+        let mut couples = Vec::new();
+
+        let wigs = self.wigs.get::<_, Vec<u8>>(&[&key])?;
+        if !wigs.is_empty() {
+            let wits = self.est_wits(pre, dig)?;
+            for wig in wigs {
+                let wiger = Siger::from_qb64(&String::from_utf8_lossy(&wig), None)?;
+                if let Some(wit) = wits.get(wiger.index() as usize) {
+                    let mut couple = wit.clone();
+                    couple.extend_from_slice(&wig);
+                    couples.push(couple);
+                }
+            }
+        }
+
+        couples.extend(self.rcts.get::<_, Vec<u8>>(&[&key])?);
+
+        if couples.is_empty() {
+            return Ok(None);
+        }
+        couples.sort();
+
+        let diger = Diger::from_ser(&couples.concat(), Some(mtr_dex::BLAKE3_256))?;
+        Ok(Some(diger.qb64()))
+    }
+
+    /// Witness list in effect for an event of `pre`, read from the latest
+    /// establishment event at or before it, empty if the event is not stored
+    fn est_wits(&self, pre: &str, dig: &str) -> Result<Vec<Vec<u8>>, KERIError> {
+        let raw = match self.evts.get::<_, Vec<u8>>(&[&dg_key(pre, dig)])? {
+            Some(raw) => raw,
+            None => return Ok(Vec::new()),
+        };
+        let mut serder = SerderKERI::from_raw(&raw, None)?;
+        let mut est = dig.as_bytes().to_vec();
+        while !serder.estive() {
+            let sn = match serder.sn().and_then(|sn| sn.checked_sub(1)) {
+                Some(sn) => sn,
+                None => return Ok(Vec::new()),
+            };
+            est = match self.kels.get_last::<_, Vec<u8>>(&[&sn_key(pre, sn)])? {
+                Some(dig) => dig,
+                None => return Ok(Vec::new()),
+            };
+            let raw = match self.evts.get::<_, Vec<u8>>(&[&dg_key(pre, &est)])? {
+                Some(raw) => raw,
+                None => return Ok(Vec::new()),
+            };
+            serder = SerderKERI::from_raw(&raw, None)?;
+        }
+
+        Ok(self.wits.get::<_, Vec<u8>>(&[&dg_key(pre, &est)])?)
+    }
+
+    /// Stores member signatures on a group event and reports progress toward its threshold
+    ///
+    /// Each incoming signature is verified against the group event's signing key
+    /// at its index and dropped when the index is out of range or the signature
+    /// does not verify. Verified signatures are kept in insertion order in .gsigs
+    /// with at most one per signing index, so a repeated or conflicting signature
+    /// at an index already held is ignored. The threshold is the current signing
+    /// threshold `kt` of the group event, which must already be stored in .evts.
+    ///
+    /// # Parameters
+    /// * `group_pre` - Group identifier prefix
+    /// * `dig` - SAID of the group event
+    /// * `new_sigers` - Incoming member indexed signatures
+    ///
+    /// # Returns
+    /// * `Result<(usize, usize), KERIError>` - (collected, required) where collected is
+    ///   the number of distinct verified indices held and required the fewest
+    ///   signatures, counting those held, that satisfy the threshold. The threshold
+    ///   is met once collected reaches required
+    pub fn collect_group_sigs(
+        &self,
+        group_pre: &str,
+        dig: &str,
+        new_sigers: &[Siger],
+    ) -> Result<(usize, usize), KERIError> {
+        let key = dg_key(group_pre, dig);
+        let raw = self.evts.get::<_, Vec<u8>>(&[&key])?.ok_or_else(|| {
+            KERIError::MissingEntryError(format!("Missing group event for dig={}.", dig))
+        })?;
+        let serder = SerderKERI::from_raw(&raw, None)?;
+        let tholder = serder.tholder().ok_or_else(|| {
+            KERIError::FieldError(format!("Missing signing threshold for dig={}.", dig))
+        })?;
+        let verfers = serder.verfers().ok_or_else(|| {
+            KERIError::FieldError(format!("Missing signing keys for dig={}.", dig))
+        })?;
+
+        let mut indices = HashSet::new();
+        for sig in self.gsigs.get::<_, Vec<u8>>(&[&key])? {
+            indices.insert(Siger::from_qb64(&String::from_utf8_lossy(&sig), None)?.index());
+        }
+
+        for siger in new_sigers {
+            let verified = match verfers.get(siger.index() as usize) {
+                Some(verfer) => verfer.verify(siger.raw(), serder.raw())?,
+                None => false,
+            };
+            if verified && indices.insert(siger.index()) {
+                self.gsigs.add(&[&key], &siger.qb64b())?;
+            }
+        }
+
+        let have: Vec<usize> = indices.iter().map(|index| *index as usize).collect();
+        let required = if tholder.satisfy(&have) {
+            have.len()
+        } else {
+            have.len() + tholder.remaining(&have)
+        };
+
+        Ok((have.len(), required))
+    }
+
+    /// Returns the stored endpoint urls of the providers of an identifier in a role
+    ///
+    /// Endpoint providers are the eids in .ends under (pre, role) that are either
+    /// allowed or enabled. Their urls are looked up in .locs by (eid, role, scheme)
+    /// and nullified locations are skipped.
+    ///
+    /// # Parameters
+    /// * `pre` - qb64 identifier prefix of the controller
+    /// * `role` - Endpoint role such as `witness` or `watcher`
+    ///
+    /// # Returns
+    /// * `Result<Vec<(String, String)>, KERIError>` - (scheme, url) pairs in key order
+    pub fn get_endpoints(&self, pre: &str, role: &str) -> Result<Vec<(String, String)>, KERIError> {
+        let mut endpoints = Vec::new();
+        for (keys, end) in self.ends.get_item_iter(&[pre, role])? {
+            if keys.len() != 3 || keys[0] != pre || keys[1] != role {
+                continue;
+            }
+            if end.allowed != Some(true) && end.enabled != Some(true) {
+                continue;
+            }
+
+            let eid = &keys[2];
+            for (lkeys, loc) in self.locs.get_item_iter(&[eid.as_str(), role])? {
+                if lkeys.len() != 3 || &lkeys[0] != eid || lkeys[1] != role || loc.is_nullified()
+                {
+                    continue;
+                }
+                endpoints.push((lkeys[2].clone(), loc.url));
+            }
+        }
+
+        Ok(endpoints)
+    }
+
+    /// Returns the first seen log of an identifier
+    ///
+    /// # Parameters
+    /// * `pre` - qb64 identifier prefix
+    ///
+    /// # Returns
+    /// * `Result<impl Iterator<Item = (u64, Vec<u8>)>, KERIError>` - (fn, dig) of each
+    ///   FEL entry of exactly `pre` in fn order
+    pub fn fel_digs<'a>(
+        &self,
+        pre: &'a str,
+    ) -> Result<impl Iterator<Item = (u64, Vec<u8>)> + 'a, KERIError> {
+        let items: Vec<(Vec<Vec<u8>>, u64, Vec<u8>)> =
+            self.fels.get_on_item_iter(&[pre.as_bytes()], 0)?;
+
+        Ok(items.into_iter().filter_map(move |(ckey, fn_num, dig)| {
+            (ckey.first().map(|k| k.as_slice()) == Some(pre.as_bytes())).then_some((fn_num, dig))
+        }))
+    }
+
+    /// Returns every identifier prefix that has at least one event in its KEL
+    ///
+    /// # Returns
+    /// * `Result<Vec<String>, KERIError>` - Distinct qb64 prefixes in key order
+    pub fn list_prefixes(&self) -> Result<Vec<String>, KERIError> {
+        let mut prefixes = IndexSet::new();
+        for (keys, _) in self.kels.get_item_iter(&[b""], false)? {
+            let (pre, _) = split_on_key(keys.join(&b'.'), None)?;
+            prefixes.insert(String::from_utf8_lossy(&pre).to_string());
+        }
+
+        Ok(prefixes.into_iter().collect())
+    }
+
+    /// Returns one page of the identifier prefixes that have at least one event in their KEL
+    ///
+    /// Each prefix costs one seek past all of its .kels entries, so a page reads
+    /// `limit` keys no matter how long the KELs are or how deep the cursor is.
+    ///
+    /// # Parameters
+    /// * `after` - Cursor prefix, only prefixes whose .kels entries sort after it are
+    ///   returned. None starts from the first prefix
+    /// * `limit` - Maximum number of prefixes in the page
+    ///
+    /// # Returns
+    /// * `Result<Vec<String>, KERIError>` - Up to `limit` distinct qb64 prefixes in key
+    ///   order. The last entry is the cursor for the next page
+    pub fn list_prefixes_paged(
+        &self,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<String>, KERIError> {
+        let mut prefixes = Vec::new();
+        let mut cursor = after.map(|pre| sn_key(pre, u64::MAX)).unwrap_or_default();
+        while prefixes.len() < limit {
+            let key = match self
+                .lmdber
+                .get_key_after(&self.kels.on_base.base.sdb, &cursor)?
+            {
+                Some(key) => key,
+                None => break,
+            };
+            let (pre, _) = split_on_key(&key, None)?;
+            cursor = sn_key(&pre, u64::MAX);
+            prefixes.push(String::from_utf8_lossy(&pre).to_string());
+        }
+
+        Ok(prefixes)
+    }
+
+    /// Returns the sns missing from the KEL of an identifier
+    ///
+    /// # Parameters
+    /// * `pre` - qb64 identifier prefix
+    ///
+    /// # Returns
+    /// * `Result<Vec<u64>, KERIError>` - Ascending sns between the lowest and highest
+    ///   stored sn that have no event in kels, empty for an unknown prefix
+    pub fn kel_gaps(&self, pre: &str) -> Result<Vec<u64>, KERIError> {
+        let items = self
+            .kels
+            .get_on_item_iter::<_, Vec<u8>>(&[pre.as_bytes()], 0)?;
+
+        let mut sns = Vec::new();
+        for item in items {
+            let (ckey, sn, _) = item?;
+            if ckey.first().map(|k| k.as_slice()) == Some(pre.as_bytes()) {
+                sns.push(sn);
+            }
+        }
+        sns.sort_unstable();
+        sns.dedup();
+
+        let mut gaps = Vec::new();
+        for pair in sns.windows(2) {
+            gaps.extend(pair[0] + 1..pair[1]);
+        }
+
+        Ok(gaps)
+    }
+
+    /// Returns the current signing keys of an identifier from its cached key state
+    ///
+    /// # Parameters
+    /// * `pre` - Identifier prefix as string
+    ///
+    /// # Returns
+    /// * `Result<Vec<Verfer>, KERIError>` - Verfers of the `k` keys in key state order,
+    ///   MissingEntryError if there is no key state for `pre`
+    pub fn current_verfers(&self, pre: &str) -> Result<Vec<Verfer>, KERIError> {
+        let state = self.states.get(&[pre])?.ok_or_else(|| {
+            KERIError::MissingEntryError(format!("Missing key state for pre={}.", pre))
+        })?;
+
+        let verfers = state
+            .k
+            .iter()
+            .map(|key| Verfer::from_qb64(key))
+            .collect::<Result<Vec<Verfer>, _>>()?;
+
+        Ok(verfers)
+    }
+
+    /// Returns the event of an identifier at a first seen ordinal
+    ///
+    /// First seen order follows acceptance so after a superseding recovery it
+    /// differs from sequence number order.
+    ///
+    /// # Parameters
+    /// * `pre` - Identifier prefix as string
+    /// * `fn_num` - First seen ordinal number of the event in the FEL
+    ///
+    /// # Returns
+    /// * `Result<Option<SerderKERI>, KERIError>` - Event first seen at `fn_num`, None if
+    ///   the FEL has no entry there, MissingEntryError if the event body is missing
+    pub fn event_at_fn(&self, pre: &str, fn_num: u64) -> Result<Option<SerderKERI>, KERIError> {
+        let dig: Vec<u8> = match self.fels.get_on(&[pre], fn_num)? {
+            Some(dig) => dig,
+            None => return Ok(None),
+        };
+
+        let raw = self
+            .evts
+            .get::<_, Vec<u8>>(&[&dg_key(pre, &dig)])?
+            .ok_or_else(|| {
+                KERIError::MissingEntryError(format!(
+                    "Missing event for dig={}.",
+                    String::from_utf8_lossy(&dig)
+                ))
+            })?;
+
+        Ok(Some(SerderKERI::from_raw(&raw, None)?))
+    }
+
+    /// Returns the digests of event bodies in evts that neither a kels entry nor
+    /// an escrow references
+    ///
+    /// # Returns
+    /// * `Result<Vec<String>, KERIError>` - Orphaned event digests in evts key order
+    pub fn find_orphaned_events(&self) -> Result<Vec<String>, KERIError> {
+        self.gc_orphaned_events(true)
+    }
+
+    /// Removes event bodies in evts that neither a kels entry nor an escrow
+    /// references, along with their sigs, dtss, wigs, wits, esrs, rcts and vrcs
+    /// entries
+    ///
+    /// Events still held in the out of order, partially signed or group
+    /// signature escrows are kept so they can be reprocessed later.
+    ///
+    /// # Parameters
+    /// * `dry_run` - When true only reports the orphans without deleting them
+    ///
+    /// # Returns
+    /// * `Result<Vec<String>, KERIError>` - Digests of the orphaned events removed,
+    ///   or that would be removed on a dry run
+    pub fn gc_orphaned_events(&self, dry_run: bool) -> Result<Vec<String>, KERIError> {
+        let mut indexed = HashSet::new();
+        for (keys, dig) in self.kels.get_item_iter(&[b""], false)? {
+            let (pre, _) = split_on_key(keys.join(&b'.'), None)?;
+            indexed.insert((pre, dig));
+        }
+        for item in self.ooes.get_on_item_iter::<_, Vec<u8>>(&[b""], 0)? {
+            let (keys, _, dig) = item?;
+            if let Some(pre) = keys.first() {
+                indexed.insert((pre.clone(), dig));
+            }
+        }
+        for (keys, dig) in self.pses.get_item_iter(&[b""], false)? {
+            if let Some(pre) = keys.first() {
+                indexed.insert((pre.clone(), dig));
+            }
+        }
+        for (keys, _) in self.gsigs.get_item_iter(&[b""], false)? {
+            if let [pre, dig] = keys.as_slice() {
+                indexed.insert((pre.clone(), dig.clone()));
+            }
+        }
+
+        let mut removed = Vec::new();
+        for (keys, _) in self.evts.get_item_iter(&[b""], false)? {
+            if let [pre, dig] = keys.as_slice() {
+                if indexed.contains(&(pre.clone(), dig.clone())) {
+                    continue;
+                }
+                if !dry_run {
+                    self.evts.rem(&[pre, dig])?;
+                    self.sigs.rem::<_, Vec<u8>>(&[pre, dig], None)?;
+                    self.dtss.rem::<_, Vec<u8>>(&[pre, dig], None)?;
+                    self.wigs.rem::<_, Vec<u8>>(&[pre, dig], None)?;
+                    self.wits.rem::<_, Vec<u8>>(&[pre, dig], None)?;
+                    self.esrs.rem(&[pre, dig])?;
+                    self.rcts.rem::<_, Vec<u8>>(&[pre, dig], None)?;
+                    self.vrcs.rem::<_, Vec<u8>>(&[pre, dig], None)?;
+                }
+                removed.push(String::from_utf8_lossy(dig).to_string());
+            }
+        }
+
+        Ok(removed)
+    }
+
+    /// Returns every first seen key event whose seals anchor a SAID
+    ///
+    /// Scans the `a` field of each event in every first seen event log for a
+    /// digest seal `(d)` or event seal `(i, s, d)` whose `d` is the SAID.
+    ///
+    /// # Parameters
+    /// * `said` - SAID to look for such as that of an ACDC
+    ///
+    /// # Returns
+    /// * `Result<Vec<(String, u64)>, KERIError>` - (pre, sn) of each anchoring event
+    ///   ordered by first seen ordinal across all FELs
+    pub fn find_anchors(&self, said: &str) -> Result<Vec<(String, u64)>, KERIError> {
+        let mut anchors = Vec::new();
+        for (pre, _, dig) in self.get_fel_item_all_pre_iter()? {
+            let raw = match self.evts.get::<_, Vec<u8>>(&[&dg_key(&pre, &dig)])? {
+                Some(raw) => raw,
+                None => continue,
+            };
+            let serder = SerderKERI::from_raw(&raw, None)?;
+            if let Some(SadValue::Array(seals)) = serder.ked().get("a") {
+                if seals
+                    .iter()
+                    .any(|seal| seal.get("d").and_then(|d| d.as_str()) == Some(said))
+                {
+                    anchors.push((pre, serder.sn().unwrap_or_default()));
+                }
+            }
+        }
+
+        Ok(anchors)
+    }
+
+    /// Adds the pre and sn of a first seen event to the anchor index under the
+    /// SAID of each seal in its `a` field that has a `d`
+    ///
+    /// # Parameters
+    /// * `serder` - Newly first seen key event
+    pub fn index_anchors(&self, serder: &SerderKERI) -> Result<(), KERIError> {
+        let seals = match serder.ked().get("a") {
+            Some(SadValue::Array(seals)) => seals.clone(),
+            _ => return Ok(()),
+        };
+        let pre = serder
+            .pre()
+            .ok_or_else(|| KERIError::FieldError("Missing pre for anchor index.".to_string()))?;
+        let key = sn_key(&pre, serder.sn().unwrap_or_default());
+
+        for seal in seals.iter() {
+            if let Some(said) = seal.get("d").and_then(|d| d.as_str()) {
+                self.anis.add(&[said], &key)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Backfills the anchor index from every first seen event log
+    ///
+    /// Stores written before .anis existed have no index entries for events that
+    /// were already first seen, so this replays each FEL through index_anchors.
+    /// Adding to .anis is idempotent so it is safe to run on an indexed store.
+    ///
+    /// # Returns
+    /// * `Result<usize, KERIError>` - Number of first seen events replayed
+    pub fn reindex_anchors(&self) -> Result<usize, KERIError> {
+        let mut count = 0;
+        for (pre, fn_num, dig) in self.get_fel_item_all_pre_iter()? {
+            let raw = self
+                .evts
+                .get::<_, Vec<u8>>(&[&dg_key(&pre, &dig)])?
+                .ok_or_else(|| {
+                    KERIError::MissingEntryError(format!(
+                        "Missing event for pre={} fn={} dig={}.",
+                        pre, fn_num, dig
+                    ))
+                })?;
+            self.index_anchors(&SerderKERI::from_raw(&raw, None)?)?;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+    /// Returns the (pre, sn) of each first seen event anchoring a SAID read from
+    /// the anchor index rather than by scanning every KEL as find_anchors does
+    ///
+    /// # Parameters
+    /// * `said` - SAID to look up such as that of an ACDC
+    ///
+    /// # Returns
+    /// * `Result<Vec<(String, u64)>, KERIError>` - (pre, sn) of each anchoring event
+    ///   in the order they were first seen
+    pub fn anchor_index_lookup(&self, said: &str) -> Result<Vec<(String, u64)>, KERIError> {
+        let mut anchors = Vec::new();
+        for key in self.anis.get::<_, Vec<u8>>(&[said])? {
+            let (pre, sn) = split_on_key(&key, None)?;
+            let pre = String::from_utf8(pre).map_err(|e| {
+                KERIError::ValueError(format!("Invalid pre in anchor index: {}", e))
+            })?;
+            anchors.push((pre, sn));
+        }
+
+        Ok(anchors)
+    }
+
+    /// Returns the out of order escrowed event that has been waiting longest
+    ///
+    /// # Returns
+    /// * `Result<Option<(String, u64, String)>, KERIError>` - (pre, sn, dts) of the
+    ///   escrowed event with the earliest escrow datetime or None if escrow is empty
+    pub fn oldest_escrow(&self) -> Result<Option<(String, u64, String)>, KERIError> {
+        let mut oldest: Option<(DateTime<chrono::FixedOffset>, (String, u64, String))> = None;
+
+        for item in self.ooes.get_on_item_iter::<_, Vec<u8>>(&[b""], 0)? {
+            let (ckey, sn, dig) = item?;
+            let pre = match ckey.first() {
+                Some(pre) => String::from_utf8_lossy(pre).to_string(),
+                None => continue,
+            };
+
+            let dtss = self.dtss.get::<_, Vec<u8>>(&[&dg_key(&pre, &dig)])?;
+            let dts = match dtss.first() {
+                Some(dts) => String::from_utf8_lossy(dts).to_string(),
+                None => continue,
+            };
+            let dt = DateTime::parse_from_rfc3339(&dts).map_err(|e| {
+                KERIError::DeserializationError(format!("Invalid dts={}: {}", dts, e))
+            })?;
+
+            if oldest.as_ref().map_or(true, |(odt, _)| dt < *odt) {
+                oldest = Some((dt, (pre, sn, dts)));
+            }
+        }
+
+        Ok(oldest.map(|(_, item)| item))
+    }
+
+    /// Counts one more failed reprocessing attempt of an escrowed event
+    ///
+    /// # Parameters
+    /// * `pre` - qb64 identifier prefix
+    /// * `dig` - SAID of the escrowed event
+    ///
+    /// # Returns
+    /// * `Result<u64, KERIError>` - Number of failed attempts including this one
+    pub fn add_escrow_attempt(&self, pre: &str, dig: &str) -> Result<u64, KERIError> {
+        let key = dg_key(pre, dig);
+        let attempts = match self.eats.get(&[&key])? {
+            Some(number) => u64::try_from(number.num())
+                .ok()
+                .and_then(|attempts| attempts.checked_add(1))
+                .ok_or_else(|| {
+                    KERIError::ValidationError(format!(
+                        "Escrow attempts={} out of range for pre={} dig={}",
+                        number.num(),
+                        pre,
+                        dig
+                    ))
+                })?,
+            None => 1,
+        };
+        let number = Number::from_num(&BigUint::from(attempts))?;
+        self.eats.pin(&[&key], &number)?;
+
+        Ok(attempts)
+    }
+
+    /// Records an escrowed event as a dead letter and forgets its attempts
+    ///
+    /// The caller removes the event from its escrow. The event itself stays in
+    /// .evts for inspection.
+    ///
+    /// # Parameters
+    /// * `pre` - qb64 identifier prefix
+    /// * `dig` - SAID of the escrowed event
+    /// * `escrow` - Name of the escrow sub DB the event is dropped from
+    pub fn put_dead_letter(&self, pre: &str, dig: &str, escrow: &str) -> Result<(), KERIError> {
+        let key = dg_key(pre, dig);
+        self.eats.rem(&[&key])?;
+        self.dlts.pin(&[&key], &escrow.to_string())?;
+
+        Ok(())
+    }
+
+    /// Writes every entry of `other` into this database in one write transaction
+    ///
+    /// Each entry of a sub db of `other` is set in the sub db of the same name
+    /// here, so plain sub dbs take the value of `other` and dupsort sub dbs gain
+    /// the dups they lack. Entries only in this database are left alone. Nothing
+    /// is written when any write fails.
+    ///
+    /// # Parameters
+    /// * `other` - Database whose entries are merged, such as a scratch Baser
+    ///
+    /// # Returns
+    /// * `Result<usize, KERIError>` - Number of entries written
+    pub fn merge_from(&self, other: &Baser) -> Result<usize, KERIError> {
+        let mut entries = Vec::new();
+        for name in other.lmdber.sub_db_names()? {
+            let src = other.lmdber.open_database(Some(&name))?;
+            let dst = self.lmdber.open_database(Some(&name))?;
+            let (src, dst) = match (src, dst) {
+                (Some(src), Some(dst)) => (src, dst),
+                _ => {
+                    return Err(KERIError::DatabaseError(format!(
+                        "Missing sub db {} to merge.",
+                        name
+                    )))
+                }
+            };
+            other.lmdber.get_top_items_iter(&src, b"", |key, val| {
+                entries.push((dst, key.to_vec(), val.to_vec()));
+                Ok(true)
+            })?;
+        }
+
+        let count = self.lmdber.cross_db_batch(|writer| {
+            for (db, key, val) in &entries {
+                writer.set_val(db, key, val)?;
+            }
+            Ok(entries.len())
+        })?;
+
+        Ok(count)
+    }
+
+    /// Returns every escrowed event dropped after too many failed attempts
+    ///
+    /// # Returns
+    /// * `Result<Vec<(String, String, String)>, KERIError>` - (pre, dig, escrow) of
+    ///   each dead letter in key order
+    pub fn dead_letters(&self) -> Result<Vec<(String, String, String)>, KERIError> {
+        let mut letters = Vec::new();
+        for (keys, escrow) in self.dlts.get_item_iter(&[b""], false)? {
+            if keys.len() != 2 {
+                continue;
+            }
+            letters.push((
+                String::from_utf8_lossy(&keys[0]).to_string(),
+                String::from_utf8_lossy(&keys[1]).to_string(),
+                String::from_utf8_lossy(&escrow).to_string(),
+            ));
+        }
+
+        Ok(letters)
+    }
+
+    /// Returns the stored KEL chain hash for `pre`
+    ///
+    /// The chain hash is folded incrementally as each event is first seen. Compare
+    /// against `recompute_kel_chain_hash` to detect events altered out of band.
+    ///
+    /// # Parameters
+    /// * `pre` - Identifier prefix as string
+    ///
+    /// # Returns
+    /// * `Result<String, KERIError>` - qb64 of the chain hash accumulator
+    pub fn kel_chain_hash(&self, pre: &str) -> Result<String, KERIError> {
+        match self.kchs.get(&[pre])? {
+            Some(diger) => Ok(diger.qb64()),
+            None => Err(KERIError::MissingEntryError(format!(
+                "No KEL chain hash for pre={}.",
+                pre
+            ))),
+        }
+    }
+
+    /// Folds the serialized event `raw` into the KEL chain hash for `pre` and stores it
+    ///
+    /// # Parameters
+    /// * `pre` - Identifier prefix as string
+    /// * `raw` - Serialization of the newly first seen event
+    ///
+    /// # Returns
+    /// * `Result<String, KERIError>` - qb64 of the updated chain hash
+    pub fn fold_kel_chain_hash(&self, pre: &str, raw: &[u8]) -> Result<String, KERIError> {
+        let prior = self.kchs.get(&[pre])?;
+        let diger = Self::chain_hash(prior.as_ref(), raw)?;
+        self.kchs.pin(&[pre], &diger)?;
+        Ok(diger.qb64())
+    }
+
+    /// Recomputes the KEL chain hash for `pre` from the events currently stored
+    /// in first seen order without updating the stored value
+    ///
+    /// # Parameters
+    /// * `pre` - Identifier prefix as string
+    ///
+    /// # Returns
+    /// * `Result<String, KERIError>` - qb64 of the recomputed chain hash
+    pub fn recompute_kel_chain_hash(&self, pre: &str) -> Result<String, KERIError> {
+        let mut diger: Option<Diger> = None;
+        for (_, dig) in self.fel_digs(pre)? {
+            let raw = self
+                .evts
+                .get::<_, Vec<u8>>(&[&dg_key(pre, &dig)])?
+                .ok_or_else(|| {
+                    KERIError::MissingEntryError(format!(
+                        "Missing event for dig={}.",
+                        String::from_utf8_lossy(&dig)
+                    ))
+                })?;
+            diger = Some(Self::chain_hash(diger.as_ref(), &raw)?);
+        }
+
+        match diger {
+            Some(diger) => Ok(diger.qb64()),
+            None => Err(KERIError::MissingEntryError(format!(
+                "No events in KEL for pre={}.",
+                pre
+            ))),
+        }
+    }
+
+    /// Computes the next chain hash from the prior accumulator and an event serialization
+    fn chain_hash(prior: Option<&Diger>, raw: &[u8]) -> Result<Diger, KERIError> {
+        let mut ser = prior.map(|diger| diger.qb64b()).unwrap_or_default();
+        ser.extend_from_slice(&Diger::from_ser(raw, None)?.qb64b());
+        Ok(Diger::from_ser(&ser, None)?)
+    }
+
     /// Return count of signatures at key.
     /// Uses dgKey format for the key.
     /// Returns zero if no entry at key.
@@ -1126,3 +2514,332 @@ impl<'db> Drop for Baser<'db> {
         // and it has its own Drop implementation
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cesr::tholder::TholderSith;
+    use crate::keri::core::eventing::kevery::tests::{
+        build_kel, ingest, kel_signers, make_creder, open_baser, other_salter, setup_db,
+        setup_kevery, sign_cigar, sign_event, sign_indexed,
+    };
+    use crate::keri::core::eventing::receipt::ReceiptEventBuilder;
+    use crate::keri::core::eventing::{group_incept, InceptionEventBuilder, InteractEventBuilder};
+    use chrono::Utc;
+    use indexmap::IndexMap;
+
+    #[test]
+    fn test_witnessed_sn() -> Result<(), KERIError> {
+        let salter = other_salter()?;
+        let signer = &salter.signers(1, 0, "ctl", None, None, None, false)?[0];
+        let wits = salter.signers(2, 0, "wit", None, Some(false), None, false)?;
+
+        let icp = InceptionEventBuilder::new(vec![signer.verfer().qb64()])
+            .with_ndigs(vec![Diger::from_ser(&signer.verfer().qb64b(), None)?.qb64()])
+            .with_wits(wits.iter().map(|w| w.verfer().qb64()).collect())
+            .with_toad(2)
+            .build()?;
+        let pre = icp.pre().unwrap();
+        let ixn = InteractEventBuilder::new(pre.clone(), icp.said().unwrap().to_string())
+            .with_sn(1)
+            .build()?;
+
+        let witness = |serder: &SerderKERI, i: usize| -> Result<Siger, KERIError> {
+            sign_indexed(&wits[i], serder, i as u32)
+        };
+
+        // The controller's own Kevery accepts its events before they are fully witnessed
+        let lmdber = setup_db("test_witnessed_sn")?;
+        let mut db = open_baser(&lmdber)?;
+        db.prefixes.insert(pre.clone());
+        let mut kevery = setup_kevery(&db)?;
+
+        // One of two required witness sigs
+        kevery.process_event(
+            icp.clone(),
+            vec![sign_event(signer, &icp)?],
+            Some(vec![witness(&icp, 0)?]),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(true),
+        )?;
+        assert_eq!(db.get_witnessed_sn(&pre)?, None);
+
+        let rct = ReceiptEventBuilder::new(pre.clone(), 0, icp.said().unwrap().to_string())
+            .build()
+            .map_err(|e| KERIError::ValueError(e.to_string()))?;
+        kevery.process_receipt_witness(rct, vec![witness(&icp, 1)?], None)?;
+        assert_eq!(db.get_witnessed_sn(&pre)?, Some(0));
+
+        kevery.process_event(
+            ixn.clone(),
+            vec![sign_event(signer, &ixn)?],
+            Some(vec![witness(&ixn, 0)?]),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(true),
+        )?;
+        assert_eq!(db.get_witnessed_sn(&pre)?, Some(0));
+
+        let rct = ReceiptEventBuilder::new(pre.clone(), 1, ixn.said().unwrap().to_string())
+            .build()
+            .map_err(|e| KERIError::ValueError(e.to_string()))?;
+        kevery.process_receipt_witness(rct, vec![witness(&ixn, 1)?], None)?;
+        assert_eq!(db.get_witnessed_sn(&pre)?, Some(1));
+
+        // Marker never moves backwards
+        assert!(!db.set_witnessed_sn(&pre, 0)?);
+        assert_eq!(db.get_witnessed_sn(&pre)?, Some(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_collect_group_sigs() -> Result<(), KERIError> {
+        let signers = kel_signers(4)?;
+
+        let states: Vec<KeyStateRecord> = (0..2)
+            .map(|i| -> Result<KeyStateRecord, KERIError> {
+                Ok(KeyStateRecord {
+                    k: vec![signers[i].verfer().qb64()],
+                    n: vec![Diger::from_ser(&signers[i + 2].verfer().qb64b(), None)?.qb64()],
+                    ..Default::default()
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        let gicp = group_incept(
+            &states,
+            Some(TholderSith::Integer(2)),
+            Some(TholderSith::Integer(2)),
+            vec![],
+            None,
+        )?;
+        let gpre = gicp.pre().unwrap();
+        let gdig = gicp.said().unwrap();
+
+        let lmdber = setup_db("test_collect_group_sigs")?;
+        let db = open_baser(&lmdber)?;
+
+        // Unknown group event
+        let result = db.collect_group_sigs(&gpre, gdig, &[]);
+        assert!(matches!(result, Err(KERIError::MissingEntryError(_))));
+
+        db.evts.pin(&[&dg_key(&gpre, gdig)], &gicp.raw().to_vec())?;
+        let sig0 = sign_indexed(&signers[0], &gicp, 0)?;
+        let sig1 = sign_indexed(&signers[1], &gicp, 1)?;
+
+        // A signature by a key that is not the member's at that index is dropped
+        let forged = sign_indexed(&signers[2], &gicp, 0)?;
+        assert_eq!(db.collect_group_sigs(&gpre, gdig, &[forged])?, (0, 2));
+        let stray = sign_indexed(&signers[1], &gicp, 2)?;
+        assert_eq!(db.collect_group_sigs(&gpre, gdig, &[stray])?, (0, 2));
+        assert_eq!(db.gsigs.cnt(&[&dg_key(&gpre, gdig)])?, 0);
+
+        assert_eq!(db.collect_group_sigs(&gpre, gdig, &[sig0.clone()])?, (1, 2));
+
+        // Repeated index is not counted twice
+        assert_eq!(db.collect_group_sigs(&gpre, gdig, &[sig0.clone()])?, (1, 2));
+
+        assert_eq!(db.collect_group_sigs(&gpre, gdig, &[sig1, sig0])?, (2, 2));
+        assert_eq!(db.gsigs.cnt(&[&dg_key(&gpre, gdig)])?, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_receipt_diff() -> Result<(), KERIError> {
+        let salter = other_salter()?;
+        let signer = &salter.signers(1, 0, "ctl", None, None, None, false)?[0];
+        let wits = salter.signers(3, 0, "wit", None, Some(false), None, false)?;
+        let wpres: Vec<String> = wits.iter().map(|w| w.verfer().qb64()).collect();
+
+        let icp = InceptionEventBuilder::new(vec![signer.verfer().qb64()])
+            .with_ndigs(vec![Diger::from_ser(&signer.verfer().qb64b(), None)?.qb64()])
+            .with_wits(wpres.clone())
+            .with_toad(3)
+            .build()?;
+        let pre = icp.pre().unwrap();
+        let said = icp.said().unwrap().to_string();
+
+        let wigers = wits
+            .iter()
+            .enumerate()
+            .map(|(i, wit)| sign_indexed(wit, &icp, i as u32))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let lmdber = setup_db("test_receipt_diff")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        kevery.process_event(
+            icp.clone(),
+            vec![sign_event(signer, &icp)?],
+            Some(wigers),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+
+        let mut missing = db.receipt_diff(&pre, &said, &[wpres[0].clone()])?;
+        missing.sort();
+        let mut expected = vec![wpres[1].clone(), wpres[2].clone()];
+        expected.sort();
+        assert_eq!(missing, expected);
+
+        assert!(db.receipt_diff(&pre, &said, &wpres)?.is_empty());
+        assert_eq!(db.receipt_diff(&pre, &said, &[])?.len(), 3);
+
+        // Witness receipts on an interaction event resolve against the inception wits
+        let ixn = InteractEventBuilder::new(pre.clone(), said.clone())
+            .with_sn(1)
+            .build()?;
+        let isaid = ixn.said().unwrap().to_string();
+        assert!(db.receipt_diff(&pre, &isaid, &[])?.is_empty());
+        let iwigers = wits
+            .iter()
+            .enumerate()
+            .map(|(i, wit)| sign_indexed(wit, &ixn, i as u32))
+            .collect::<Result<Vec<_>, _>>()?;
+        kevery.process_event(
+            ixn.clone(),
+            vec![sign_event(signer, &ixn)?],
+            Some(iwigers),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )?;
+        let mut missing = db.receipt_diff(&pre, &isaid, &[wpres[2].clone()])?;
+        missing.sort();
+        let mut expected = vec![wpres[0].clone(), wpres[1].clone()];
+        expected.sort();
+        assert_eq!(missing, expected);
+        let bogus = "EBG9LuUbFzV4OV5cGS9IeQWzy9SuyVFyVrpRc4l1xzPA";
+        assert!(db.receipt_diff(&pre, bogus, &[])?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_anchors() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_find_anchors")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+        ingest(&mut kevery, &kel)?;
+
+        let creder = make_creder(&pre, "0AAxyHwW6htOZ_rANOaZb2N2")?;
+        assert!(db.find_anchors(creder.said())?.is_empty());
+
+        // Event seal in an interaction event of the issuer
+        let signers = kel_signers(2)?;
+        kevery.anchor_acdc(&pre, &creder, &signers[1])?;
+
+        // Digest seal in the inception event of another identifier
+        let salter = other_salter()?;
+        let signer = &salter.signers(1, 0, "oth", None, None, None, false)?[0];
+        let mut seal = IndexMap::new();
+        seal.insert("d".to_string(), SadValue::String(creder.said().to_string()));
+        let icp = InceptionEventBuilder::new(vec![signer.verfer().qb64()])
+            .with_data(vec![SadValue::Object(seal)])
+            .build()?;
+        let sigers = vec![sign_event(signer, &icp)?];
+        ingest(&mut kevery, &[(icp.clone(), sigers)])?;
+
+        // Anchors come back ordered by first seen ordinal across all FELs
+        let anchors = db.find_anchors(creder.said())?;
+        assert_eq!(anchors, vec![(icp.pre().unwrap(), 0), (pre.clone(), 3)]);
+        assert!(db.find_anchors(kel[1].0.said().unwrap())?.is_empty());
+
+        // The anchor index agrees with the full scan
+        let mut indexed = db.anchor_index_lookup(creder.said())?;
+        indexed.sort();
+        let mut scanned = anchors.clone();
+        scanned.sort();
+        assert_eq!(indexed, scanned);
+        assert!(db.anchor_index_lookup(kel[1].0.said().unwrap())?.is_empty());
+
+        // A store indexed before .anis existed is backfilled from the FELs
+        db.anis.rem(&[creder.said()], None::<&Vec<u8>>)?;
+        assert!(db.anchor_index_lookup(creder.said())?.is_empty());
+        assert_eq!(db.reindex_anchors()?, 5);
+        let mut indexed = db.anchor_index_lookup(creder.said())?;
+        indexed.sort();
+        assert_eq!(indexed, scanned);
+        assert_eq!(db.reindex_anchors()?, 5);
+        assert_eq!(db.anchor_index_lookup(creder.said())?.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_endpoints() -> Result<(), KERIError> {
+        let kel = build_kel()?;
+        let pre = kel[0].0.pre().unwrap();
+
+        let lmdber = setup_db("test_get_endpoints")?;
+        let db = open_baser(&lmdber)?;
+        let mut kevery = setup_kevery(&db)?;
+
+        let salter = other_salter()?;
+        let witness = &salter.signers(1, 0, "wit", None, Some(false), None, false)?[0];
+        let wit = witness.verfer().qb64();
+
+        for (scheme, url) in [
+            ("http", "http://127.0.0.1:5632/"),
+            ("tcp", "tcp://127.0.0.1:5631/"),
+        ] {
+            let mut data = IndexMap::new();
+            data.insert("eid".to_string(), SadValue::String(wit.clone()));
+            data.insert("role".to_string(), SadValue::String("witness".to_string()));
+            data.insert("scheme".to_string(), SadValue::String(scheme.to_string()));
+            data.insert("url".to_string(), SadValue::String(url.to_string()));
+            let serder = ReplyEventBuilder::new()
+                .with_route("/loc/scheme".to_string())
+                .with_data(data)
+                .with_stamp(Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Micros, false))
+                .build()?;
+            let cigar = sign_cigar(witness, &serder)?;
+            kevery.process_loc_scheme(serder, vec![], vec![cigar])?;
+        }
+
+        // Locations alone do not make the witness an endpoint of pre
+        assert!(db.get_endpoints(&pre, "witness")?.is_empty());
+
+        let keys = [pre.as_str(), "witness", wit.as_str()];
+        db.ends.pin(
+            &keys,
+            &EndpointRecord::with_values(Some(true), None, String::new()),
+        )?;
+        let endpoints = db.get_endpoints(&pre, "witness")?;
+        assert_eq!(
+            endpoints,
+            vec![
+                ("http".to_string(), "http://127.0.0.1:5632/".to_string()),
+                ("tcp".to_string(), "tcp://127.0.0.1:5631/".to_string()),
+            ]
+        );
+        assert!(db.get_endpoints(&pre, "watcher")?.is_empty());
+
+        // Disallowed providers are skipped
+        db.ends.pin(
+            &keys,
+            &EndpointRecord::with_values(Some(false), None, String::new()),
+        )?;
+        assert!(db.get_endpoints(&pre, "witness")?.is_empty());
+
+        Ok(())
+    }
+}
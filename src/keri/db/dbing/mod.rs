@@ -3,11 +3,16 @@ pub mod keys;
 use crate::keri::core::filing::{BaseFiler, Filer, FilerDefaults};
 use crate::keri::db::dbing::keys::{on_key, split_on_key, suffix, unsuffix};
 use crate::keri::db::errors::DBError;
-use heed::{Database, DatabaseFlags, Env, EnvOpenOptions};
+use base64::{engine::general_purpose, Engine};
+use heed::{
+    CompactionOption, Database, DatabaseFlags, Env, EnvFlags, EnvOpenOptions, PutFlags, RoTxn,
+    RwTxn, WithoutTls,
+};
 use std::collections::HashSet;
 use std::fs;
+use std::io::{BufRead, Write};
 use std::ops::Bound;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 const MAX_ON: u64 = u64::MAX;
@@ -22,6 +27,9 @@ pub struct LMDBerBuilder {
     name: String,
     temp: bool,
     reopen: bool,
+    map_size: Option<usize>,
+    max_dbs: Option<u32>,
+    no_sync: bool,
     // other fields...
 }
 
@@ -31,6 +39,9 @@ impl Default for LMDBerBuilder {
             name: "test".to_string(),
             temp: true,
             reopen: true, // other defaults
+            map_size: None,
+            max_dbs: None,
+            no_sync: false,
         }
     }
 }
@@ -51,26 +62,53 @@ impl LMDBerBuilder {
         self
     }
 
+    /// Maximum size in bytes of the environment, defaults to LMDBer::MAP_SIZE
+    pub fn map_size(mut self, map_size: usize) -> Self {
+        self.map_size = Some(map_size);
+        self
+    }
+
+    /// Maximum number of named sub dbs, defaults to LMDBer::MAX_NAMED_DBS
+    pub fn max_dbs(mut self, max_dbs: u32) -> Self {
+        self.max_dbs = Some(max_dbs);
+        self
+    }
+
+    /// Open without syncing to disk on commit, see LMDBer::no_sync
+    pub fn no_sync(mut self, no_sync: bool) -> Self {
+        self.no_sync = no_sync;
+        self
+    }
+
     // other setters
 
     pub fn build(self) -> Result<LMDBer, DBError> {
-        // Create and return an LMDBer instance
-        LMDBer::new(
+        // Create the LMDBer closed so the environment settings apply on open
+        let mut lmdber = LMDBer::new(
             self.name,
             "".to_string(), // base parameter
             self.temp,
-            None,        // head_dir_path
-            None,        // perm
-            self.reopen, // reopen
-            false,       // clear
-            false,       // reuse
-            false,       // clean
-            false,       // filed
-            false,       // extensioned
-            None,        // mode
-            None,        // fext
-            false,       // readonly
-        )
+            None,  // head_dir_path
+            None,  // perm
+            false, // reopen
+            false, // clear
+            false, // reuse
+            false, // clean
+            false, // filed
+            false, // extensioned
+            None,  // mode
+            None,  // fext
+            false, // readonly
+        )?;
+        lmdber.map_size = self.map_size.unwrap_or(LMDBer::MAP_SIZE);
+        lmdber.max_dbs = self.max_dbs.unwrap_or(LMDBer::MAX_NAMED_DBS);
+        lmdber.no_sync = self.no_sync;
+
+        if self.reopen {
+            lmdber.reopen(None, None, None, false, false, false, None, None)?;
+        }
+
+        Ok(lmdber)
     }
 }
 
@@ -84,11 +122,23 @@ pub struct LMDBer {
     pub filer: BaseFiler,
 
     /// LMDB environment
-    pub env: Option<Arc<Env>>,
+    ///
+    /// Opened without thread local read slots so a thread may hold several read
+    /// transactions at once, as a Snapshot or OnItemIter alongside plain getters.
+    pub env: Option<Arc<Env<WithoutTls>>>,
 
     /// Whether the database is opened in readonly mode
     pub readonly: bool,
 
+    /// Maximum size in bytes of the environment
+    map_size: usize,
+
+    /// Maximum number of named sub dbs in the environment
+    max_dbs: u32,
+
+    /// Whether commits skip flushing to disk until an explicit sync
+    no_sync: bool,
+
     /// Version of the database
     version: Option<String>,
 }
@@ -110,10 +160,21 @@ impl LMDBer {
         self.filer.temp()
     }
 
-    pub fn env(&self) -> Option<&Arc<Env>> {
+    pub fn env(&self) -> Option<&Arc<Env<WithoutTls>>> {
         self.env.as_ref()
     }
 
+    /// Returns a shared handle to the underlying heed environment if open.
+    ///
+    /// This is an escape hatch for operations the wrapper does not expose.
+    /// Transactions opened on the raw env bypass all LMDBer invariants, such as
+    /// insertion ordering proems on io dup and io set values and ordinal
+    /// suffix formatting, so writes through it can leave the databases in a
+    /// state the rest of the library cannot read back correctly.
+    pub fn raw_env(&self) -> Option<Arc<Env<WithoutTls>>> {
+        self.env.clone()
+    }
+
     pub fn path(&self) -> Option<PathBuf> {
         self.filer.path()
     }
@@ -213,6 +274,9 @@ impl LMDBer {
             filer,
             env: None,
             readonly,
+            map_size: Self::MAP_SIZE,
+            max_dbs: Self::MAX_NAMED_DBS,
+            no_sync: false,
             version: None,
         };
 
@@ -264,18 +328,27 @@ impl LMDBer {
         }
 
         // Open environment with heed
-        let mut env_builder = EnvOpenOptions::new();
+        // Read txns are not tied to a thread local slot so the ones held by
+        // snapshots and streaming iterators do not collide with other reads
+        let mut env_builder = EnvOpenOptions::new().read_txn_without_tls();
 
         // Configure environment
-        env_builder
-            .map_size(Self::MAP_SIZE)
-            .max_dbs(Self::MAX_NAMED_DBS);
+        env_builder.map_size(self.map_size).max_dbs(self.max_dbs);
 
-        let env = if self.readonly {
-            unsafe { Arc::new(env_builder.open(&dir_path)?) }
-        } else {
-            unsafe { Arc::new(env_builder.open(&dir_path)?) }
-        };
+        let mut flags = EnvFlags::empty();
+        if self.readonly {
+            // READ_ONLY only restricts the environment so every write txn fails
+            flags |= EnvFlags::READ_ONLY;
+        }
+        if self.no_sync {
+            // Commits are durable only after an explicit sync
+            flags |= EnvFlags::NO_SYNC;
+        }
+        unsafe {
+            env_builder.flags(flags);
+        }
+
+        let env = unsafe { Arc::new(env_builder.open(&dir_path)?) };
 
         self.env = Some(env);
 
@@ -284,6 +357,83 @@ impl LMDBer {
         Ok(result)
     }
 
+    /// Maximum size in bytes of the environment
+    ///
+    /// Reflects any growth of the open environment through `grow_map_size`.
+    pub fn map_size(&self) -> usize {
+        match self.env.as_ref() {
+            Some(env) => env.info().map_size,
+            None => self.map_size,
+        }
+    }
+
+    /// Grows the maximum size of the open environment to `new_size` bytes
+    ///
+    /// LMDB only allows a resize while this process has no open transactions on
+    /// the environment. The exclusive borrow rules out live snapshots and writes
+    /// through this LMDBer, but an OnItemIter from `range_items` or a transaction
+    /// on the `raw_env` handle owns its transaction, so the caller must drop those
+    /// first. Writes that fill the map fail with MDB_MAP_FULL and are never
+    /// resized implicitly.
+    ///
+    /// # Parameters
+    /// - `new_size`: New maximum size in bytes, must not be below the current size
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the environment was resized
+    /// - `Err(DBError)`: If the database is closed, the size would shrink or LMDB
+    ///   refuses the resize
+    pub fn grow_map_size(&mut self, new_size: usize) -> Result<(), DBError> {
+        if new_size < self.map_size() {
+            return Err(DBError::ValueError(format!(
+                "Map size {} is below current map size {}",
+                new_size,
+                self.map_size()
+            )));
+        }
+        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+        // Borrowed txns are ruled out by &mut self, owned ones by the caller
+        unsafe { env.resize(new_size)? };
+        self.map_size = new_size;
+        Ok(())
+    }
+
+    /// Maximum number of named sub dbs in the environment
+    pub fn max_dbs(&self) -> u32 {
+        self.max_dbs
+    }
+
+    /// Whether the environment was opened with NO_SYNC
+    ///
+    /// Without a sync per commit bulk writes are much faster, but a crash of the
+    /// machine (not just the process) can lose or corrupt transactions committed
+    /// since the last `sync`. Callers opting in must call `sync(true)` at their
+    /// own durability points such as the end of an import.
+    pub fn no_sync(&self) -> bool {
+        self.no_sync
+    }
+
+    /// Flush committed writes to disk
+    ///
+    /// Mirrors mdb_env_sync: with `force` the buffers are always flushed, without
+    /// it they are flushed only when the environment was not opened with NO_SYNC,
+    /// where each commit has already synced.
+    ///
+    /// # Parameters
+    /// - `force`: Flush even when opened with NO_SYNC
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the flush succeeded or was not needed
+    /// - `Err(DBError)`: If the database is closed or the flush fails
+    pub fn sync(&self, force: bool) -> Result<(), DBError> {
+        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+        if force || !self.no_sync {
+            env.force_sync()?;
+        }
+
+        Ok(())
+    }
+
     /// Get the version of the database
     pub fn version(&self) -> Option<&str> {
         self.version.as_deref()
@@ -350,6 +500,15 @@ impl LMDBer {
     // Open an existing database
     pub fn open_database(&self, name: Option<&str>) -> Result<Option<BytesDatabase>, DBError> {
         let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+
+        // A readonly environment refuses write txns so open through a read txn
+        if self.readonly {
+            let rtxn = env.read_txn()?;
+            let db = env.open_database(&rtxn, name)?;
+            rtxn.commit()?;
+            return Ok(db);
+        }
+
         let mut wtxn = env.write_txn()?;
 
         let db = env.open_database(&mut wtxn, name)?;
@@ -358,6 +517,26 @@ impl LMDBer {
         Ok(db)
     }
 
+    /// Returns the names of the named sub dbs in the environment
+    ///
+    /// LMDB keeps the name of each named sub db as a key of the unnamed main db.
+    pub fn sub_db_names(&self) -> Result<Vec<String>, DBError> {
+        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+        let rtxn = env.read_txn()?;
+        let main: BytesDatabase = match env.open_database(&rtxn, None)? {
+            Some(main) => main,
+            None => return Ok(Vec::new()),
+        };
+
+        let mut names = Vec::new();
+        for item in main.iter(&rtxn)? {
+            let (name, _) = item?;
+            names.push(String::from_utf8_lossy(name).to_string());
+        }
+
+        Ok(names)
+    }
+
     // Get a value
     pub fn len(&self, db: &BytesDatabase) -> Result<u64, DBError> {
         let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
@@ -394,6 +573,41 @@ impl LMDBer {
         Ok(true)
     }
 
+    /// Writes many key value pairs in one write transaction without overwriting
+    ///
+    /// Each pair is only written when its key does not already exist, including
+    /// keys written earlier in the same batch, which mirrors `put_val`. Nothing is
+    /// committed unless every put succeeds.
+    ///
+    /// # Parameters
+    /// - `db`: The sub db to write into
+    /// - `items`: Key value pairs to write in order
+    ///
+    /// # Returns
+    /// - `Ok(count)`: Number of pairs actually written
+    /// - `Err(DBError)`: If a database error occurs, in which case none are written
+    pub fn put_vals_batch(
+        &self,
+        db: &BytesDatabase,
+        items: &[(&[u8], &[u8])],
+    ) -> Result<usize, DBError> {
+        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+        let mut wtxn = env.write_txn()?;
+
+        // Dropping wtxn on an early return aborts the whole batch
+        let mut count = 0;
+        for (key, val) in items {
+            if db.get(&wtxn, key)?.is_some() {
+                continue;
+            }
+            db.put(&mut wtxn, key, val)?;
+            count += 1;
+        }
+        wtxn.commit()?;
+
+        Ok(count)
+    }
+
     // Same as put_val, kept for compatibility
     pub fn set_val(&self, db: &BytesDatabase, key: &[u8], val: &[u8]) -> Result<bool, DBError> {
         let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
@@ -403,6 +617,51 @@ impl LMDBer {
         Ok(true)
     }
 
+    /// Opens a read snapshot of the whole environment
+    ///
+    /// Reads through the snapshot all see the database as of this call regardless
+    /// of later writes. The read transaction is released when the snapshot is
+    /// dropped. Plain getters and other snapshots may be used on the same thread
+    /// while it is open.
+    ///
+    /// # Returns
+    /// - `Ok(Snapshot)`: Snapshot borrowing this LMDBer
+    /// - `Err(DBError)`: If the database is closed or the read fails to open
+    pub fn snapshot(&self) -> Result<Snapshot<'_>, DBError> {
+        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+        Ok(Snapshot {
+            txn: env.read_txn()?,
+        })
+    }
+
+    /// Runs `f` with a writer whose puts and deletes span any number of sub dbs
+    /// in one write transaction
+    ///
+    /// The transaction commits only when `f` returns Ok. An error from `f` or from
+    /// the commit aborts it so no sub db sees any of the batch's writes.
+    ///
+    /// # Parameters
+    /// - `f`: Closure doing the writes through the CrossDbWriter
+    ///
+    /// # Returns
+    /// - `Ok(T)`: The value returned by `f` after the batch committed
+    /// - `Err(DBError)`: If the database is closed, `f` fails or the commit fails
+    pub fn cross_db_batch<T, F>(&self, f: F) -> Result<T, DBError>
+    where
+        F: FnOnce(&mut CrossDbWriter<'_>) -> Result<T, DBError>,
+    {
+        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+        let mut writer = CrossDbWriter {
+            txn: env.write_txn()?,
+        };
+
+        // Dropping the writer on an early return aborts the whole batch
+        let result = f(&mut writer)?;
+        writer.txn.commit()?;
+
+        Ok(result)
+    }
+
     // Get a value
     pub fn get_val(&self, db: &BytesDatabase, key: &[u8]) -> Result<Option<Vec<u8>>, DBError> {
         let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
@@ -416,6 +675,41 @@ impl LMDBer {
         Ok(result)
     }
 
+    /// Returns the first key in db that sorts strictly after `key`
+    ///
+    /// An empty `key` returns the first key in db. Lets callers seek past every
+    /// entry under a key prefix without iterating them.
+    ///
+    /// # Parameters
+    /// - `db`: The database to search
+    /// - `key`: Key to seek past
+    ///
+    /// # Returns
+    /// - `Ok(Some(Vec<u8>))`: The next key if any
+    /// - `Ok(None)`: If no key sorts after `key`
+    /// - `Err(DBError)`: If a database error occurs
+    pub fn get_key_after(
+        &self,
+        db: &BytesDatabase,
+        key: &[u8],
+    ) -> Result<Option<Vec<u8>>, DBError> {
+        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+        let rtxn = env.read_txn()?;
+
+        let start = if key.is_empty() {
+            Bound::Unbounded
+        } else {
+            Bound::Excluded(key)
+        };
+        let mut iter = db.range(&rtxn, &(start, Bound::Unbounded))?;
+        let result = match iter.next() {
+            Some(item) => Some(item?.0.to_vec()),
+            None => None,
+        };
+
+        Ok(result)
+    }
+
     /// Returns the last of the duplicated values associated with a key for databases with dupsort=true,
     ///
     /// # Parameters
@@ -468,20 +762,276 @@ impl LMDBer {
     }
 
     // Count entries in a database
+    //
+    // Reads the entry count from the db's stats rather than iterating. For a dupsort
+    // db LMDB counts every duplicate as its own entry, which matches what iterating
+    // the db would yield, so no fallback to iteration is needed.
     pub fn cnt(&self, db: &BytesDatabase) -> Result<usize, DBError> {
         let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
         let rtxn = env.read_txn()?;
 
-        // In heed 0.22.0, we can iterate to count
+        Ok(db.len(&rtxn)? as usize)
+    }
+
+    /// Run an operation and measure how much the environment's used space changed
+    ///
+    /// Used bytes are the size of all non-free pages across every database in the
+    /// environment, sampled before and after `f` runs.
+    ///
+    /// # Parameters
+    /// - `f`: The operation to measure
+    ///
+    /// # Returns
+    /// - `Ok((result, delta))`: Result of `f` and the change in used bytes
+    /// - `Err(DBError)`: If the database is closed or `f` fails
+    pub fn with_usage_delta<F, R>(&self, f: F) -> Result<(R, i64), DBError>
+    where
+        F: FnOnce() -> Result<R, DBError>,
+    {
+        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+
+        let before = env.non_free_pages_size()? as i64;
+        let result = f()?;
+        let after = env.non_free_pages_size()? as i64;
+
+        Ok((result, after - before))
+    }
+
+    /// Count pages in the environment's file that no database is using
+    ///
+    /// LMDB reuses freed pages but never shrinks its data file, so this is the
+    /// space a `copy` with compaction would reclaim. Computed as the pages spanned
+    /// by the file up to the last used page less the two meta pages and the pages
+    /// in use by every database.
+    ///
+    /// # Returns
+    /// - `Ok(count)`: Number of free pages
+    /// - `Err(DBError)`: If the database is closed or a read fails
+    pub fn free_pages(&self) -> Result<u64, DBError> {
+        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+
+        let rtxn = env.read_txn()?;
+        let main: BytesDatabase = env
+            .open_database(&rtxn, None)?
+            .ok_or_else(|| DBError::DatabaseError("Missing main database".to_string()))?;
+        let page_size = main.stat(&rtxn)?.page_size as u64;
+        drop(rtxn);
+
+        let total = env.info().last_page_number as u64 + 1;
+        let used = env.non_free_pages_size()? / page_size;
+
+        Ok(total.saturating_sub(2).saturating_sub(used))
+    }
+
+    /// List the names of the named sub dbs in the environment
+    ///
+    /// LMDB records each named database as an entry in the unnamed root
+    /// database, so this walks the root keys. Entries that are not a usable
+    /// name, such as the empty name or non UTF-8 keys, are skipped.
+    ///
+    /// # Returns
+    /// - `Ok(names)`: Sub db names in key order
+    /// - `Err(DBError)`: If the database is closed or a read fails
+    pub fn list_databases(&self) -> Result<Vec<String>, DBError> {
+        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+
+        let rtxn = env.read_txn()?;
+        let main: BytesDatabase = env
+            .open_database(&rtxn, None)?
+            .ok_or_else(|| DBError::DatabaseError("Missing main database".to_string()))?;
+
+        let mut names = Vec::new();
+        for result in main.iter(&rtxn)? {
+            let (key, _) = result?;
+            match std::str::from_utf8(key) {
+                Ok(name) if !name.is_empty() => names.push(name.to_string()),
+                _ => continue,
+            }
+        }
+
+        Ok(names)
+    }
+
+    /// Write a compacted copy of the environment to `dest_path`
+    ///
+    /// Uses LMDB's copy with compaction which omits free pages and renumbers the
+    /// used ones, so the copy is as small as the live data allows. `dest_path` is the
+    /// data file to create, conventionally `data.mdb` inside an empty directory that
+    /// the caller can then swap in place of the current one.
+    ///
+    /// # Parameters
+    /// - `dest_path`: Path of the data file to write
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the copy was written
+    /// - `Err(DBError)`: If the database is closed or the copy fails
+    pub fn compact_to(&self, dest_path: &Path) -> Result<(), DBError> {
+        self.backup(dest_path, true)
+    }
+
+    /// Write an online copy of the environment to `dest` while it stays open
+    ///
+    /// The copy runs under its own read transaction so concurrent writers are not
+    /// blocked and the backup is a consistent view as of when it started. `dest` is
+    /// the data file to create and must not already exist; a directory holding
+    /// only it can be opened as an LMDBer to restore.
+    ///
+    /// # Parameters
+    /// - `dest`: Path of the data file to write
+    /// - `compact`: Omit free pages and renumber the used ones when true
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the backup was written
+    /// - `Err(DBError)`: DbClosed if the environment is closed, or if the copy fails
+    pub fn backup(&self, dest: &Path, compact: bool) -> Result<(), DBError> {
+        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+        let option = if compact {
+            CompactionOption::Enabled
+        } else {
+            CompactionOption::Disabled
+        };
+        env.copy_to_path(dest, option)?;
+
+        Ok(())
+    }
+
+    /// Writes every entry of a sub db as newline delimited JSON records
+    ///
+    /// Each line is `{"key":<b64>,"val":<b64>}` with the raw key and raw value
+    /// base64 encoded so hidden proems are kept. Keys ending in `sep` plus a 32
+    /// character hex ordinal, such as on and insertion ordered keys, also get an
+    /// `"on"` field with the decoded ordinal. Values starting with the 33 character
+    /// insertion ordering proem of io dup sub dbs get an `"ion"` field with the
+    /// decoded proem ordinal.
+    ///
+    /// # Parameters
+    /// - `db`: The sub db to dump
+    /// - `out`: Writer for the NDJSON lines
+    /// - `sep`: Optional separator byte (defaults to '.')
+    ///
+    /// # Returns
+    /// - `Ok(count)`: Number of records written
+    /// - `Err(DBError)`: If a database or write error occurs
+    pub fn export_ndjson<W: Write>(
+        &self,
+        db: &BytesDatabase,
+        out: &mut W,
+        sep: Option<[u8; 1]>,
+    ) -> Result<usize, DBError> {
+        let sep = sep.unwrap_or([b'.']);
+        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+        let rtxn = env.read_txn()?;
+
         let mut count = 0;
-        let iter = db.iter(&rtxn)?;
-        for _ in iter {
+        for entry in db.iter(&rtxn)? {
+            let (key, val) = entry?;
+
+            let mut record = serde_json::Map::new();
+            record.insert(
+                "key".to_string(),
+                general_purpose::STANDARD.encode(key).into(),
+            );
+            record.insert(
+                "val".to_string(),
+                general_purpose::STANDARD.encode(val).into(),
+            );
+            if let Some(on) = Self::key_ordinal(key, sep) {
+                record.insert("on".to_string(), on.into());
+            }
+            if let Some(ion) = Self::val_ordinal(val) {
+                record.insert("ion".to_string(), ion.into());
+            }
+
+            serde_json::to_writer(&mut *out, &record)
+                .map_err(|e| DBError::IoError(e.to_string()))?;
+            out.write_all(b"\n")
+                .map_err(|e| DBError::IoError(e.to_string()))?;
             count += 1;
         }
 
         Ok(count)
     }
 
+    /// Reads newline delimited JSON records written by `export_ndjson` into a sub db
+    ///
+    /// Every record is decoded before anything is written and then all are written
+    /// in one transaction so a malformed input leaves the sub db unchanged. Blank
+    /// lines are skipped and any `"on"` field is ignored since the raw key already
+    /// holds the ordinal.
+    ///
+    /// # Parameters
+    /// - `db`: The sub db to load into
+    /// - `input`: Reader of NDJSON lines
+    ///
+    /// # Returns
+    /// - `Ok(count)`: Number of records written
+    /// - `Err(DBError)`: ParseError naming the line number of a malformed record,
+    ///   or a database or read error
+    pub fn import_ndjson<R: BufRead>(
+        &self,
+        db: &BytesDatabase,
+        input: R,
+    ) -> Result<usize, DBError> {
+        let mut records = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            let line = line.map_err(|e| DBError::IoError(e.to_string()))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let malformed = |msg: String| {
+                DBError::ParseError(format!(
+                    "Malformed NDJSON record at line {}: {}",
+                    i + 1,
+                    msg
+                ))
+            };
+
+            let record: serde_json::Value =
+                serde_json::from_str(&line).map_err(|e| malformed(e.to_string()))?;
+            let field = |name: &str| -> Result<Vec<u8>, DBError> {
+                let b64 = record
+                    .get(name)
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| malformed(format!("missing string field {}", name)))?;
+                general_purpose::STANDARD
+                    .decode(b64)
+                    .map_err(|e| malformed(format!("invalid base64 in {}: {}", name, e)))
+            };
+            let key = field("key")?;
+            let val = field("val")?;
+            records.push((key, val));
+        }
+
+        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+        let mut wtxn = env.write_txn()?;
+        for (key, val) in records.iter() {
+            db.put(&mut wtxn, key, val)?;
+        }
+        wtxn.commit()?;
+
+        Ok(records.len())
+    }
+
+    /// Returns the ordinal of a key ending in sep plus 32 hex characters if any
+    fn key_ordinal(key: &[u8], sep: [u8; 1]) -> Option<u64> {
+        let pos = key.iter().rposition(|b| *b == sep[0])?;
+        let hex = &key[pos + 1..];
+        if hex.len() != 32 || !hex.iter().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        u64::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok()
+    }
+
+    /// Returns the ordinal of a value starting with a 33 character io dup proem if any
+    fn val_ordinal(val: &[u8]) -> Option<u64> {
+        if val.len() < 33 || val[32] != b'.' || !val[..32].iter().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        u64::from_str_radix(std::str::from_utf8(&val[..32]).ok()?, 16).ok()
+    }
+
     /// Get items with a given prefix and process them with a callback function
     ///
     /// # Parameters
@@ -532,46 +1082,109 @@ impl LMDBer {
         Ok(count)
     }
 
-    // Delete all values with a given prefix
-    pub fn del_top_val(&self, db: &BytesDatabase, prefix: &[u8]) -> Result<bool, DBError> {
-        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
-        let mut txn = env.write_txn()?;
-
-        // First collect keys with the prefix
-        let read_txn = env.read_txn()?;
-        let iter = db.iter(&read_txn)?;
-
-        let keys_to_delete: Vec<Vec<u8>> = iter
-            .filter_map(|result| match result {
-                Ok((k, _)) => {
-                    if k.starts_with(prefix) {
-                        Some(Ok(k.to_vec()))
-                    } else {
-                        None
-                    }
-                }
-                Err(e) => Some(Err(DBError::EnvError(e))),
-            })
-            .collect::<Result<Vec<_>, _>>()?;
+    /// Iterates over the items whose keys start with prefix in descending key order
+    ///
+    /// Walks only the prefix branch backwards, so the latest entries under a key
+    /// are reached without loading the whole branch.
+    ///
+    /// # Parameters
+    /// - `db`: The database to iterate
+    /// - `prefix`: The prefix to match keys against
+    /// - `cb`: Callback function that takes key-value pairs, returning false stops
+    ///
+    /// # Returns
+    /// - `Ok(count)`: Number of items processed
+    /// - `Err(DBError)`: If a database error occurs
+    pub fn get_top_items_back_iter<F>(
+        &self,
+        db: &BytesDatabase,
+        prefix: &[u8],
+        mut cb: F,
+    ) -> Result<usize, DBError>
+    where
+        F: FnMut(&[u8], &[u8]) -> Result<bool, DBError>,
+    {
+        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+        let txn = env.read_txn()?;
+
+        // Empty prefix means get all items
+        let iter: Box<dyn Iterator<Item = Result<(&[u8], &[u8]), heed::Error>>> =
+            if prefix.is_empty() {
+                Box::new(db.rev_iter(&txn)?)
+            } else {
+                Box::new(db.rev_prefix_iter(&txn, prefix)?)
+            };
+
+        let mut count = 0;
+        for result in iter {
+            let (k, v) = result?;
+            count += 1;
+            if !cb(k, v)? {
+                break;
+            }
+        }
+
+        Ok(count)
+    }
 
-        // Close read transaction before starting write operations
-        drop(read_txn);
+    // Delete all values with a given prefix
+    //
+    // Scans and deletes in one write transaction so the removal is atomic and no
+    // key written between a separate scan and delete can be missed.
+    pub fn del_top_val(&self, db: &BytesDatabase, prefix: &[u8]) -> Result<bool, DBError> {
+        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+        let mut wtxn = env.write_txn()?;
 
+        // LMDB rejects an empty key so an empty prefix walks the whole db instead
         let mut deleted = false;
-        if !keys_to_delete.is_empty() {
-            for key in keys_to_delete {
-                db.delete(&mut txn, &key)?;
-                deleted = true;
+        if prefix.is_empty() {
+            let mut iter = db.iter_mut(&mut wtxn)?;
+            while let Some(result) = iter.next() {
+                result?;
+                // SAFETY: no key or value borrowed from the iterator outlives this call
+                if unsafe { iter.del_current()? } {
+                    deleted = true;
+                }
+            }
+        } else {
+            let mut iter = db.prefix_iter_mut(&mut wtxn, prefix)?;
+            while let Some(result) = iter.next() {
+                result?;
+                // SAFETY: no key or value borrowed from the iterator outlives this call
+                if unsafe { iter.del_current()? } {
+                    deleted = true;
+                }
             }
+        }
 
-            txn.commit()?;
+        if deleted {
+            wtxn.commit()?;
         } else {
-            txn.abort();
+            wtxn.abort();
         }
 
         Ok(deleted)
     }
 
+    /// Delete every entry in db in a single write transaction
+    ///
+    /// The named sub db itself is kept so `db` remains usable for later puts.
+    ///
+    /// # Parameters
+    /// - `db`: The sub db to empty
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the sub db was emptied
+    /// - `Err(DBError)`: If the database is closed or the clear fails
+    pub fn clear_database(&self, db: &BytesDatabase) -> Result<(), DBError> {
+        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+        let mut wtxn = env.write_txn()?;
+        db.clear(&mut wtxn)?;
+        wtxn.commit()?;
+
+        Ok(())
+    }
+
     /// Write serialized bytes val to location at onkey consisting of
     /// key + sep + serialized on in db.
     /// Overwrites pre-existing value at onkey if any.
@@ -670,27 +1283,15 @@ impl LMDBer {
 
         // Get the environment
         let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
-        // Initialize with default ordinal 0
-        let mut on = 0;
-        let onkey = on_key(key, 0, Some(sep));
 
-        // Create a range iterator to find keys with the given prefix
-        // The iterator will return keys in ascending order
-
-        let rtxn = env.read_txn()?;
-        let range = (Bound::Included(onkey.as_slice()), Bound::Unbounded);
+        // One write txn both finds the last ordinal and writes so no read txn is
+        // left open to pin old pages and no other append can slip in between
+        let mut wtxn = env.write_txn()?;
 
-        let mut iter = db.range(&rtxn, &range)?;
-        let mut last_entry = None;
+        let last_entry = last_on(&wtxn, db, key, sep)?;
 
-        for result in iter {
-            let (onkey, _) = result?;
-            let (ckey, cn) = split_on_key(onkey.as_ref(), Some(sep))?;
-            if ckey != key {
-                break;
-            }
-            last_entry = Some(cn);
-        }
+        // Initialize with default ordinal 0
+        let mut on = 0;
         // If we found a last entry, increment its ordinal number
         if let Some(last_on) = last_entry {
             // Check for overflow
@@ -706,9 +1307,6 @@ impl LMDBer {
         // Create the new key with the determined ordinal number
         let onkey = on_key(key, on, Some(sep));
 
-        // Use a write transaction to add the new entry
-        let mut wtxn = env.write_txn()?;
-
         // Check if the key already exists (should not happen if our algorithm is correct)
         if db.get(&wtxn, &onkey)?.is_some() {
             return Err(DBError::ValueError(format!(
@@ -744,7 +1342,7 @@ impl LMDBer {
         &self,
         db: &BytesDatabase,
         key: &[u8],
-        on: u32,
+        on: u64,
         val: &[u8],
         sep: Option<[u8; 1]>,
     ) -> Result<bool, DBError> {
@@ -758,7 +1356,7 @@ impl LMDBer {
             .map_err(|e| DBError::DatabaseError(format!("{}", e)))?;
 
         let onkey = if !key.is_empty() {
-            on_key(key, on as u64, Some(sep))
+            on_key(key, on, Some(sep))
         } else {
             key.to_vec()
         };
@@ -803,7 +1401,7 @@ impl LMDBer {
         &self,
         db: &BytesDatabase,
         key: &[u8],
-        on: u32,
+        on: u64,
         sep: Option<[u8; 1]>,
     ) -> Result<Option<Vec<u8>>, DBError> {
         let sep = sep.unwrap_or(*b".");
@@ -816,7 +1414,7 @@ impl LMDBer {
             .map_err(|e| DBError::DatabaseError(format!("{}", e)))?;
 
         let onkey = if !key.is_empty() {
-            on_key(key, on as u64, Some(sep))
+            on_key(key, on, Some(sep))
         } else {
             key.to_vec()
         };
@@ -850,7 +1448,7 @@ impl LMDBer {
         &self,
         db: &BytesDatabase,
         key: &[u8],
-        on: u32,
+        on: u64,
         sep: Option<[u8; 1]>,
     ) -> Result<bool, DBError> {
         let sep = sep.unwrap_or(*b".");
@@ -863,7 +1461,7 @@ impl LMDBer {
             .map_err(|e| DBError::DatabaseError(format!("{}", e)))?;
 
         let onkey = if !key.is_empty() {
-            on_key(key, on as u64, Some(sep))
+            on_key(key, on, Some(sep))
         } else {
             key.to_vec()
         };
@@ -955,55 +1553,115 @@ impl LMDBer {
         Ok(count)
     }
 
-    /// - txn: the read transaction to use
-    pub fn get_on_item_iter<F>(
+    /// Returns an iterator over the (key, on, val) triples in db starting at the
+    /// onkey for key and on. The iterator holds its own read transaction so it
+    /// may be driven with a plain for loop and outlive no borrow of the LMDBer.
+    ///
+    /// # Parameters
+    /// - db: named sub db of lmdb
+    /// - key: key within sub db's keyspace, None or empty iterates all keys
+    /// - on: ordinal number at which to start, defaults to 0
+    /// - sep: separator bytes for split, defaults to '.'
+    ///
+    /// # Returns
+    /// - `Ok(OnItemIter)`: iterator stopping at the first entry for another key
+    /// - `Err(DBError)`: if the environment is closed or the txn cannot be opened
+    pub fn range_items(
         &self,
         db: &BytesDatabase,
         key: Option<&[u8]>,
         on: Option<u64>,
         sep: Option<[u8; 1]>,
-        mut callback: F,
-    ) -> Result<(), DBError>
-    where
-        F: FnMut(Vec<u8>, u64, Vec<u8>) -> Result<bool, DBError>,
-    {
+    ) -> Result<OnItemIter, DBError> {
         let separator = sep.unwrap_or([b'.']);
-        let key = key.unwrap_or(&[]);
-        let on = on.unwrap_or(0);
+        let key = key.unwrap_or(&[]).to_vec();
 
         let env = self
             .env
             .as_ref()
             .ok_or(DBError::DatabaseError("Not opened".to_string()))?;
-        let txn = env
-            .read_txn()
+        let txn = Env::clone(env)
+            .static_read_txn()
             .map_err(|e| DBError::DatabaseError(format!("{}", e)))?;
 
-        // Create the starting key for the range
-        let mut iter = if !key.is_empty() {
-            let onkey = on_key(key, on, Some(separator));
-            let range = (Bound::Included(onkey.as_slice()), Bound::Unbounded);
-            db.range(&txn, &range)?
+        let start = if !key.is_empty() {
+            Bound::Included(on_key(&key, on.unwrap_or(0), Some(separator)))
         } else {
-            let range = (Bound::Unbounded, Bound::Unbounded);
-            db.range(&txn, &range)?
+            Bound::Unbounded
         };
 
-        while let Some(result) = iter.next() {
-            let (ckey, cval) = result?;
+        Ok(OnItemIter {
+            txn,
+            db: *db,
+            key,
+            sep: separator,
+            start,
+            last: None,
+            done: false,
+        })
+    }
+
+    /// Calls callback with each (key, on, val) triple from range_items until it
+    /// returns false or the entries for key are exhausted
+    pub fn get_on_item_iter<F>(
+        &self,
+        db: &BytesDatabase,
+        key: Option<&[u8]>,
+        on: Option<u64>,
+        sep: Option<[u8; 1]>,
+        mut callback: F,
+    ) -> Result<(), DBError>
+    where
+        F: FnMut(Vec<u8>, u64, Vec<u8>) -> Result<bool, DBError>,
+    {
+        for item in self.range_items(db, key, on, sep)? {
+            let (ckey, cn, cval) = item?;
+
+            // If callback returns false, stop iteration
+            if !callback(ckey, cn, cval)? {
+                break;
+            }
+        }
+
+        Ok(())
+    }
 
-            // Split the key to get the base part and the ordinal number
-            let (ckey_base, cn) = split_on_key(ckey.as_ref(), Some(separator))?;
+    /// Calls callback with each (key, on, val) triple for key whose ordinal is in
+    /// `[start_on, stop_on)` until it returns false
+    ///
+    /// Iteration stops as soon as the base key no longer matches or the ordinal
+    /// reaches `stop_on`, so only the window is read.
+    ///
+    /// # Parameters
+    /// - db: named sub db of lmdb
+    /// - key: key within sub db's keyspace
+    /// - start_on: first ordinal included
+    /// - stop_on: first ordinal excluded
+    /// - sep: separator bytes for split, defaults to '.'
+    pub fn get_on_item_range<F>(
+        &self,
+        db: &BytesDatabase,
+        key: &[u8],
+        start_on: u64,
+        stop_on: u64,
+        sep: Option<[u8; 1]>,
+        mut callback: F,
+    ) -> Result<(), DBError>
+    where
+        F: FnMut(Vec<u8>, u64, Vec<u8>) -> Result<bool, DBError>,
+    {
+        if start_on >= stop_on {
+            return Ok(());
+        }
 
-            // If key is not empty and ckey_base doesn't match key, return an error
-            // This will be caught in the take_while below to stop iteration
-            if !key.is_empty() && ckey_base != key {
+        for item in self.range_items(db, Some(key), Some(start_on), sep)? {
+            let (ckey, cn, cval) = item?;
+            if cn >= stop_on {
                 break;
             }
 
-            // Call the callback with each value
             // If callback returns false, stop iteration
-            if !callback(ckey_base, cn, cval.to_vec())? {
+            if !callback(ckey, cn, cval)? {
                 break;
             }
         }
@@ -1079,7 +1737,7 @@ impl LMDBer {
                 if ckey == key {
                     // This is a value at our target key
                     existing_vals.insert(Vec::from(val));
-                    ion = cion + 1; // ion to add at is increment of cion
+                    ion = cion.saturating_add(1); // ion to add at is increment of cion
                 } else {
                     // We've moved past our key range
                     break;
@@ -1090,6 +1748,13 @@ impl LMDBer {
         // Process each value that's not already in the set
         for val in vals {
             if !existing_vals.contains(&Vec::from(*val)) {
+                // MAX_ON is reserved as the seek sentinel of get_io_set_val_last
+                if ion >= MAX_ON {
+                    return Err(DBError::ValueError(format!(
+                        "Insertion ordinal ion={} for key={:?} is reserved.",
+                        ion, key
+                    )));
+                }
                 let iokey = suffix(key, ion, Some(sep));
 
                 // Add the new entry
@@ -1213,7 +1878,7 @@ impl LMDBer {
                     if cval == val {
                         found = true; // Value already exists in the set
                     }
-                    ion = cion + 1; // ion to add at is increment of cion
+                    ion = cion.saturating_add(1); // ion to add at is increment of cion
                 } else {
                     // We've moved past our key range
                     break;
@@ -1228,6 +1893,14 @@ impl LMDBer {
             return Ok(false);
         }
 
+        // MAX_ON is reserved as the seek sentinel of get_io_set_val_last
+        if ion >= MAX_ON {
+            return Err(DBError::ValueError(format!(
+                "Insertion ordinal ion={} for key={:?} is reserved.",
+                ion, key
+            )));
+        }
+
         // Create the key with the next available ordinal
         let iokey = suffix(key, ion, Some(sep));
 
@@ -1531,12 +2204,70 @@ impl LMDBer {
         Ok(())
     }
 
+    /// Iterates the insertion ordered set of values at same apparent effective key
+    /// in reverse, from the last inserted value back to the first.
+    /// Uses hidden ordinal key suffix for insertion ordering.
+    /// The suffix is appended and stripped transparently.
+    ///
+    /// # Parameters
+    /// - `db`: Instance of named sub db with dupsort==False
+    /// - `key`: Apparent effective key
+    /// - `sep`: Optional separator byte (defaults to '.')
+    /// - `callback`: Function to call for each value found, return false to stop
+    ///
+    /// # Returns
+    /// - `Ok(())`: If iteration completed successfully
+    /// - `Err(DBError)`: If a database error occurs
+    pub fn get_io_set_vals_back_iter<F>(
+        &self,
+        db: &BytesDatabase,
+        key: &[u8],
+        sep: Option<[u8; 1]>,
+        mut callback: F,
+    ) -> Result<(), DBError>
+    where
+        F: FnMut(&[u8]) -> Result<bool, DBError>,
+    {
+        let sep = sep.unwrap_or([b'.']);
+        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+
+        // Create a read transaction
+        let rtxn = env.read_txn()?;
+
+        // Start from iokey at max suffix value and walk backwards
+        let max_iokey = suffix(key, u64::MAX, Some(sep));
+        let range = (Bound::Unbounded, Bound::Included(max_iokey.as_slice()));
+        let iter = db.rev_range(&rtxn, &range)?;
+
+        for entry in iter {
+            let (iokey, val) = entry?;
+
+            if let (ckey, _) = unsuffix(&iokey, Some(sep))? {
+                if ckey != key {
+                    // We've moved before our key range
+                    break;
+                }
+
+                // If callback returns false, stop iteration
+                if !callback(&val)? {
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns the last added value at the apparent effective key if any,
     /// otherwise None if no entry.
     ///
     /// Uses hidden ordinal key suffix for insertion ordering.
     /// The suffix is appended and stripped transparently.
     ///
+    /// Seeks from the iokey at ordinal u64::MAX. That ordinal is reserved and
+    /// never written by `add_io_set_val` or `put_io_set_vals`, but since the seek
+    /// is inclusive a value stored there by other means is still returned.
+    ///
     /// # Parameters
     /// - `db`: Instance of named sub db with dupsort==False
     /// - `key`: Apparent effective key
@@ -1729,10 +2460,11 @@ impl LMDBer {
 
     /// Put each entry from a list of values to the given key in the database
     /// Adds to existing values at key if any
-    /// Returns true if successful
+    /// Returns the number of values actually written
     ///
     /// Duplicates are inserted in lexicographic order not insertion order.
-    /// LMDB does not insert a duplicate unless it is a unique value for that key.
+    /// LMDB does not insert a duplicate unless it is a unique value for that key,
+    /// so values already at key or repeated in vals are not counted.
     ///
     /// # Parameters
     /// * `db` - opened named sub database with dupsort=True
@@ -1743,7 +2475,7 @@ impl LMDBer {
         db: &BytesDatabase,
         key: &[u8],
         vals: &[impl AsRef<[u8]>],
-    ) -> Result<bool, DBError> {
+    ) -> Result<usize, DBError> {
         let env = match &self.env {
             Some(env) => env,
             None => return Err(DBError::DbClosed),
@@ -1754,10 +2486,14 @@ impl LMDBer {
             Err(e) => return Err(DBError::EnvError(e)),
         };
 
+        // NO_DUP_DATA makes LMDB report an existing dup as KEYEXIST so only new
+        // values are counted within the same txn that writes them
+        let mut count = 0;
         for val in vals {
-            match db.put(&mut txn, key, val.as_ref()) {
-                Ok(_) => {} // Success returns (), no boolean to check
-                Err(e) => {
+            match db.put_with_flags(&mut txn, PutFlags::NO_DUP_DATA, key, val.as_ref()) {
+                Ok(_) => count += 1,
+                Err(heed::Error::Mdb(heed::MdbError::KeyExist)) => {}
+                Err(_) => {
                     return Err(DBError::KeyError(format!(
                         "Key: `{:?}` is either empty, too big, or wrong DUPFIXED size",
                         key
@@ -1770,8 +2506,28 @@ impl LMDBer {
             return Err(DBError::EnvError(e));
         }
 
-        // Since we got here without errors, all values were added successfully
-        Ok(true)
+        Ok(count)
+    }
+
+    /// Return all duplicate values at key in database
+    ///
+    /// Duplicates are retrieved in lexicographic order not insertion order.
+    /// Use `get_vals_iter` to stream large sets of duplicates instead.
+    ///
+    /// # Parameters
+    /// * `db` - opened named sub database with dupsort=True
+    /// * `key` - bytes of key within sub db's keyspace
+    ///
+    /// # Returns
+    /// * `Result<Vec<Vec<u8>>, DBError>` - Values at key, empty if there are none
+    pub fn get_vals(&self, db: &BytesDatabase, key: &[u8]) -> Result<Vec<Vec<u8>>, DBError> {
+        let mut vals = Vec::new();
+        self.get_vals_iter(db, key, |v| {
+            vals.push(v.to_vec());
+            Ok(true)
+        })?;
+
+        Ok(vals)
     }
 
     /// Return iterator of all duplicate values at key in database
@@ -1853,7 +2609,7 @@ impl LMDBer {
     fn count_duplicates(
         &self,
         db: &BytesDatabase,
-        txn: &heed::RoTxn,
+        txn: &RoTxn<'_, WithoutTls>,
         key: &[u8],
     ) -> Result<usize, DBError> {
         let prefix_iter = match db.prefix_iter(txn, &key) {
@@ -1935,6 +2691,43 @@ impl LMDBer {
 
         Ok(result)
     }
+
+    /// Deletes exactly the duplicate at key that equals val, even when val is empty
+    ///
+    /// Unlike `del_vals`, an empty val never falls back to deleting all duplicates.
+    /// LMDB only keeps an empty duplicate while it is the sole value at key, so an
+    /// empty val alongside other duplicates is reported as absent.
+    ///
+    /// # Parameters
+    /// * `db` - opened named sub database with dupsort=True
+    /// * `key` - bytes of key within sub db's keyspace
+    /// * `val` - bytes of the duplicate value to delete, possibly empty
+    ///
+    /// # Returns
+    /// * `Result<bool, DBError>` - True if the duplicate existed and was deleted
+    pub fn del_val_exact(
+        &self,
+        db: &BytesDatabase,
+        key: &[u8],
+        val: &[u8],
+    ) -> Result<bool, DBError> {
+        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+        let mut txn = env.write_txn()?;
+        let result = match db.delete_one_duplicate(&mut txn, key, val) {
+            Ok(result) => result,
+            Err(heed::Error::Mdb(heed::MdbError::BadValSize)) if val.is_empty() => false,
+            Err(_) => {
+                return Err(DBError::KeyError(format!(
+                    "Key: `{:?}` is either empty, too big, or wrong DUPFIXED size",
+                    key
+                )))
+            }
+        };
+        txn.commit()?;
+
+        Ok(result)
+    }
+
     /// Return list of duplicate values at key in db in insertion order
     /// Returns empty vector if no entry at key
     /// Removes prepended proem ordinal from each val before returning
@@ -1978,7 +2771,7 @@ impl LMDBer {
                     // Make sure we only process exact key matches
                     if k == key {
                         // Skip values that are too short (must be at least 33 bytes for the proem)
-                        if val.len() > 33 {
+                        if val.len() >= 33 {
                             // Remove the 33-byte proem from the value
                             vals.push(Vec::from(&val[33..]));
                         }
@@ -1997,34 +2790,100 @@ impl LMDBer {
         Ok(vals)
     }
 
-    /// Write each entry from list of bytes vals to key in db in insertion order
-    /// Adds to existing values at key if any
-    /// Returns true if at least one of vals is added as dup, false otherwise
+    /// Checks that the proem ordinals of the io dup vals at key are strictly
+    /// increasing in the order lmdb returns them
     /// Assumes DB opened with dupsort=True
     ///
-    /// Duplicates at a given key preserve insertion order of duplicate.
-    /// Because lmdb is lexocographic an insertion ordering proem is prepended to
-    /// all values that makes lexocographic order that same as insertion order.
-    ///
-    /// Duplicates are ordered as a pair of key plus value so prepending proem
-    /// to each value changes duplicate ordering. Proem is 33 characters long.
-    /// With 32 character hex string followed by '.' for essentially unlimited
-    /// number of values which will be limited by memory.
+    /// Since lmdb orders dups lexicographically a healthy key has proems whose
+    /// lexicographic order is their insertion order. A repeated, malformed or out of
+    /// order proem means something wrote around the proem system.
     ///
     /// # Parameters
     /// - `db`: Opened named sub db with dupsort=True
     /// - `key`: bytes of key within sub db's keyspace
-    /// - `vals`: list of bytes of values to be written
     ///
     /// # Returns
-    /// - `Ok(bool)`: True if at least one value was added, false otherwise
-    /// - `Err(DBError)`: If a database error occurs
-    pub fn put_io_dup_vals(
-        &self,
-        db: &BytesDatabase,
-        key: &[u8],
+    /// - `Ok(())`: If the proems at key are well formed and strictly increasing
+    /// - `Err(DBError::ProemDisorder)`: If any proem violates the ordering
+    pub fn check_io_dup_invariants(&self, db: &BytesDatabase, key: &[u8]) -> Result<(), DBError> {
+        let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
+        let txn = env.read_txn()?;
+
+        let disorder = || DBError::ProemDisorder {
+            key: String::from_utf8_lossy(key).to_string(),
+        };
+
+        let mut prior: Option<u128> = None;
+        for res in db.prefix_iter(&txn, key)? {
+            let (k, val) = res?;
+            if k != key {
+                continue;
+            }
+
+            if val.len() < 33 || val[32] != b'.' {
+                return Err(disorder());
+            }
+            let ordinal = std::str::from_utf8(&val[..32])
+                .ok()
+                .and_then(|on| u128::from_str_radix(on, 16).ok())
+                .ok_or_else(disorder)?;
+
+            if prior.is_some_and(|p| ordinal <= p) {
+                return Err(disorder());
+            }
+            prior = Some(ordinal);
+        }
+
+        Ok(())
+    }
+
+    /// Write each entry from list of bytes vals to key in db in insertion order
+    /// Adds to existing values at key if any
+    /// Returns true if at least one of vals is added as dup, false otherwise
+    /// Assumes DB opened with dupsort=True
+    ///
+    /// Duplicates at a given key preserve insertion order of duplicate.
+    /// Because lmdb is lexocographic an insertion ordering proem is prepended to
+    /// all values that makes lexocographic order that same as insertion order.
+    ///
+    /// Duplicates are ordered as a pair of key plus value so prepending proem
+    /// to each value changes duplicate ordering. Proem is 33 characters long.
+    /// With 32 character hex string followed by '.' for essentially unlimited
+    /// number of values which will be limited by memory.
+    ///
+    /// # Parameters
+    /// - `db`: Opened named sub db with dupsort=True
+    /// - `key`: bytes of key within sub db's keyspace
+    /// - `vals`: list of bytes of values to be written
+    ///
+    /// # Returns
+    /// - `Ok(bool)`: True if at least one value was added, false otherwise
+    /// - `Err(DBError)`: If a database error occurs
+    pub fn put_io_dup_vals(
+        &self,
+        db: &BytesDatabase,
+        key: &[u8],
         vals: &[impl AsRef<[u8]>],
     ) -> Result<bool, DBError> {
+        Ok(self.put_io_dup_vals_counted(db, key, vals)? > 0)
+    }
+
+    /// Same as `put_io_dup_vals` but returns how many of vals were written
+    ///
+    /// # Parameters
+    /// - `db`: Opened named sub db with dupsort=True
+    /// - `key`: bytes of key within sub db's keyspace
+    /// - `vals`: list of bytes of values to be written
+    ///
+    /// # Returns
+    /// - `Ok(usize)`: Number of values written, 0 when all were already dups
+    /// - `Err(DBError)`: If a database error occurs
+    pub fn put_io_dup_vals_counted(
+        &self,
+        db: &BytesDatabase,
+        key: &[u8],
+        vals: &[impl AsRef<[u8]>],
+    ) -> Result<usize, DBError> {
         let env = self.env.as_ref().ok_or(DBError::DbClosed)?;
 
         // Get preexisting dups if any
@@ -2033,7 +2892,7 @@ impl LMDBer {
 
         // Create a write transaction
         let mut wtxn = env.write_txn()?;
-        let mut result = false;
+        let mut count = 0;
 
         // Find the current highest index
         let mut idx = 0;
@@ -2100,14 +2959,14 @@ impl LMDBer {
                 db.put(&mut wtxn, key, &val_with_proem)?;
 
                 idx += 1;
-                result = true;
+                count += 1;
             }
         }
 
         // Commit the transaction
         wtxn.commit()?;
 
-        Ok(result)
+        Ok(count)
     }
 
     /// Add val bytes as dup in insertion order to key in db
@@ -2176,7 +3035,7 @@ impl LMDBer {
                     // Make sure we only process exact key matches
                     if k == key {
                         // Skip values that are too short (must be at least 33 bytes for the proem)
-                        if val.len() > 33 {
+                        if val.len() >= 33 {
                             // Call the callback with the value with the proem removed
                             if !callback(&val[33..])? {
                                 // If callback returns false, stop iteration
@@ -2362,7 +3221,7 @@ impl LMDBer {
                         // Make sure we only process exact key matches
                         if k == key {
                             // Skip values that are too short (must be at least 33 bytes for the proem)
-                            if proval.len() > 33 {
+                            if proval.len() >= 33 {
                                 // Compare the value without the proem
                                 if &proval[33..] == val {
                                     // Found the value to delete
@@ -2462,7 +3321,7 @@ impl LMDBer {
                     // Make sure we only process exact key matches
                     if k == key {
                         // Skip values that are too short (must be at least 33 bytes for the proem)
-                        if val.len() > 33 {
+                        if val.len() >= 33 {
                             // Store this value (with proem removed)
                             last_val = Some(Vec::from(&val[33..]));
                         }
@@ -2843,7 +3702,7 @@ impl LMDBer {
 
                         if let Some(val) = last_value {
                             // Process the value
-                            let val_without_proem = if val.len() > 33 {
+                            let val_without_proem = if val.len() >= 33 {
                                 val[33..].to_vec()
                             } else {
                                 val
@@ -2900,7 +3759,7 @@ impl LMDBer {
 
                 if let Some(val) = last_value {
                     // Process the value
-                    let val_without_proem = if val.len() > 33 {
+                    let val_without_proem = if val.len() >= 33 {
                         val[33..].to_vec()
                     } else {
                         val
@@ -3170,6 +4029,320 @@ impl Drop for LMDBer {
     }
 }
 
+/// Snapshot is a consistent read view of an LMDBer environment
+///
+/// Holds one read transaction that every getter borrows so a sequence of reads
+/// cannot observe a concurrent write part way through. Dropping the snapshot
+/// aborts the read transaction.
+pub struct Snapshot<'a> {
+    txn: RoTxn<'a, WithoutTls>,
+}
+
+impl Snapshot<'_> {
+    /// Get the value at key in db as of the snapshot
+    pub fn get_val(&self, db: &BytesDatabase, key: &[u8]) -> Result<Option<Vec<u8>>, DBError> {
+        Ok(db.get(&self.txn, key)?.map(|val| val.to_vec()))
+    }
+
+    /// Get the value at onkey consisting of key + sep + serialized on in db as of
+    /// the snapshot
+    ///
+    /// # Parameters
+    /// - db: named sub db of lmdb
+    /// - key: key within sub db's keyspace
+    /// - on: ordinal number at which to retrieve
+    /// - sep: separator bytes for split
+    pub fn get_on_val(
+        &self,
+        db: &BytesDatabase,
+        key: &[u8],
+        on: u64,
+        sep: Option<[u8; 1]>,
+    ) -> Result<Option<Vec<u8>>, DBError> {
+        let onkey = if !key.is_empty() {
+            on_key(key, on, Some(sep.unwrap_or(*b".")))
+        } else {
+            key.to_vec()
+        };
+
+        match db.get(&self.txn, &onkey) {
+            Ok(val) => Ok(val.map(|val| val.to_vec())),
+            Err(heed::Error::Mdb(heed::MdbError::BadValSize)) => Err(DBError::ValueError(format!(
+                "Key: `{:?}` is either empty, too big, or wrong DUPFIXED size",
+                onkey
+            ))),
+            Err(e) => Err(DBError::DatabaseError(format!("{}", e))),
+        }
+    }
+
+    /// Get the insertion ordered io dup vals at key in db as of the snapshot with
+    /// their proems stripped
+    /// Assumes DB opened with dupsort=True
+    pub fn get_io_dup_vals(&self, db: &BytesDatabase, key: &[u8]) -> Result<Vec<Vec<u8>>, DBError> {
+        let mut vals = Vec::new();
+        for res in db.prefix_iter(&self.txn, key)? {
+            let (k, val) = res.map_err(|_| {
+                DBError::KeyError(format!(
+                    "Key: `{:?}` is either empty, too big (for lmdb), or wrong DUPFIXED size.",
+                    key
+                ))
+            })?;
+            if k == key && val.len() >= 33 {
+                vals.push(val[33..].to_vec());
+            }
+        }
+
+        Ok(vals)
+    }
+}
+
+/// OnItemIter streams the (key, on, val) triples of an ordinal keyed sub db
+///
+/// Owns a read transaction for its whole life so every item comes from one
+/// consistent view. Each step first looks for a later duplicate at the last key
+/// seen and then reopens the range just past that key so no cursor has to borrow
+/// from the transaction it lives beside.
+pub struct OnItemIter {
+    txn: RoTxn<'static, WithoutTls>,
+    db: BytesDatabase,
+    key: Vec<u8>,
+    sep: [u8; 1],
+    start: Bound<Vec<u8>>,
+    last: Option<(Vec<u8>, Vec<u8>)>,
+    done: bool,
+}
+
+impl OnItemIter {
+    /// Fetch the next duplicate at the last key seen, else the first raw entry at
+    /// or after the current start bound
+    fn fetch(&self) -> Result<Option<(Vec<u8>, Vec<u8>)>, DBError> {
+        if let Some((lkey, lval)) = &self.last {
+            if let Some(dups) = self.db.get_duplicates(&self.txn, lkey)? {
+                for dup in dups {
+                    let dval = match dup {
+                        Ok((_, dval)) => dval,
+                        // A db opened without dupsort has no duplicates to step through
+                        Err(heed::Error::Mdb(heed::MdbError::Incompatible)) => break,
+                        Err(e) => return Err(e.into()),
+                    };
+                    if dval > lval.as_slice() {
+                        return Ok(Some((lkey.clone(), dval.to_vec())));
+                    }
+                }
+            }
+        }
+
+        let start = match &self.start {
+            Bound::Included(k) => Bound::Included(k.as_slice()),
+            Bound::Excluded(k) => Bound::Excluded(k.as_slice()),
+            Bound::Unbounded => Bound::Unbounded,
+        };
+        let range = (start, Bound::Unbounded);
+        match self.db.range(&self.txn, &range)?.next() {
+            Some(result) => {
+                let (ckey, cval) = result?;
+                Ok(Some((ckey.to_vec(), cval.to_vec())))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+impl Iterator for OnItemIter {
+    type Item = Result<(Vec<u8>, u64, Vec<u8>), DBError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let (ckey, cval) = match self.fetch() {
+            Ok(Some(entry)) => entry,
+            Ok(None) => {
+                self.done = true;
+                return None;
+            }
+            Err(e) => {
+                self.done = true;
+                return Some(Err(e));
+            }
+        };
+
+        // Split the key to get the base part and the ordinal number
+        let (ckey_base, cn) = match split_on_key(&ckey, Some(self.sep)) {
+            Ok(split) => split,
+            Err(e) => {
+                self.done = true;
+                return Some(Err(e));
+            }
+        };
+
+        // Moved past the entries for key
+        if !self.key.is_empty() && ckey_base != self.key {
+            self.done = true;
+            return None;
+        }
+
+        self.start = Bound::Excluded(ckey.clone());
+        self.last = Some((ckey, cval.clone()));
+        Some(Ok((ckey_base, cn, cval)))
+    }
+}
+
+/// Returns the ordinal of the last onkey at key in db as seen by txn, None if
+/// there is no entry at key
+fn last_on(
+    txn: &RwTxn,
+    db: &BytesDatabase,
+    key: &[u8],
+    sep: [u8; 1],
+) -> Result<Option<u64>, DBError> {
+    // Scan backwards from the highest possible ordinal for the last entry
+    let first = on_key(key, 0, Some(sep));
+    let last = on_key(key, MAX_ON, Some(sep));
+    let range = (
+        Bound::Included(first.as_slice()),
+        Bound::Included(last.as_slice()),
+    );
+    for result in db.rev_range(txn, &range)? {
+        let (onkey, _) = result?;
+        let (ckey, cn) = split_on_key(onkey, Some(sep))?;
+        if ckey == key {
+            return Ok(Some(cn));
+        }
+    }
+
+    Ok(None)
+}
+
+/// CrossDbWriter writes to any sub db of an LMDBer within one write transaction
+///
+/// Covers plain, dupsort, insertion ordered dupsort and ordinal keyed sub dbs.
+/// Only handed out by `LMDBer::cross_db_batch`, which commits or aborts the
+/// transaction as a whole once the batch closure returns.
+pub struct CrossDbWriter<'a> {
+    txn: RwTxn<'a>,
+}
+
+impl CrossDbWriter<'_> {
+    /// Get the value at key in db including writes made earlier in the batch
+    pub fn get_val(&self, db: &BytesDatabase, key: &[u8]) -> Result<Option<Vec<u8>>, DBError> {
+        Ok(db.get(&self.txn, key)?.map(|val| val.to_vec()))
+    }
+
+    /// Write val at key in db without overwriting, returns false if key exists
+    pub fn put_val(&mut self, db: &BytesDatabase, key: &[u8], val: &[u8]) -> Result<bool, DBError> {
+        if db.get(&self.txn, key)?.is_some() {
+            return Ok(false);
+        }
+        db.put(&mut self.txn, key, val)?;
+
+        Ok(true)
+    }
+
+    /// Write val at key in db overwriting any existing value, on a dupsort db
+    /// val is added as a dup unless already present
+    pub fn set_val(&mut self, db: &BytesDatabase, key: &[u8], val: &[u8]) -> Result<bool, DBError> {
+        db.put(&mut self.txn, key, val)?;
+
+        Ok(true)
+    }
+
+    /// Delete key from db, returns false if key did not exist
+    pub fn del_val(&mut self, db: &BytesDatabase, key: &[u8]) -> Result<bool, DBError> {
+        Ok(db.delete(&mut self.txn, key)?)
+    }
+
+    /// Add val as a dup at key in dupsort db, returns false if val is already a dup
+    pub fn add_val(&mut self, db: &BytesDatabase, key: &[u8], val: &[u8]) -> Result<bool, DBError> {
+        match db.put_with_flags(&mut self.txn, PutFlags::NO_DUP_DATA, key, val) {
+            Ok(_) => Ok(true),
+            Err(heed::Error::Mdb(heed::MdbError::KeyExist)) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Delete the dup equal to val at key in dupsort db or all dups when val is
+    /// None, returns false if nothing was deleted
+    pub fn del_vals(
+        &mut self,
+        db: &BytesDatabase,
+        key: &[u8],
+        val: Option<&[u8]>,
+    ) -> Result<bool, DBError> {
+        match val {
+            Some(val) => Ok(db.delete_one_duplicate(&mut self.txn, key, val)?),
+            None => Ok(db.delete(&mut self.txn, key)?),
+        }
+    }
+
+    /// Add val in insertion order at key in dupsort db behind the same hidden
+    /// ordinal proem as `LMDBer::add_io_dup_val`, returns false if val is
+    /// already a dup
+    pub fn add_io_dup_val(
+        &mut self,
+        db: &BytesDatabase,
+        key: &[u8],
+        val: &[u8],
+    ) -> Result<bool, DBError> {
+        let mut idx = 0;
+        if let Some(dups) = db.get_duplicates(&self.txn, key)? {
+            for dup in dups {
+                let (_, dup) = dup?;
+                if dup.len() >= 33 && &dup[33..] == val {
+                    return Ok(false);
+                }
+                if let Some(last) = std::str::from_utf8(&dup[..32.min(dup.len())])
+                    .ok()
+                    .and_then(|hex| u64::from_str_radix(hex, 16).ok())
+                {
+                    idx = last + 1;
+                }
+            }
+        }
+
+        let mut proval = format!("{:032x}.", idx).into_bytes();
+        proval.extend_from_slice(val);
+        db.put(&mut self.txn, key, &proval)?;
+
+        Ok(true)
+    }
+
+    /// Write val at the onkey of key and on without overwriting, returns false
+    /// if the onkey exists
+    pub fn put_on_val(
+        &mut self,
+        db: &BytesDatabase,
+        key: &[u8],
+        on: u64,
+        val: &[u8],
+    ) -> Result<bool, DBError> {
+        self.put_val(db, &on_key(key, on, None), val)
+    }
+
+    /// Append val at the ordinal after the last onkey of key, returns that ordinal
+    pub fn append_on_val(
+        &mut self,
+        db: &BytesDatabase,
+        key: &[u8],
+        val: &[u8],
+    ) -> Result<u64, DBError> {
+        let on = match last_on(&self.txn, db, key, *b".")? {
+            Some(MAX_ON) => {
+                return Err(DBError::ValueError(format!(
+                    "Number part on={} for key part key={:?} exceeds maximum size.",
+                    MAX_ON, key
+                )))
+            }
+            Some(last) => last + 1,
+            None => 0,
+        };
+        db.put(&mut self.txn, &on_key(key, on, None), val)?;
+
+        Ok(on)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3322,133 +4495,1024 @@ mod tests {
     }
 
     #[test]
-    fn test_get_top_item_iter_and_del_top_val() -> Result<(), DBError> {
-        // Create a new LMDBer instance with a temporary database
-        let mut lmdber = LMDBer::new(
-            "test_db", "", true,  // temp
-            None,  // head dir path
-            None,  // perm
-            true,  // reopen
-            true,  // clear
-            false, // reuse
-            false, // clean
-            false, // filed
-            false, // extensioned
-            None,  // mode
-            None,  // fext
-            false, // readonly
-        )?;
+    fn test_get_top_item_iter_and_del_top_val() -> Result<(), DBError> {
+        // Create a new LMDBer instance with a temporary database
+        let mut lmdber = LMDBer::new(
+            "test_db", "", true,  // temp
+            None,  // head dir path
+            None,  // perm
+            true,  // reopen
+            true,  // clear
+            false, // reuse
+            false, // clean
+            false, // filed
+            false, // extensioned
+            None,  // mode
+            None,  // fext
+            false, // readonly
+        )?;
+
+        // Create a test database
+        let db = lmdber
+            .create_database(Some("test_db"), None)
+            .expect("Failed to open database");
+
+        // Insert test values
+        let key = b"a.1".to_vec();
+        let val = b"wow".to_vec();
+        assert!(lmdber.put_val(&db, &key, &val)?);
+
+        let key = b"a.2".to_vec();
+        let val = b"wee".to_vec();
+        assert!(lmdber.put_val(&db, &key, &val)?);
+
+        let key = b"b.1".to_vec();
+        let val = b"woo".to_vec();
+        assert!(lmdber.put_val(&db, &key, &val)?);
+
+        // Test get_top_item_iter to retrieve all items
+        let mut items = Vec::new();
+        lmdber.get_top_items_iter(&db, b"", |k, v| {
+            items.push((k.to_vec(), v.to_vec()));
+            Ok(true)
+        })?;
+
+        assert_eq!(
+            items,
+            vec![
+                (b"a.1".to_vec(), b"wow".to_vec()),
+                (b"a.2".to_vec(), b"wee".to_vec()),
+                (b"b.1".to_vec(), b"woo".to_vec()),
+            ]
+        );
+
+        // Test deleting values with a specific prefix
+        assert!(lmdber.del_top_val(&db, b"a.")?);
+
+        // Test get_top_item_iter after deletion
+        let mut items = Vec::new();
+        lmdber.get_top_items_iter(&db, b"", |k, v| {
+            items.push((k.to_vec(), v.to_vec()));
+            Ok(true)
+        })?;
+
+        assert_eq!(items, vec![(b"b.1".to_vec(), b"woo".to_vec()),]);
+
+        // Clean up
+        lmdber.close(true)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_top_items_back_iter() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder().temp(true).name("test_back").build()?;
+        let db = lmdber.create_database(Some("top."), None)?;
+
+        assert!(lmdber.put_val(&db, b"a.1", b"wow")?);
+        assert!(lmdber.put_val(&db, b"a.2", b"wee")?);
+        assert!(lmdber.put_val(&db, b"a.3", b"wam")?);
+        assert!(lmdber.put_val(&db, b"b.1", b"woo")?);
+
+        let mut keys = Vec::new();
+        let count = lmdber.get_top_items_back_iter(&db, b"a.", |k, _| {
+            keys.push(k.to_vec());
+            Ok(true)
+        })?;
+        assert_eq!(count, 3);
+        assert_eq!(
+            keys,
+            vec![b"a.3".to_vec(), b"a.2".to_vec(), b"a.1".to_vec()]
+        );
+
+        // Stopping after the first item yields only the latest entry
+        let mut latest = Vec::new();
+        lmdber.get_top_items_back_iter(&db, b"a.", |k, v| {
+            latest.push((k.to_vec(), v.to_vec()));
+            Ok(false)
+        })?;
+        assert_eq!(latest, vec![(b"a.3".to_vec(), b"wam".to_vec())]);
+
+        let mut none = 0;
+        lmdber.get_top_items_back_iter(&db, b"c.", |_, _| {
+            none += 1;
+            Ok(true)
+        })?;
+        assert_eq!(none, 0);
+
+        // Empty prefix walks the whole db backwards
+        let mut all = Vec::new();
+        lmdber.get_top_items_back_iter(&db, b"", |k, _| {
+            all.push(k.to_vec());
+            Ok(true)
+        })?;
+        assert_eq!(all.first(), Some(&b"b.1".to_vec()));
+        assert_eq!(all.len(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cnt() -> Result<(), DBError> {
+        // Create a temporary directory for the database
+        // Create a new LMDBer instance with a temporary database
+        let mut lmdber = LMDBer::new(
+            "test_db", "", true,  // temp
+            None,  // head_dir_path
+            None,  // perm
+            true,  // reopen
+            true,  // clear
+            false, // reuse
+            false, // clean
+            false, // filed
+            false, // extensioned
+            None,  // mode
+            None,  // fext
+            false, // readonly
+        )?;
+
+        // Create a test database with dupsort flag
+        let db = lmdber
+            .create_database(Some("test_db"), Some(true))
+            .expect("Failed to open database");
+
+        // Test empty database count
+        assert_eq!(lmdber.cnt(&db)?, 0);
+
+        // Insert test values
+        let key0 = b"key0".to_vec();
+        let key1 = b"key1".to_vec();
+        let val1 = b"val1".to_vec();
+        let val2 = b"val2".to_vec();
+
+        assert!(lmdber.put_val(&db, &key0, &val1)?);
+        assert!(lmdber.put_val(&db, &key1, &val2)?); // With DUP_SORT, we can have multiple values for the same key
+
+        // Test count after insertion
+        assert_eq!(lmdber.cnt(&db)?, 2);
+
+        // Add more items
+        let key2 = b"key2".to_vec();
+        let val3 = b"val3".to_vec();
+        assert!(lmdber.put_val(&db, &key2, &val3)?);
+
+        // Test count again
+        assert_eq!(lmdber.cnt(&db)?, 3);
+
+        // Delete items
+        assert!(lmdber.del_val(&db, &key1)?);
+
+        // Test count after deletion
+        assert_eq!(lmdber.cnt(&db)?, 2);
+
+        // Clean up
+        lmdber.close(true)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_put_vals_batch() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder()
+            .temp(true)
+            .name("test_put_vals_batch")
+            .no_sync(true)
+            .build()?;
+        let batch = lmdber.create_database(Some("batch."), Some(false))?;
+        let single = lmdber.create_database(Some("single."), Some(false))?;
+
+        let keys: Vec<Vec<u8>> = (0..300)
+            .map(|i| format!("key.{:05}", i).into_bytes())
+            .collect();
+        let vals: Vec<Vec<u8>> = (0..300).map(|i| format!("val{}", i).into_bytes()).collect();
+        let items: Vec<(&[u8], &[u8])> = keys
+            .iter()
+            .zip(vals.iter())
+            .map(|(k, v)| (k.as_slice(), v.as_slice()))
+            .collect();
+
+        assert_eq!(lmdber.put_vals_batch(&batch, &items)?, 300);
+        let mut count = 0;
+        for (key, val) in items.iter() {
+            if lmdber.put_val(&single, key, val)? {
+                count += 1;
+            }
+        }
+        assert_eq!(count, 300);
+        assert_eq!(lmdber.cnt(&batch)?, lmdber.cnt(&single)?);
+        assert_eq!(
+            lmdber.get_val(&batch, b"key.00123")?,
+            Some(b"val123".to_vec())
+        );
+
+        // Existing keys and repeats within a batch are not overwritten
+        let items: [(&[u8], &[u8]); 3] = [
+            (b"key.00000", b"new"),
+            (b"key.00300", b"val300"),
+            (b"key.00300", b"new"),
+        ];
+        assert_eq!(lmdber.put_vals_batch(&batch, &items)?, 1);
+        assert_eq!(
+            lmdber.get_val(&batch, b"key.00000")?,
+            Some(b"val0".to_vec())
+        );
+        assert_eq!(
+            lmdber.get_val(&batch, b"key.00300")?,
+            Some(b"val300".to_vec())
+        );
+        assert_eq!(lmdber.cnt(&batch)?, 301);
+
+        // A failing put rolls back the whole batch
+        let items: [(&[u8], &[u8]); 2] = [(b"key.00301", b"val301"), (b"", b"empty")];
+        assert!(lmdber.put_vals_batch(&batch, &items).is_err());
+        assert_eq!(lmdber.get_val(&batch, b"key.00301")?, None);
+        assert_eq!(lmdber.cnt(&batch)?, 301);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshot() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder().temp(true).name("test_snapshot").build()?;
+        let db = lmdber.create_database(Some("vals."), Some(false))?;
+        let ddb = lmdber.create_database(Some("dups."), Some(true))?;
+
+        assert!(lmdber.set_val(&db, b"a", b"1")?);
+        assert!(lmdber.put_on_val(&db, b"b", 0, b"b0", None)?);
+        assert!(lmdber.add_io_dup_val(&ddb, b"c", b"z")?);
+        assert!(lmdber.add_io_dup_val(&ddb, b"c", b"y")?);
+
+        let snapshot = lmdber.snapshot()?;
+
+        // Writes after the snapshot opened are not visible through it
+        assert!(lmdber.set_val(&db, b"a", b"2")?);
+        assert!(lmdber.set_val(&db, &on_key(b"b", 0, None), b"b1")?);
+        assert_eq!(snapshot.get_val(&db, b"a")?, Some(b"1".to_vec()));
+        assert_eq!(snapshot.get_val(&db, b"x")?, None);
+        assert_eq!(
+            snapshot.get_on_val(&db, b"b", 0, None)?,
+            Some(b"b0".to_vec())
+        );
+        assert_eq!(snapshot.get_on_val(&db, b"b", 1, None)?, None);
+        assert_eq!(
+            snapshot.get_io_dup_vals(&ddb, b"c")?,
+            vec![b"z".to_vec(), b"y".to_vec()]
+        );
+
+        // Plain getters and a second snapshot on this thread run alongside it
+        assert_eq!(lmdber.get_val(&db, b"a")?, Some(b"2".to_vec()));
+        assert_eq!(
+            lmdber.get_io_dup_vals(&ddb, b"c")?,
+            vec![b"z".to_vec(), b"y".to_vec()]
+        );
+        let later = lmdber.snapshot()?;
+        assert_eq!(later.get_val(&db, b"a")?, Some(b"2".to_vec()));
+        assert_eq!(snapshot.get_val(&db, b"a")?, Some(b"1".to_vec()));
+        drop(later);
+        drop(snapshot);
+
+        assert_eq!(lmdber.get_val(&db, b"a")?, Some(b"2".to_vec()));
+        assert_eq!(lmdber.get_on_val(&db, b"b", 0, None)?, Some(b"b1".to_vec()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cross_db_batch() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder().temp(true).name("test_cross_db").build()?;
+        let evts = lmdber.create_database(Some("evts."), None)?;
+        let sigs = lmdber.create_database(Some("sigs."), None)?;
+        let fels = lmdber.create_database(Some("fels."), None)?;
+
+        let written = lmdber.cross_db_batch(|writer| {
+            writer.put_val(&evts, b"a", b"evt")?;
+            writer.put_val(&sigs, b"a", b"sig")?;
+            writer.put_val(&fels, b"a", b"fel")?;
+            writer.get_val(&evts, b"a")
+        })?;
+        assert_eq!(written, Some(b"evt".to_vec()));
+        assert_eq!(lmdber.get_val(&evts, b"a")?, Some(b"evt".to_vec()));
+        assert_eq!(lmdber.get_val(&sigs, b"a")?, Some(b"sig".to_vec()));
+        assert_eq!(lmdber.get_val(&fels, b"a")?, Some(b"fel".to_vec()));
+
+        // Failing part way through leaves all three untouched
+        let result: Result<(), DBError> = lmdber.cross_db_batch(|writer| {
+            writer.set_val(&evts, b"a", b"evt2")?;
+            writer.put_val(&sigs, b"b", b"sig2")?;
+            writer.del_val(&fels, b"a")?;
+            Err(DBError::ValueError("abort".to_string()))
+        });
+        assert!(matches!(result, Err(DBError::ValueError(_))));
+        assert_eq!(lmdber.get_val(&evts, b"a")?, Some(b"evt".to_vec()));
+        assert_eq!(lmdber.get_val(&sigs, b"b")?, None);
+        assert_eq!(lmdber.get_val(&fels, b"a")?, Some(b"fel".to_vec()));
+
+        // Dup, insertion ordered dup and ordinal writes share the batch
+        let dups = lmdber.create_database(Some("dups."), Some(true))?;
+        let iods = lmdber.create_database(Some("iods."), Some(true))?;
+        let ons = lmdber.create_database(Some("ons."), None)?;
+        lmdber.cross_db_batch(|writer| {
+            assert!(writer.add_val(&dups, b"a", b"z")?);
+            assert!(writer.add_val(&dups, b"a", b"y")?);
+            assert!(!writer.add_val(&dups, b"a", b"z")?);
+            assert!(writer.add_io_dup_val(&iods, b"a", b"z")?);
+            assert!(writer.add_io_dup_val(&iods, b"a", b"y")?);
+            assert!(!writer.add_io_dup_val(&iods, b"a", b"z")?);
+            assert_eq!(writer.append_on_val(&ons, b"a", b"v0")?, 0);
+            assert_eq!(writer.append_on_val(&ons, b"a", b"v1")?, 1);
+            assert!(writer.put_on_val(&ons, b"a", 5, b"v5")?);
+            assert!(!writer.put_on_val(&ons, b"a", 5, b"v6")?);
+            assert_eq!(writer.append_on_val(&ons, b"a", b"v6")?, 6);
+            Ok(())
+        })?;
+        let vals = vec![b"y".to_vec(), b"z".to_vec()];
+        assert_eq!(lmdber.get_vals(&dups, b"a")?, vals);
+        assert_eq!(
+            lmdber.get_io_dup_vals(&iods, b"a")?,
+            vec![b"z".to_vec(), b"y".to_vec()]
+        );
+        assert_eq!(lmdber.append_on_val(&ons, b"a", b"v7", None)?, 7);
+
+        let result: Result<(), DBError> = lmdber.cross_db_batch(|writer| {
+            writer.del_vals(&dups, b"a", Some(b"y"))?;
+            writer.add_io_dup_val(&iods, b"a", b"x")?;
+            writer.append_on_val(&ons, b"a", b"v8")?;
+            Err(DBError::ValueError("abort".to_string()))
+        });
+        assert!(result.is_err());
+        assert_eq!(lmdber.cnt_vals(&dups, b"a")?, 2);
+        assert_eq!(lmdber.cnt_io_dup_vals(&iods, b"a")?, 2);
+        assert_eq!(lmdber.get_on_val(&ons, b"a", 8, None)?, None);
+
+        // set_val on a dupsort db adds a missing dup and leaves an existing one
+        lmdber.cross_db_batch(|writer| {
+            writer.set_val(&dups, b"a", b"z")?;
+            writer.set_val(&dups, b"a", b"x")
+        })?;
+        assert_eq!(lmdber.cnt_vals(&dups, b"a")?, 3);
+
+        let mut names = lmdber.sub_db_names()?;
+        names.sort();
+        assert_eq!(names, vec!["dups.", "evts.", "fels.", "iods.", "ons.", "sigs."]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cnt_dupsort() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder()
+            .temp(true)
+            .name("test_cnt_dupsort")
+            .build()?;
+        let db = lmdber.create_database(Some("plain."), Some(false))?;
+        let ddb = lmdber.create_database(Some("dups."), Some(true))?;
+
+        for i in 0..100 {
+            let key = format!("key.{:03}", i).into_bytes();
+            assert!(lmdber.put_val(&db, &key, b"val")?);
+            // Repeat put is not distinct so is not counted
+            assert!(!lmdber.put_val(&db, &key, b"other")?);
+
+            let vals = [b"a".to_vec(), b"b".to_vec(), b"c".to_vec()];
+            assert_eq!(lmdber.put_vals(&ddb, &key, &vals)?, 3);
+            assert!(!lmdber.add_val(&ddb, &key, b"a")?);
+        }
+
+        assert_eq!(lmdber.cnt(&db)?, 100);
+        assert_eq!(lmdber.cnt(&ddb)?, 300);
+
+        let env = lmdber.env().unwrap();
+        let rtxn = env.read_txn()?;
+        assert_eq!(ddb.iter(&rtxn)?.count(), 300);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_del_top_val_bulk() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder().temp(true).name("test_del_top").build()?;
+        let db = lmdber.create_database(Some("top."), None)?;
+
+        let items: Vec<(String, String)> = (0..1000u32)
+            .map(|i| (format!("pre.{:04}", i), format!("val{}", i)))
+            .collect();
+        let pairs: Vec<(&[u8], &[u8])> = items
+            .iter()
+            .map(|(k, v)| (k.as_bytes(), v.as_bytes()))
+            .collect();
+        assert_eq!(lmdber.put_vals_batch(&db, &pairs)?, 1000);
+        assert!(lmdber.put_val(&db, b"prf.0000", b"keep")?);
+        assert!(lmdber.put_val(&db, b"pre", b"keep")?);
+
+        assert!(lmdber.del_top_val(&db, b"pre.")?);
+        assert_eq!(lmdber.cnt(&db)?, 2);
+        assert_eq!(lmdber.get_val(&db, b"pre.0999")?, None);
+        assert_eq!(lmdber.get_val(&db, b"prf.0000")?, Some(b"keep".to_vec()));
+        assert_eq!(lmdber.get_val(&db, b"pre")?, Some(b"keep".to_vec()));
+
+        // Nothing left under the prefix
+        assert!(!lmdber.del_top_val(&db, b"pre.")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clear_database() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder().temp(true).name("test_clear_db").build()?;
+        let db = lmdber.create_database(Some("clear."), None)?;
+        let other = lmdber.create_database(Some("other."), None)?;
+
+        for i in 0..100u32 {
+            let key = format!("key.{:04}", i);
+            lmdber.put_val(&db, key.as_bytes(), b"val")?;
+        }
+        lmdber.put_val(&other, b"key", b"val")?;
+        assert_eq!(lmdber.cnt(&db)?, 100);
+
+        lmdber.clear_database(&db)?;
+        assert_eq!(lmdber.cnt(&db)?, 0);
+        assert_eq!(lmdber.cnt(&other)?, 1);
+
+        // Handle still works after the clear
+        assert!(lmdber.put_val(&db, b"key.0000", b"new")?);
+        assert_eq!(lmdber.get_val(&db, b"key.0000")?, Some(b"new".to_vec()));
+        assert_eq!(lmdber.cnt(&db)?, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_readonly() -> Result<(), DBError> {
+        let dir = tempfile::tempdir().map_err(|e| DBError::IoError(e.to_string()))?;
+        let open = |readonly: bool| {
+            LMDBer::new(
+                "test_readonly",
+                "",
+                false,                          // temp
+                Some(dir.path().to_path_buf()), // head_dir_path
+                None,                           // perm
+                true,                           // reopen
+                false,                          // clear
+                true,                           // reuse
+                false,                          // clean
+                false,                          // filed
+                false,                          // extensioned
+                None,                           // mode
+                None,                           // fext
+                readonly,
+            )
+        };
+
+        let mut lmdber = open(false)?;
+        let db = lmdber.create_database(Some("vals."), Some(false))?;
+        assert!(lmdber.put_val(&db, b"a", b"1")?);
+        lmdber.close(false)?;
+
+        let mut lmdber = open(true)?;
+        assert!(lmdber.readonly);
+        let db = lmdber.open_database(Some("vals."))?.unwrap();
+        assert_eq!(lmdber.get_val(&db, b"a")?, Some(b"1".to_vec()));
+        assert!(lmdber.put_val(&db, b"b", b"2").is_err());
+        assert!(lmdber.set_val(&db, b"a", b"2").is_err());
+        assert_eq!(lmdber.get_val(&db, b"b")?, None);
+        lmdber.close(false)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_map_size() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder()
+            .temp(true)
+            .name("test_builder_defaults")
+            .build()?;
+        assert_eq!(lmdber.map_size(), LMDBer::MAP_SIZE);
+        assert_eq!(lmdber.max_dbs(), LMDBer::MAX_NAMED_DBS);
+
+        let lmdber = LMDBer::builder()
+            .temp(true)
+            .name("test_builder_map_size")
+            .map_size(1 << 20)
+            .max_dbs(2)
+            .no_sync(true)
+            .build()?;
+        assert_eq!(lmdber.map_size(), 1 << 20);
+        assert_eq!(lmdber.max_dbs(), 2);
+        let info = lmdber.env().unwrap().info();
+        assert_eq!(info.map_size, 1 << 20);
+
+        // Writes past the configured map size fail with MDB_MAP_FULL
+        let db = lmdber.create_database(Some("big."), None)?;
+        let val = vec![0u8; 64 << 10];
+        let mut puts = 0u32;
+        while puts < 32 {
+            let key = format!("key.{:04}", puts);
+            if lmdber.put_val(&db, key.as_bytes(), &val).is_err() {
+                break;
+            }
+            puts += 1;
+        }
+        assert!(puts > 0 && puts < 16);
+
+        // Only max_dbs named sub dbs may be opened
+        lmdber.create_database(Some("other."), None)?;
+        assert!(lmdber.create_database(Some("third."), None).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    #[ignore]
+    fn test_builder_map_size_large() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder()
+            .temp(true)
+            .name("test_builder_map_size_large")
+            .map_size(2 << 30)
+            .no_sync(true)
+            .build()?;
+
+        // More than the default 100MB fits without MDB_MAP_FULL
+        let db = lmdber.create_database(Some("big."), None)?;
+        let val = vec![0u8; 1 << 20];
+        for i in 0..120u32 {
+            let key = format!("key.{:04}", i);
+            assert!(lmdber.put_val(&db, key.as_bytes(), &val)?);
+        }
+        assert_eq!(lmdber.cnt(&db)?, 120);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sync() -> Result<(), DBError> {
+        let mut lmdber = LMDBer::builder().temp(true).name("test_sync").build()?;
+        assert!(!lmdber.no_sync());
+        let db = lmdber.create_database(Some("vals."), None)?;
+        assert!(lmdber.put_val(&db, b"a", b"1")?);
+        lmdber.sync(false)?;
+        lmdber.sync(true)?;
+
+        // Bulk writes without a sync per commit then one explicit flush
+        let mut bulk = LMDBer::builder()
+            .temp(true)
+            .name("test_sync_bulk")
+            .no_sync(true)
+            .build()?;
+        assert!(bulk.no_sync());
+        let db = bulk.create_database(Some("vals."), None)?;
+        for i in 0..100u32 {
+            let key = format!("key.{:04}", i);
+            bulk.put_val(&db, key.as_bytes(), b"val")?;
+        }
+        bulk.sync(false)?;
+        bulk.sync(true)?;
+        assert_eq!(bulk.cnt(&db)?, 100);
+
+        bulk.close(false)?;
+        assert!(matches!(bulk.sync(true), Err(DBError::DbClosed)));
+        lmdber.close(false)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_full_resize() -> Result<(), DBError> {
+        let mut lmdber = LMDBer::builder()
+            .temp(true)
+            .name("test_map_full_resize")
+            .map_size(1 << 18)
+            .build()?;
+        assert_eq!(lmdber.map_size(), 1 << 18);
+
+        // Far more than 256KB of values, so the batch fails with MDB_MAP_FULL
+        let db = lmdber.create_database(Some("full."), None)?;
+        let val = vec![7u8; 1 << 12];
+        let keys: Vec<Vec<u8>> = (0..512u32)
+            .map(|i| format!("batch.{:04}", i).into_bytes())
+            .collect();
+        let items: Vec<(&[u8], &[u8])> = keys
+            .iter()
+            .map(|k| (k.as_slice(), val.as_slice()))
+            .collect();
+        assert!(matches!(
+            lmdber.put_vals_batch(&db, &items),
+            Err(DBError::EnvError(heed::Error::Mdb(heed::MdbError::MapFull)))
+        ));
+        assert_eq!(lmdber.cnt(&db)?, 0);
+        assert_eq!(lmdber.map_size(), 1 << 18);
+
+        // Manual growth never shrinks the map
+        let size = lmdber.map_size();
+        assert!(lmdber.grow_map_size(size / 2).is_err());
+        lmdber.grow_map_size(size * 64)?;
+        assert_eq!(lmdber.map_size(), size * 64);
+        assert_eq!(lmdber.put_vals_batch(&db, &items)?, 512);
+        assert!(lmdber.put_val(&db, b"key.put", &val)?);
+        assert!(lmdber.set_val(&db, b"key.set", &val)?);
+        assert_eq!(lmdber.cnt(&db)?, 514);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_key_after() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder()
+            .temp(true)
+            .name("test_get_key_after")
+            .build()?;
+        let db = lmdber.create_database(Some("seek."), Some(true))?;
+
+        assert_eq!(lmdber.get_key_after(&db, b"")?, None);
+
+        lmdber.put_vals(&db, b"a.1", &[b"x".to_vec(), b"y".to_vec()])?;
+        lmdber.put_vals(&db, b"a.2", &[b"z".to_vec()])?;
+        lmdber.put_vals(&db, b"b.1", &[b"w".to_vec()])?;
+
+        assert_eq!(lmdber.get_key_after(&db, b"")?, Some(b"a.1".to_vec()));
+        // Duplicates of the key itself are skipped
+        assert_eq!(lmdber.get_key_after(&db, b"a.1")?, Some(b"a.2".to_vec()));
+        assert_eq!(lmdber.get_key_after(&db, b"a.~")?, Some(b"b.1".to_vec()));
+        assert_eq!(lmdber.get_key_after(&db, b"b.1")?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_raw_env() -> Result<(), DBError> {
+        let mut lmdber = LMDBer::builder().temp(true).name("test_raw_env").build()?;
+
+        let env = lmdber.raw_env().expect("Env should be open");
+        let rtxn = env.read_txn()?;
+        drop(rtxn);
+        drop(env);
+
+        lmdber.close(true)?;
+        assert!(lmdber.raw_env().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_usage_delta() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder().temp(true).name("test_usage").build()?;
+        let db = lmdber.create_database(Some("usage."), None)?;
+
+        let (count, delta) = lmdber.with_usage_delta(|| {
+            let mut count = 0;
+            for i in 0..1000u32 {
+                let key = format!("key.{:08}", i);
+                if lmdber.put_val(&db, key.as_bytes(), &[0u8; 64])? {
+                    count += 1;
+                }
+            }
+            Ok(count)
+        })?;
+
+        assert_eq!(count, 1000);
+        assert!(delta > 0);
+
+        // Reads do not grow the environment
+        let (_, delta) = lmdber.with_usage_delta(|| lmdber.cnt(&db))?;
+        assert_eq!(delta, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_free_pages() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder().temp(true).name("test_free_pages").build()?;
+        let db = lmdber.create_database(Some("free."), None)?;
+
+        for i in 0..1000u32 {
+            let key = format!("key.{:08}", i);
+            lmdber.put_val(&db, key.as_bytes(), &[0u8; 256])?;
+        }
+        let before = lmdber.free_pages()?;
+
+        for i in 0..1000u32 {
+            let key = format!("key.{:08}", i);
+            lmdber.del_val(&db, key.as_bytes())?;
+        }
+        let after = lmdber.free_pages()?;
+
+        assert!(after > 0);
+        assert!(after > before);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_databases() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder().temp(true).name("test_list_dbs").build()?;
+        assert!(lmdber.list_databases()?.is_empty());
 
-        // Create a test database
-        let db = lmdber
-            .create_database(Some("test_db"), None)
-            .expect("Failed to open database");
+        lmdber.create_database(Some("evts."), None)?;
+        lmdber.create_database(Some("sigs."), Some(true))?;
+        lmdber.create_database(Some("fels."), None)?;
 
-        // Insert test values
-        let key = b"a.1".to_vec();
-        let val = b"wow".to_vec();
-        assert!(lmdber.put_val(&db, &key, &val)?);
+        let names = lmdber.list_databases()?;
+        assert_eq!(names, vec!["evts.", "fels.", "sigs."]);
 
-        let key = b"a.2".to_vec();
-        let val = b"wee".to_vec();
-        assert!(lmdber.put_val(&db, &key, &val)?);
+        Ok(())
+    }
 
-        let key = b"b.1".to_vec();
-        let val = b"woo".to_vec();
-        assert!(lmdber.put_val(&db, &key, &val)?);
+    #[test]
+    fn test_export_ndjson() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder().temp(true).name("test_ndjson").build()?;
+        let db = lmdber.create_database(Some("dump."), None)?;
+
+        lmdber.put_val(&db, b"alpha", b"one")?;
+        lmdber.put_val(&db, &on_key(b"pre", 3, None), b"three")?;
+        lmdber.put_val(&db, b"beta.gamma", &[0u8, 255, 7])?;
+
+        let mut out = Vec::new();
+        assert_eq!(lmdber.export_ndjson(&db, &mut out, None)?, 3);
+
+        let text = String::from_utf8(out).unwrap();
+        let records: Vec<serde_json::Value> = text
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 3);
+
+        let decode = |v: &serde_json::Value| general_purpose::STANDARD.decode(v.as_str().unwrap()).unwrap();
+
+        // Entries come out in key order
+        assert_eq!(decode(&records[0]["key"]), b"alpha".to_vec());
+        assert_eq!(decode(&records[0]["val"]), b"one".to_vec());
+        assert!(records[0].get("on").is_none());
+
+        assert_eq!(decode(&records[1]["key"]), b"beta.gamma".to_vec());
+        assert_eq!(decode(&records[1]["val"]), vec![0u8, 255, 7]);
+        assert!(records[1].get("on").is_none());
+
+        assert_eq!(decode(&records[2]["key"]), on_key(b"pre", 3, None));
+        assert_eq!(decode(&records[2]["val"]), b"three".to_vec());
+        assert_eq!(records[2]["on"].as_u64(), Some(3));
+        assert!(records.iter().all(|r| r.get("ion").is_none()));
+
+        // Io dup values carry their insertion ordering proem ordinal
+        let iodb = lmdber.create_database(Some("iodump."), Some(true))?;
+        lmdber.put_io_dup_vals(&iodb, b"pre", &[b"z".as_slice(), b"a".as_slice()])?;
+        let mut out = Vec::new();
+        assert_eq!(lmdber.export_ndjson(&iodb, &mut out, None)?, 2);
+        let text = String::from_utf8(out).unwrap();
+        let records: Vec<serde_json::Value> = text
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(decode(&records[0]["val"])[33..], *b"z");
+        assert_eq!(records[0]["ion"].as_u64(), Some(0));
+        assert_eq!(decode(&records[1]["val"])[33..], *b"a");
+        assert_eq!(records[1]["ion"].as_u64(), Some(1));
+        assert!(records[1].get("on").is_none());
+
+        // Empty db
+        let empty = lmdber.create_database(Some("empty."), None)?;
+        let mut out = Vec::new();
+        assert_eq!(lmdber.export_ndjson(&empty, &mut out, None)?, 0);
+        assert!(out.is_empty());
 
-        // Test get_top_item_iter to retrieve all items
-        let mut items = Vec::new();
-        lmdber.get_top_items_iter(&db, b"", |k, v| {
-            items.push((k.to_vec(), v.to_vec()));
-            Ok(true)
-        })?;
+        Ok(())
+    }
 
+    #[test]
+    fn test_import_ndjson() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder().temp(true).name("test_import_ndjson").build()?;
+        let src = lmdber.create_database(Some("src."), None)?;
+        lmdber.put_val(&src, b"alpha", b"one")?;
+        lmdber.put_val(&src, &on_key(b"pre", 3, None), b"three")?;
+        lmdber.put_val(&src, b"beta.gamma", &[0u8, 255, 7])?;
+        lmdber.put_io_set_vals(&src, b"set", &[b"x".as_slice(), b"y".as_slice()], None)?;
+
+        let mut dump = Vec::new();
+        assert_eq!(lmdber.export_ndjson(&src, &mut dump, None)?, 5);
+
+        // Round trip into an empty sub db gives identical contents
+        let dst = lmdber.create_database(Some("dst."), None)?;
+        assert_eq!(lmdber.import_ndjson(&dst, dump.as_slice())?, 5);
+
+        let mut redump = Vec::new();
+        lmdber.export_ndjson(&dst, &mut redump, None)?;
+        assert_eq!(redump, dump);
         assert_eq!(
-            items,
-            vec![
-                (b"a.1".to_vec(), b"wow".to_vec()),
-                (b"a.2".to_vec(), b"wee".to_vec()),
-                (b"b.1".to_vec(), b"woo".to_vec()),
-            ]
+            lmdber.get_io_set_vals(&dst, b"set", None, None)?,
+            vec![b"x".to_vec(), b"y".to_vec()]
         );
 
-        // Test deleting values with a specific prefix
-        assert!(lmdber.del_top_val(&db, b"a.")?);
+        // Malformed line is reported by number and nothing is written
+        let bad = lmdber.create_database(Some("bad."), None)?;
+        let input = b"{\"key\":\"YQ==\",\"val\":\"Yg==\"}\n\n{\"key\":\"YQ==\"}\n";
+        let result = lmdber.import_ndjson(&bad, input.as_slice());
+        match result {
+            Err(DBError::ParseError(msg)) => assert!(msg.contains("line 3"), "{}", msg),
+            other => panic!("Expected ParseError got {:?}", other),
+        }
+        assert_eq!(lmdber.cnt(&bad)?, 0);
 
-        // Test get_top_item_iter after deletion
-        let mut items = Vec::new();
-        lmdber.get_top_items_iter(&db, b"", |k, v| {
-            items.push((k.to_vec(), v.to_vec()));
-            Ok(true)
-        })?;
+        let input = b"not json\n";
+        let result = lmdber.import_ndjson(&bad, input.as_slice());
+        assert!(matches!(result, Err(DBError::ParseError(msg)) if msg.contains("line 1")));
 
-        assert_eq!(items, vec![(b"b.1".to_vec(), b"woo".to_vec()),]);
+        Ok(())
+    }
 
-        // Clean up
-        lmdber.close(true)?;
+    #[test]
+    fn test_compact_to() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder().temp(true).name("test_compact").build()?;
+        let db = lmdber.create_database(Some("churn."), None)?;
+
+        // Heavy churn leaves most pages free
+        for i in 0..2000u32 {
+            let key = format!("key.{:08}", i);
+            lmdber.put_val(&db, key.as_bytes(), &[i as u8; 256])?;
+        }
+        for i in (0..2000u32).filter(|i| i % 4 != 0) {
+            let key = format!("key.{:08}", i);
+            lmdber.del_val(&db, key.as_bytes())?;
+        }
+
+        let dir = tempfile::tempdir().map_err(|e| DBError::IoError(e.to_string()))?;
+        let dest = dir.path().join("data.mdb");
+        lmdber.compact_to(&dest)?;
+
+        let src = lmdber.path().unwrap().join("data.mdb");
+        let src_size = fs::metadata(&src).map_err(|e| DBError::IoError(e.to_string()))?.len();
+        let dest_size = fs::metadata(&dest).map_err(|e| DBError::IoError(e.to_string()))?.len();
+        assert!(dest_size <= src_size);
+
+        // Same logical contents
+        let env = unsafe {
+            EnvOpenOptions::new()
+                .max_dbs(LMDBer::MAX_NAMED_DBS)
+                .open(dir.path())?
+        };
+        let rtxn = env.read_txn()?;
+        let cdb: BytesDatabase = env.open_database(&rtxn, Some("churn."))?.unwrap();
+        assert_eq!(cdb.len(&rtxn)? as usize, lmdber.cnt(&db)?);
+        for i in (0..2000u32).step_by(4) {
+            let key = format!("key.{:08}", i);
+            assert_eq!(cdb.get(&rtxn, key.as_bytes())?, Some(&[i as u8; 256][..]));
+        }
+        assert_eq!(cdb.get(&rtxn, b"key.00000001")?, None);
 
         Ok(())
     }
 
     #[test]
-    fn test_cnt() -> Result<(), DBError> {
-        // Create a temporary directory for the database
-        // Create a new LMDBer instance with a temporary database
-        let mut lmdber = LMDBer::new(
-            "test_db", "", true,  // temp
-            None,  // head_dir_path
-            None,  // perm
-            true,  // reopen
-            true,  // clear
-            false, // reuse
-            false, // clean
-            false, // filed
-            false, // extensioned
-            None,  // mode
-            None,  // fext
-            false, // readonly
-        )?;
+    fn test_backup() -> Result<(), DBError> {
+        let mut lmdber = LMDBer::builder().temp(true).name("test_backup").build()?;
+        let db = lmdber.create_database(Some("vals."), None)?;
+        for i in 0..100u32 {
+            let key = format!("key.{:04}", i);
+            lmdber.put_val(&db, key.as_bytes(), format!("val{}", i).as_bytes())?;
+        }
 
-        // Create a test database with dupsort flag
-        let db = lmdber
-            .create_database(Some("test_db"), Some(true))
-            .expect("Failed to open database");
+        // Restore location is where an LMDBer under dir expects its data file
+        let dir = tempfile::tempdir().map_err(|e| DBError::IoError(e.to_string()))?;
+        let open = |readonly: bool| {
+            LMDBer::new(
+                "test_backup",
+                "",
+                false,                          // temp
+                Some(dir.path().to_path_buf()), // head_dir_path
+                None,                           // perm
+                true,                           // reopen
+                false,                          // clear
+                true,                           // reuse
+                false,                          // clean
+                false,                          // filed
+                false,                          // extensioned
+                None,                           // mode
+                None,                           // fext
+                readonly,
+            )
+        };
+        let mut restore = open(false)?;
+        let path = restore.path().unwrap();
+        restore.close(false)?;
+        fs::remove_file(path.join("data.mdb")).map_err(|e| DBError::IoError(e.to_string()))?;
+
+        lmdber.backup(&path.join("data.mdb"), false)?;
+
+        let mut restore = open(true)?;
+        let rdb = restore.open_database(Some("vals."))?.unwrap();
+        assert_eq!(restore.cnt(&rdb)?, 100);
+        for i in 0..100u32 {
+            let key = format!("key.{:04}", i);
+            let val = format!("val{}", i).into_bytes();
+            assert_eq!(restore.get_val(&rdb, key.as_bytes())?, Some(val));
+        }
+        restore.close(false)?;
 
-        // Test empty database count
-        assert_eq!(lmdber.cnt(&db)?, 0);
+        // Closed environment cannot be backed up
+        lmdber.close(false)?;
+        let dest = dir.path().join("closed.mdb");
+        assert!(matches!(lmdber.backup(&dest, true), Err(DBError::DbClosed)));
 
-        // Insert test values
-        let key0 = b"key0".to_vec();
-        let key1 = b"key1".to_vec();
-        let val1 = b"val1".to_vec();
-        let val2 = b"val2".to_vec();
+        Ok(())
+    }
 
-        assert!(lmdber.put_val(&db, &key0, &val1)?);
-        assert!(lmdber.put_val(&db, &key1, &val2)?); // With DUP_SORT, we can have multiple values for the same key
+    #[test]
+    fn test_check_io_dup_invariants() -> Result<(), DBError> {
+        let dber = LMDBer::builder()
+            .temp(true)
+            .name("test_io_dup_invariants")
+            .build()?;
+        let db = dber.create_database(Some("peep."), Some(true))?;
 
-        // Test count after insertion
-        assert_eq!(lmdber.cnt(&db)?, 2);
+        // Healthy and empty keys
+        let key = b"A";
+        dber.put_io_dup_vals(&db, key, &[b"z", b"m", b"x", b"a"])?;
+        dber.add_io_dup_val(&db, key, b"b")?;
+        dber.check_io_dup_invariants(&db, key)?;
+        dber.check_io_dup_invariants(&db, b"B")?;
+
+        // Repeated ordinal written around the proem system
+        let key = b"C";
+        let vals = [
+            format!("{:032x}.x", 0),
+            format!("{:032x}.y", 0),
+        ];
+        dber.put_vals(&db, key, &vals)?;
+        let result = dber.check_io_dup_invariants(&db, key);
+        assert!(matches!(result, Err(DBError::ProemDisorder { key }) if key == "C"));
+
+        // Mixed case hex sorts out of insertion order
+        let key = b"D";
+        dber.put_vals(&db, key, &[format!("{:032x}.x", 10), format!("{:032X}.y", 11)])?;
+        assert!(matches!(
+            dber.check_io_dup_invariants(&db, key),
+            Err(DBError::ProemDisorder { .. })
+        ));
+
+        // Missing proem
+        dber.put_vals(&db, b"E", &[b"raw"])?;
+        assert!(dber.check_io_dup_invariants(&db, b"E").is_err());
 
-        // Add more items
-        let key2 = b"key2".to_vec();
-        let val3 = b"val3".to_vec();
-        assert!(lmdber.put_val(&db, &key2, &val3)?);
+        Ok(())
+    }
 
-        // Test count again
-        assert_eq!(lmdber.cnt(&db)?, 3);
+    #[test]
+    fn test_io_dup_empty_val() -> Result<(), DBError> {
+        let dber = LMDBer::builder().temp(true).build()?;
+        let db = dber.create_database(Some("peep."), Some(true))?;
+        let key = b"A";
 
-        // Delete items
-        assert!(lmdber.del_val(&db, &key1)?);
+        // An empty value is stored as just its 33 byte proem
+        assert!(dber.add_io_dup_val(&db, key, b"")?);
+        assert!(dber.add_io_dup_val(&db, key, b"b")?);
+        let expected = vec![Vec::new(), b"b".to_vec()];
+        assert_eq!(dber.get_io_dup_vals(&db, key)?, expected);
 
-        // Test count after deletion
-        assert_eq!(lmdber.cnt(&db)?, 2);
+        let mut iter_vals = Vec::new();
+        dber.get_io_dup_vals_iter(&db, key, |v| {
+            iter_vals.push(v.to_vec());
+            Ok(true)
+        })?;
+        assert_eq!(iter_vals, expected);
 
-        // Clean up
-        lmdber.close(true)?;
+        assert!(dber.del_io_dup_val(&db, key, b"b")?);
+        assert_eq!(dber.get_io_dup_val_last(&db, key)?, Some(Vec::new()));
+        let snapshot = dber.snapshot()?;
+        assert_eq!(snapshot.get_io_dup_vals(&db, key)?, vec![Vec::<u8>::new()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_del_val_exact() -> Result<(), DBError> {
+        let dber = LMDBer::builder().temp(true).build()?;
+        let db = dber.create_database(Some("exact."), Some(true))?;
+        let key = b"A";
+
+        // LMDB only keeps an empty duplicate while it is the sole value at key
+        let empty: [&[u8]; 1] = [b""];
+        assert_eq!(dber.put_vals(&db, key, &empty)?, 1);
+        assert_eq!(dber.cnt_vals(&db, key)?, 1);
+        assert!(dber.del_val_exact(&db, key, b"")?);
+        assert_eq!(dber.cnt_vals(&db, key)?, 0);
+        assert!(!dber.del_val_exact(&db, key, b"")?);
+
+        // Only the matching duplicate is removed
+        let vals: [&[u8]; 3] = [b"a", b"b", b"c"];
+        assert_eq!(dber.put_vals(&db, key, &vals)?, 3);
+        assert!(!dber.del_val_exact(&db, key, b"")?);
+        assert!(dber.del_val_exact(&db, key, b"c")?);
+        assert_eq!(dber.get_vals(&db, key)?, vec![b"a".to_vec(), b"b".to_vec()]);
+
+        assert!(dber.del_val_exact(&db, key, b"b")?);
+        assert_eq!(dber.get_vals(&db, key)?, vec![b"a".to_vec()]);
+        assert!(!dber.del_val_exact(&db, b"B", b"a")?);
+
+        // del_vals with no val still deletes every duplicate
+        assert!(dber.put_vals(&db, key, &vals)? > 0);
+        assert!(dber.del_vals(&db, key, None)?);
+        assert_eq!(dber.cnt_vals(&db, key)?, 0);
 
         Ok(())
     }
+
     #[test]
     fn test_dup_vals_methods() -> Result<(), DBError> {
         // Set up temporary database
@@ -3472,7 +5536,7 @@ mod tests {
         assert_eq!(dber.cnt_vals(&db, key)?, 0);
 
         // Test putting values
-        assert_eq!(dber.put_vals(&db, key, &vals)?, true);
+        assert_eq!(dber.put_vals(&db, key, &vals)?, vals.len());
 
         // Values should be stored in lexicographic order
         let mut retrieved_vals = Vec::new();
@@ -3489,7 +5553,9 @@ mod tests {
         assert_eq!(dber.cnt_vals(&db, key)?, vals.len());
 
         // Test putting a duplicate value - should succeed but not change stored values
-        assert_eq!(dber.put_vals(&db, key, &[b"a"])?, true);
+        assert_eq!(dber.put_vals(&db, key, &[b"a"])?, 0);
+        assert_eq!(dber.put_vals(&db, key, &[b"a", b"c", b"c"])?, 1);
+        assert_eq!(dber.del_vals(&db, key, Some(b"c"))?, true);
 
         let mut retrieved_vals = Vec::new();
         dber.get_vals_iter(&db, key, |v| {
@@ -3520,6 +5586,10 @@ mod tests {
         let iter_vals_refs: Vec<&[u8]> = iter_vals.iter().map(|v| v.as_slice()).collect();
         assert_eq!(iter_vals_refs, [b"a", b"b", b"m", b"x", b"z"]);
 
+        // get_vals collects the same values in the same order
+        assert_eq!(dber.get_vals(&db, key)?, iter_vals);
+        assert!(dber.get_vals(&db, b"missing")?.is_empty());
+
         // Test the get_val_last function
         let last_val = dber.get_val_last(&db, key)?;
         assert_eq!(last_val, Some(b"z".to_vec()));
@@ -3535,7 +5605,7 @@ mod tests {
         assert_eq!(retrieved_vals, Vec::<Vec<u8>>::new());
 
         // Test deleting individual values
-        assert_eq!(dber.put_vals(&db, key, &vals)?, true);
+        assert_eq!(dber.put_vals(&db, key, &vals)?, vals.len());
 
         for val in &vals {
             assert_eq!(dber.del_vals(&db, key, Some(*val))?, true);
@@ -3549,7 +5619,7 @@ mod tests {
         assert_eq!(retrieved_vals, Vec::<Vec<u8>>::new());
 
         // Test deleting values while iterating
-        assert_eq!(dber.put_vals(&db, key, &vals)?, true);
+        assert_eq!(dber.put_vals(&db, key, &vals)?, vals.len());
 
         let mut vals_to_delete = Vec::new();
         dber.get_vals_iter(&db, key, |v| {
@@ -3671,6 +5741,15 @@ mod tests {
         let retrieved_vals_refs: Vec<&[u8]> = retrieved_vals.iter().map(|v| v.as_slice()).collect();
         assert_eq!(retrieved_vals_refs, expected_vals);
 
+        // Counted put only counts values that were not already dups
+        assert_eq!(dber.put_io_dup_vals_counted(&db, key, &[b"m", b"e"])?, 0);
+        let more = [b"a", b"q", b"r"];
+        assert_eq!(dber.put_io_dup_vals_counted(&db, key, &more)?, 2);
+        assert_eq!(dber.get_io_dup_val_last(&db, key)?, Some(b"r".to_vec()));
+        assert_eq!(dber.cnt_io_dup_vals(&db, key)?, 6);
+        assert!(dber.del_io_dup_val(&db, key, b"q")?);
+        assert!(dber.del_io_dup_val(&db, key, b"r")?);
+
         // Test the top iterator
         // First, add some more values with different keys
         let key2 = b"B";
@@ -3698,6 +5777,114 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_append_on_val_bounded() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder()
+            .temp(true)
+            .name("test_append_bounded")
+            .no_sync(true)
+            .build()?;
+        let db = lmdber.create_database(Some("seen."), None)?;
+        let pre = b"EH7Oq9oxCgYa-nnNLvwhp9sFZpALILlRYyB-6n4WDi7w";
+        let sep = *b".";
+
+        let count = 500u64;
+        for i in 0..count {
+            let val = format!("{:08}", i);
+            let on = lmdber.append_on_val(&db, pre, val.as_bytes(), Some(sep))?;
+            assert_eq!(on, i);
+        }
+
+        let cnt = lmdber.cnt_on_vals(&db, Some(pre), None, Some(sep))?;
+        assert_eq!(cnt, count as usize);
+        let last = lmdber.get_on_val(&db, pre, count - 1, Some(sep))?;
+        assert_eq!(last, Some(format!("{:08}", count - 1).into_bytes()));
+
+        // No stale reader pins old pages so the file stays close to the data size
+        let path = lmdber.path().expect("temp env has a path").join("data.mdb");
+        let meta = std::fs::metadata(path).map_err(|e| DBError::IoError(e.to_string()))?;
+        let size = meta.len();
+        assert!(size < 1024 * 1024, "data.mdb grew to {} bytes", size);
+
+        Ok(())
+    }
+
+    #[test]
+    #[ignore]
+    fn test_append_on_val_bounded_stress() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder()
+            .temp(true)
+            .name("test_append_bounded_stress")
+            .build()?;
+        let db = lmdber.create_database(Some("seen."), None)?;
+        let pre = b"EH7Oq9oxCgYa-nnNLvwhp9sFZpALILlRYyB-6n4WDi7w";
+        let sep = *b".";
+
+        let count = 50_000u64;
+        for i in 0..count {
+            let val = format!("{:08}", i);
+            let on = lmdber.append_on_val(&db, pre, val.as_bytes(), Some(sep))?;
+            assert_eq!(on, i);
+        }
+
+        let cnt = lmdber.cnt_on_vals(&db, Some(pre), None, Some(sep))?;
+        assert_eq!(cnt, count as usize);
+        let last = lmdber.get_on_val(&db, pre, count - 1, Some(sep))?;
+        assert_eq!(last, Some(format!("{:08}", count - 1).into_bytes()));
+
+        // No stale reader pins old pages so the file stays close to the data size
+        let path = lmdber.path().expect("temp env has a path").join("data.mdb");
+        let meta = std::fs::metadata(path).map_err(|e| DBError::IoError(e.to_string()))?;
+        let size = meta.len();
+        assert!(size < 16 * 1024 * 1024, "data.mdb grew to {} bytes", size);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_on_item_range() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder().temp(true).name("test_on_range").build()?;
+        let db = lmdber.create_database(Some("seen."), None)?;
+        let pre = b"EH7Oq9oxCgYa-nnNLvwhp9sFZpALILlRYyB-6n4WDi7w";
+        let other = b"EIDA1n-WiBA0A8YOqnKrB-wWQYYC49i5zY_qrIZIicQg";
+        let sep = *b".";
+
+        for i in 0..30u32 {
+            lmdber.append_on_val(&db, pre, format!("val{}", i).as_bytes(), Some(sep))?;
+        }
+        lmdber.append_on_val(&db, other, b"other", Some(sep))?;
+
+        let mut items = Vec::new();
+        lmdber.get_on_item_range(&db, pre, 10, 15, Some(sep), |ckey, cn, cval| {
+            items.push((ckey, cn, cval));
+            Ok(true)
+        })?;
+        assert_eq!(items.len(), 5);
+        for (i, (ckey, cn, cval)) in items.iter().enumerate() {
+            assert_eq!(ckey, &pre.to_vec());
+            assert_eq!(*cn, 10 + i as u64);
+            assert_eq!(cval, &format!("val{}", 10 + i).into_bytes());
+        }
+
+        // Window past the end stops at the last ordinal without reaching other
+        let mut ons = Vec::new();
+        lmdber.get_on_item_range(&db, pre, 28, 40, Some(sep), |_, cn, _| {
+            ons.push(cn);
+            Ok(true)
+        })?;
+        assert_eq!(ons, vec![28, 29]);
+
+        // Empty window
+        let mut ons = Vec::new();
+        lmdber.get_on_item_range(&db, pre, 5, 5, Some(sep), |_, cn, _| {
+            ons.push(cn);
+            Ok(true)
+        })?;
+        assert!(ons.is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn test_on_key_value_methods() -> Result<(), DBError> {
         // Set up a temporary directory for the test
@@ -3766,6 +5953,21 @@ mod tests {
         assert_eq!(lmdber.del_on_val(&db, pre_a, 0, None)?, true);
         assert_eq!(lmdber.get_on_val(&db, pre_a, 0, None)?, None);
 
+        // Ordinals past u32::MAX do not wrap onto low ordinals
+        let big = u32::MAX as u64 + 1;
+        assert_eq!(lmdber.put_on_val(&db, pre_a, big, dig_c, None)?, true);
+        assert_eq!(
+            lmdber.get_on_val(&db, pre_a, big, None)?,
+            Some(dig_c.to_vec())
+        );
+        assert_eq!(lmdber.get_on_val(&db, pre_a, 0, None)?, None);
+        assert_eq!(
+            lmdber.get_val(&db, &on_key(pre_a, big, None))?,
+            Some(dig_c.to_vec())
+        );
+        assert_eq!(lmdber.del_on_val(&db, pre_a, big, None)?, true);
+        assert_eq!(lmdber.get_on_val(&db, pre_a, big, None)?, None);
+
         // Test append_on_val
         // Empty database
         assert_eq!(lmdber.get_val(&db, &key_b0)?, None);
@@ -3955,6 +6157,44 @@ mod tests {
         })?;
         assert_eq!(items, vec![]);
 
+        // Streaming range_items matches the callback replay
+        let mut items = Vec::new();
+        for item in lmdber.range_items(&db, Some(pre_b), Some(3), Some(sep))? {
+            items.push(item?);
+        }
+        assert_eq!(
+            items,
+            vec![
+                (pre_b.to_vec(), 3, dig_x.to_vec()),
+                (pre_b.to_vec(), 4, dig_y.to_vec())
+            ]
+        );
+        let items = lmdber
+            .range_items(&db, None, None, Some(sep))?
+            .collect::<Result<Vec<_>, DBError>>()?;
+        assert_eq!(items.len(), 8);
+        assert_eq!(items[0], (pre_a.to_vec(), 0, dig_a.to_vec()));
+        assert_eq!(items[7], (pre_c.to_vec(), 0, dig_c.to_vec()));
+        let mut iter = lmdber.range_items(&db, Some(pre_c), Some(1), Some(sep))?;
+        assert!(iter.next().is_none());
+
+        // Other reads and writes on the same thread interleave with a live iterator
+        let mut items = Vec::new();
+        for item in lmdber.range_items(&db, Some(pre_b), None, Some(sep))? {
+            let (ckey, cn, cval) = item?;
+            assert_eq!(
+                lmdber.get_on_val(&db, &ckey, cn, Some(sep))?,
+                Some(cval.clone())
+            );
+            assert_eq!(lmdber.cnt(&db)?, 8 + items.len());
+            lmdber.set_val(&db, format!("z.{}", cn).as_bytes(), &cval)?;
+            items.push(cval);
+        }
+        assert_eq!(items.len(), 5);
+        for cn in 0..5 {
+            assert!(lmdber.del_val(&db, format!("z.{}", cn).as_bytes())?);
+        }
+
         let mut items = Vec::new();
         lmdber.get_on_val_iter(&db, Some(pre_b), None, Some(sep), |cval| {
             items.push(cval);
@@ -4292,6 +6532,98 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_get_io_set_vals_back_iter() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder().temp(true).build()?;
+        let db = lmdber.create_database(Some("test_io_set"), Some(false))?;
+
+        // Neighbouring keys on both sides must not leak into the iteration
+        let key = b"test_key";
+        let vals: [&[u8]; 4] = [b"value1", b"value2", b"value3", b"value4"];
+        lmdber.put_io_set_vals(&db, b"test_kex", &[b"before".as_slice()], None)?;
+        lmdber.put_io_set_vals(&db, key, &vals, None)?;
+        lmdber.put_io_set_vals(&db, b"test_kez", &[b"after".as_slice()], None)?;
+
+        // Full reverse iteration
+        let mut collected = Vec::new();
+        lmdber.get_io_set_vals_back_iter(&db, key, None, |val| {
+            collected.push(val.to_vec());
+            Ok(true)
+        })?;
+        assert_eq!(
+            collected,
+            vec![
+                b"value4".to_vec(),
+                b"value3".to_vec(),
+                b"value2".to_vec(),
+                b"value1".to_vec()
+            ]
+        );
+
+        // Early termination
+        let mut collected = Vec::new();
+        lmdber.get_io_set_vals_back_iter(&db, key, None, |val| {
+            collected.push(val.to_vec());
+            Ok(collected.len() < 2)
+        })?;
+        assert_eq!(collected, vec![b"value4".to_vec(), b"value3".to_vec()]);
+
+        // Non-existent key
+        let mut collected = Vec::new();
+        lmdber.get_io_set_vals_back_iter(&db, b"non_existent_key", None, |val| {
+            collected.push(val.to_vec());
+            Ok(true)
+        })?;
+        assert!(collected.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_io_set_max_ordinal() -> Result<(), DBError> {
+        let lmdber = LMDBer::builder().temp(true).build()?;
+        let db = lmdber.create_database(Some("test_io_set"), Some(false))?;
+
+        let key = b"test_key";
+        lmdber.put_io_set_vals(&db, b"test_kez", &[b"after".as_slice()], None)?;
+
+        // Next ordinal would land on the reserved u64::MAX
+        lmdber.put_val(&db, &suffix(key, u64::MAX - 1, None), b"penultimate")?;
+        assert_eq!(
+            lmdber.get_io_set_val_last(&db, key, None)?,
+            Some(b"penultimate".to_vec())
+        );
+        assert!(matches!(
+            lmdber.add_io_set_val(&db, key, b"reserved", None),
+            Err(DBError::ValueError(_))
+        ));
+        assert!(matches!(
+            lmdber.put_io_set_vals(&db, key, &[b"reserved".as_slice()], None),
+            Err(DBError::ValueError(_))
+        ));
+        assert_eq!(lmdber.cnt_io_set_vals(&db, key, None)?, 1);
+
+        // A value stored at u64::MAX by other means is still the last one
+        lmdber.put_val(&db, &suffix(key, u64::MAX, None), b"ultimate")?;
+        assert_eq!(
+            lmdber.get_io_set_val_last(&db, key, None)?,
+            Some(b"ultimate".to_vec())
+        );
+        assert!(matches!(
+            lmdber.add_io_set_val(&db, key, b"overflow", None),
+            Err(DBError::ValueError(_))
+        ));
+
+        // Also when it is the last entry in the db
+        lmdber.del_io_set_vals(&db, b"test_kez", None)?;
+        assert_eq!(
+            lmdber.get_io_set_val_last(&db, key, None)?,
+            Some(b"ultimate".to_vec())
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_get_io_set_val_last() -> Result<(), DBError> {
         // Create a temporary database
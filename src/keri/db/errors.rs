@@ -41,4 +41,7 @@ pub enum DBError {
 
     #[error("Encoding error")]
     EncodingError(String),
+
+    #[error("Proem disorder at key: {key}")]
+    ProemDisorder { key: String },
 }
@@ -992,7 +992,7 @@ mod tests {
             )?;
 
             // Create test data
-            let dater = Dater::from_dt(chrono::Utc::now());
+            let dater = Dater::now();
             let datb = dater.qb64b();
 
             let seqner = Seqner::from_sn(20);
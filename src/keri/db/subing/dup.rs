@@ -32,12 +32,13 @@ impl<'db, C: ValueCodec> DupSuber<'db, C> {
     /// dup values at key if any. Duplicate means another entry at the same key
     /// but the entry is still a unique value. Duplicates are inserted in
     /// lexicographic order not insertion order. LMDB does not insert a duplicate
-    /// unless it is a unique value for that key.
+    /// unless it is a unique value for that key. Returns the number of vals
+    /// actually written.
     pub fn put<K: AsRef<[u8]>, V: ?Sized + Clone + Into<Vec<u8>>>(
         &self,
         keys: &[K],
         vals: &[&V],
-    ) -> Result<bool, SuberError> {
+    ) -> Result<usize, SuberError> {
         let key = self.base.to_key(keys, false);
 
         // Serialize all values
@@ -108,6 +109,7 @@ impl<'db, C: ValueCodec> DupSuber<'db, C> {
         self.base
             .db
             .put_vals(&self.base.sdb, &key, &val_slices)
+            .map(|_| true)
             .map_err(SuberError::DBError)
     }
 
@@ -117,14 +119,10 @@ impl<'db, C: ValueCodec> DupSuber<'db, C> {
         <R as TryFrom<Vec<u8>>>::Error: std::fmt::Debug,
     {
         let key = self.base.to_key(keys, false);
-        let mut raw_vals: Vec<Vec<u8>> = Vec::new();
-
-        self.base
+        let raw_vals = self
+            .base
             .db
-            .get_vals_iter(&self.base.sdb, &key, |val| {
-                raw_vals.push(val.to_vec());
-                Ok(true)
-            })
+            .get_vals(&self.base.sdb, &key)
             .map_err(SuberError::DBError)?;
 
         raw_vals
@@ -363,7 +361,8 @@ mod tests {
         )));
 
         // Add test with ("test", "blue") keys as in the Python test
-        assert!(dupber.put(&["test", "blue"], &[&sal, &sue, &sam])?);
+        assert_eq!(dupber.put(&["test", "blue"], &[&sal, &sue, &sam])?, 3);
+        assert_eq!(dupber.put(&["test", "blue"], &[&sue, &sue])?, 0);
 
         // Test getting items with a prefix - similar to the Python test's usage of topkeys
         let topkeys = ["test", ""];
@@ -434,13 +433,13 @@ mod tests {
         let vals1 = ["hi", "me", "my"];
         for (i, val) in vals1.iter().enumerate() {
             let key = on_key("bob", i as u64, None);
-            assert!(dupber.put(&[&key], &[val])?);
+            assert_eq!(dupber.put(&[&key], &[val])?, 1);
         }
 
         let vals2 = ["bye", "guy", "gal"];
         for (i, val) in vals2.iter().enumerate() {
             let key = on_key("bob", i as u64, None);
-            assert!(dupber.put(&[&key], &[val])?);
+            assert_eq!(dupber.put(&[&key], &[val])?, 1);
         }
 
         // Test getting items with "bob" prefix
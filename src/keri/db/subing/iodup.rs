@@ -1,4 +1,5 @@
 use crate::keri::db::dbing::{BytesDatabase, LMDBer};
+use crate::keri::db::errors::DBError;
 use crate::keri::db::subing::dup::DupSuber;
 use crate::keri::db::subing::{SuberError, Utf8Codec, ValueCodec};
 use std::marker::PhantomData;
@@ -350,6 +351,35 @@ impl<'db, C: ValueCodec> IoDupSuber<'db, C> {
         self.base.base.trim(keys, topive)
     }
 
+    /// Return the keys whose dup values have missing, repeated or out of order
+    /// hidden ordinal proems
+    ///
+    /// # Returns
+    /// * `Result<Vec<Vec<u8>>, SuberError>` - Offending keys in key order
+    pub fn disordered_keys(&self) -> Result<Vec<Vec<u8>>, SuberError> {
+        let db = &self.base.base.db;
+        let sdb = &self.base.base.sdb;
+
+        let mut keys: Vec<Vec<u8>> = Vec::new();
+        db.get_top_items_iter(sdb, b"", |k, _| {
+            if keys.last().map(|last| last.as_slice()) != Some(k) {
+                keys.push(k.to_vec());
+            }
+            Ok(true)
+        })?;
+
+        let mut disordered = Vec::new();
+        for key in keys {
+            match db.check_io_dup_invariants(sdb, &key) {
+                Ok(()) => {}
+                Err(DBError::ProemDisorder { .. }) => disordered.push(key),
+                Err(e) => return Err(SuberError::DBError(e)),
+            }
+        }
+
+        Ok(disordered)
+    }
+
     /// Returns whether this SuberBase is configured to support duplicate values for keys.
     ///
     /// # Returns
@@ -66,7 +66,7 @@ impl<'db, C: ValueCodec> crate::keri::db::subing::on::OnSuberBase<'db, C> {
     pub fn put_on<K: AsRef<[u8]>, V: ?Sized + Clone + Into<Vec<u8>>>(
         &self,
         keys: &[K],
-        on: u32,
+        on: u64,
         val: &V,
     ) -> Result<bool, SuberError> {
         let key = self._tokey(keys);
@@ -91,7 +91,7 @@ impl<'db, C: ValueCodec> crate::keri::db::subing::on::OnSuberBase<'db, C> {
     pub fn pin_on<K: AsRef<[u8]>, V: ?Sized + Clone + Into<Vec<u8>>>(
         &self,
         keys: &[K],
-        on: u32,
+        on: u64,
         val: &V,
     ) -> Result<bool, SuberError> {
         let key = self._tokey(keys);
@@ -99,13 +99,7 @@ impl<'db, C: ValueCodec> crate::keri::db::subing::on::OnSuberBase<'db, C> {
 
         self.base
             .db
-            .set_on_val(
-                &self.base.sdb,
-                &key,
-                Some(on as u64),
-                &sval,
-                Some([self.base.sep]),
-            )
+            .set_on_val(&self.base.sdb, &key, Some(on), &sval, Some([self.base.sep]))
             .map_err(SuberError::DBError)
     }
 
@@ -143,7 +137,7 @@ impl<'db, C: ValueCodec> crate::keri::db::subing::on::OnSuberBase<'db, C> {
     pub fn get_on<K: AsRef<[u8]>, R: TryFrom<Vec<u8>>>(
         &self,
         keys: &[K],
-        on: u32,
+        on: u64,
     ) -> Result<Option<R>, SuberError>
     where
         <R as TryFrom<Vec<u8>>>::Error: std::fmt::Debug,
@@ -170,7 +164,7 @@ impl<'db, C: ValueCodec> crate::keri::db::subing::on::OnSuberBase<'db, C> {
     /// # Parameters
     /// * `keys` - Keys as prefix to be combined with serialized on suffix and sep to form onkey
     /// * `on` - Ordinal number used to form key
-    pub fn rem_on<K: AsRef<[u8]>>(&self, keys: &[K], on: u32) -> Result<bool, SuberError> {
+    pub fn rem_on<K: AsRef<[u8]>>(&self, keys: &[K], on: u64) -> Result<bool, SuberError> {
         let key = self._tokey(keys);
 
         self.base
@@ -187,17 +181,12 @@ impl<'db, C: ValueCodec> crate::keri::db::subing::on::OnSuberBase<'db, C> {
     /// # Parameters
     /// * `keys` - Top keys as prefix to be combined with serialized on suffix and sep to form top key
     /// * `on` - Ordinal number used to form key
-    pub fn cnt_on<K: AsRef<[u8]>>(&self, keys: &[K], on: u32) -> Result<usize, SuberError> {
+    pub fn cnt_on<K: AsRef<[u8]>>(&self, keys: &[K], on: u64) -> Result<usize, SuberError> {
         let key = self._tokey(keys);
 
         self.base
             .db
-            .cnt_on_vals(
-                &self.base.sdb,
-                Some(&key),
-                Some(on as u64),
-                Some([self.base.sep]),
-            )
+            .cnt_on_vals(&self.base.sdb, Some(&key), Some(on), Some([self.base.sep]))
             .map_err(SuberError::DBError)
     }
 
@@ -212,7 +201,7 @@ impl<'db, C: ValueCodec> crate::keri::db::subing::on::OnSuberBase<'db, C> {
     pub fn get_on_iter<K: AsRef<[u8]>, R: TryFrom<Vec<u8>> + 'static>(
         &self,
         keys: &[K],
-        on: u32,
+        on: u64,
     ) -> Result<Vec<R>, SuberError>
     where
         <R as TryFrom<Vec<u8>>>::Error: std::fmt::Debug,
@@ -225,7 +214,7 @@ impl<'db, C: ValueCodec> crate::keri::db::subing::on::OnSuberBase<'db, C> {
             .get_on_val_iter(
                 &self.base.sdb,
                 Some(&key),
-                Some(on as u64),
+                Some(on),
                 Some([self.base.sep]),
                 |val| {
                     // Store the value to process after the callback
@@ -255,7 +244,7 @@ impl<'db, C: ValueCodec> crate::keri::db::subing::on::OnSuberBase<'db, C> {
     pub fn get_on_item_iter<K: AsRef<[u8]>, R: TryFrom<Vec<u8>> + 'static>(
         &self,
         keys: &[K],
-        on: u32,
+        on: u64,
     ) -> Result<Vec<(Vec<Vec<u8>>, u64, R)>, SuberError>
     where
         <R as TryFrom<Vec<u8>>>::Error: std::fmt::Debug,
@@ -268,7 +257,7 @@ impl<'db, C: ValueCodec> crate::keri::db::subing::on::OnSuberBase<'db, C> {
             .get_on_item_iter(
                 &self.base.sdb,
                 Some(&key),
-                Some(on as u64),
+                Some(on),
                 Some([self.base.sep]),
                 |k, o, val| {
                     // Store the data to process after the callback
@@ -371,7 +360,7 @@ impl<'db, C: ValueCodec> OnSuber<'db, C> {
     pub fn put_on<K: AsRef<[u8]>, V: ?Sized + Clone + Into<Vec<u8>>>(
         &self,
         keys: &[K],
-        on: u32,
+        on: u64,
         val: &V,
     ) -> Result<bool, SuberError> {
         self.on_base.put_on(keys, on, val)
@@ -380,7 +369,7 @@ impl<'db, C: ValueCodec> OnSuber<'db, C> {
     pub fn pin_on<K: AsRef<[u8]>, V: ?Sized + Clone + Into<Vec<u8>>>(
         &self,
         keys: &[K],
-        on: u32,
+        on: u64,
         val: &V,
     ) -> Result<bool, SuberError> {
         self.on_base.pin_on(keys, on, val)
@@ -397,7 +386,7 @@ impl<'db, C: ValueCodec> OnSuber<'db, C> {
     pub fn get_on<K: AsRef<[u8]>, R: TryFrom<Vec<u8>>>(
         &self,
         keys: &[K],
-        on: u32,
+        on: u64,
     ) -> Result<Option<R>, SuberError>
     where
         <R as TryFrom<Vec<u8>>>::Error: std::fmt::Debug,
@@ -405,18 +394,18 @@ impl<'db, C: ValueCodec> OnSuber<'db, C> {
         self.on_base.get_on(keys, on)
     }
 
-    pub fn rem_on<K: AsRef<[u8]>>(&self, keys: &[K], on: u32) -> Result<bool, SuberError> {
+    pub fn rem_on<K: AsRef<[u8]>>(&self, keys: &[K], on: u64) -> Result<bool, SuberError> {
         self.on_base.rem_on(keys, on)
     }
 
-    pub fn cnt_on<K: AsRef<[u8]>>(&self, keys: &[K], on: u32) -> Result<usize, SuberError> {
+    pub fn cnt_on<K: AsRef<[u8]>>(&self, keys: &[K], on: u64) -> Result<usize, SuberError> {
         self.on_base.cnt_on(keys, on)
     }
 
     pub fn get_on_iter<K: AsRef<[u8]>, R: TryFrom<Vec<u8>> + 'static>(
         &self,
         keys: &[K],
-        on: u32,
+        on: u64,
     ) -> Result<Vec<R>, SuberError>
     where
         <R as TryFrom<Vec<u8>>>::Error: std::fmt::Debug,
@@ -427,7 +416,7 @@ impl<'db, C: ValueCodec> OnSuber<'db, C> {
     pub fn get_on_item_iter<K: AsRef<[u8]>, R: TryFrom<Vec<u8>> + 'static>(
         &self,
         keys: &[K],
-        on: u32,
+        on: u64,
     ) -> Result<Vec<(Vec<Vec<u8>>, u64, R)>, SuberError>
     where
         <R as TryFrom<Vec<u8>>>::Error: std::fmt::Debug,
@@ -873,4 +862,41 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_on_suber_wide_ordinals() -> Result<(), SuberError> {
+        let db = LMDBer::builder()
+            .temp(true)
+            .name("test_wide")
+            .build()
+            .map_err(SuberError::DBError)?;
+        let onsuber = OnSuber::<Utf8Codec>::new(Arc::new(&db), "bags.", None, false)?;
+
+        // Ordinals beyond u32 must not wrap onto low ordinals
+        let wide = u32::MAX as u64 + 5;
+        assert!(onsuber.put_on(&["a"], 4, &"low")?);
+        assert!(onsuber.put_on(&["a"], wide, &"wide")?);
+        assert_eq!(
+            onsuber.get_on::<_, Vec<u8>>(&["a"], wide)?,
+            Some(b"wide".to_vec())
+        );
+        assert_eq!(
+            onsuber.get_on::<_, Vec<u8>>(&["a"], 4)?,
+            Some(b"low".to_vec())
+        );
+        assert_eq!(onsuber.cnt_on(&["a"], wide)?, 1);
+        assert_eq!(onsuber.append_on(&["a"], &"next")?, wide + 1);
+
+        let items = onsuber.get_on_item_iter::<_, Vec<u8>>(&["a"], u32::MAX as u64)?;
+        let ons: Vec<u64> = items.iter().map(|(_, on, _)| *on).collect();
+        assert_eq!(ons, vec![wide, wide + 1]);
+
+        assert!(onsuber.rem_on(&["a"], wide)?);
+        assert_eq!(
+            onsuber.get_on::<_, Vec<u8>>(&["a"], 4)?,
+            Some(b"low".to_vec())
+        );
+
+        Ok(())
+    }
 }
@@ -33,7 +33,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
     pub fn add_on<K: AsRef<[u8]>, V: ?Sized + Clone + Into<Vec<u8>>>(
         &self,
         keys: &[K],
-        on: u32,
+        on: u64,
         val: &V,
     ) -> Result<bool, SuberError> {
         let key = self.on_base._tokey(keys);
@@ -45,7 +45,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
             .add_on_io_dup_val(
                 &self.on_base.base.sdb,
                 &key,
-                Some(on as u64),
+                Some(on),
                 &sval,
                 Some([self.on_base.base.sep]),
             )
@@ -77,7 +77,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
     pub fn get_on<K: AsRef<[u8]>, R: TryFrom<Vec<u8>>>(
         &self,
         keys: &[K],
-        on: u32,
+        on: u64,
     ) -> Result<Vec<R>, SuberError>
     where
         <R as TryFrom<Vec<u8>>>::Error: std::fmt::Debug,
@@ -91,7 +91,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
             .get_on_io_dup_val_iter(
                 &self.on_base.base.sdb,
                 Some(&key),
-                Some(on as u64),
+                Some(on),
                 Some([self.on_base.base.sep]),
                 |val| {
                     // Handle the deserialization manually, convert errors if needed
@@ -121,7 +121,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
     pub fn rem_on<K: AsRef<[u8]>, V: ?Sized + Clone + Into<Vec<u8>>>(
         &self,
         keys: &[K],
-        on: u32,
+        on: u64,
         val: Option<&V>,
     ) -> Result<bool, SuberError> {
         let key = self.on_base._tokey(keys);
@@ -135,7 +135,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
                     .del_on_io_dup_val(
                         &self.on_base.base.sdb,
                         &key,
-                        Some(on as u64),
+                        Some(on),
                         &sval,
                         Some([self.on_base.base.sep]),
                     )
@@ -148,7 +148,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
                 .del_on_io_dup_vals(
                     &self.on_base.base.sdb,
                     &key,
-                    Some(on as u64),
+                    Some(on),
                     Some([self.on_base.base.sep]),
                 )
                 .map_err(SuberError::DBError),
@@ -159,7 +159,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
     pub fn get_on_iter<K: AsRef<[u8]>, R: TryFrom<Vec<u8>> + 'static>(
         &self,
         keys: &[K],
-        on: u32,
+        on: u64,
     ) -> Result<Box<dyn Iterator<Item = Result<R, SuberError>> + '_>, SuberError>
     where
         <R as TryFrom<Vec<u8>>>::Error: std::fmt::Debug,
@@ -178,7 +178,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
             .get_on_io_dup_val_iter(
                 &self.on_base.base.sdb,
                 Some(&key),
-                Some(on as u64),
+                Some(on),
                 Some([sep]),
                 |val| {
                     collector.push(val);
@@ -200,7 +200,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
     pub fn get_on_item_iter<K: AsRef<[u8]>, R: TryFrom<Vec<u8>> + 'static>(
         &self,
         keys: &[K],
-        on: u32,
+        on: u64,
     ) -> Result<Box<dyn Iterator<Item = Result<(Vec<Vec<u8>>, u64, R), SuberError>> + '_>, SuberError>
     where
         <R as TryFrom<Vec<u8>>>::Error: std::fmt::Debug,
@@ -219,7 +219,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
             .get_on_io_dup_item_iter(
                 &self.on_base.base.sdb,
                 Some(&key),
-                Some(on as u64),
+                Some(on),
                 Some([sep]),
                 |k, o, v| {
                     collector.push((k.to_vec(), o, v.to_vec()));
@@ -242,7 +242,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
     pub fn get_on_last_iter<K: AsRef<[u8]>, R: TryFrom<Vec<u8>> + 'static>(
         &self,
         keys: &[K],
-        on: u32,
+        on: u64,
     ) -> Result<Box<dyn Iterator<Item = Result<R, SuberError>> + '_>, SuberError>
     where
         <R as TryFrom<Vec<u8>>>::Error: std::fmt::Debug,
@@ -261,7 +261,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
             .get_on_io_dup_last_val_iter(
                 &self.on_base.base.sdb,
                 Some(&key),
-                Some(on as u64),
+                Some(on),
                 Some([sep]),
                 |val| {
                     collector.push(val.to_vec());
@@ -282,7 +282,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
     pub fn get_on_last_item_iter<K: AsRef<[u8]>, R: TryFrom<Vec<u8>> + 'static>(
         &self,
         keys: &[K],
-        on: u32,
+        on: u64,
     ) -> Result<Box<dyn Iterator<Item = Result<(Vec<Vec<u8>>, u64, R), SuberError>> + '_>, SuberError>
     where
         <R as TryFrom<Vec<u8>>>::Error: std::fmt::Debug,
@@ -301,7 +301,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
             .get_on_io_dup_last_item_iter(
                 &self.on_base.base.sdb,
                 Some(&key),
-                Some(on as u64),
+                Some(on),
                 Some([sep]),
                 |k, o, v| {
                     collector.push((k.to_vec(), o, v.to_vec()));
@@ -324,7 +324,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
     pub fn get_on_back_iter<K: AsRef<[u8]>, R: TryFrom<Vec<u8>> + 'static>(
         &self,
         keys: &[K],
-        on: u32,
+        on: u64,
     ) -> Result<Box<dyn Iterator<Item = Result<R, SuberError>> + '_>, SuberError>
     where
         <R as TryFrom<Vec<u8>>>::Error: std::fmt::Debug,
@@ -343,7 +343,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
             .get_on_io_dup_val_back_iter(
                 &self.on_base.base.sdb,
                 Some(&key),
-                Some(on as u64),
+                Some(on),
                 Some([sep]),
                 |val| {
                     collector.push(val.to_vec());
@@ -364,7 +364,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
     pub fn get_on_item_back_iter<K: AsRef<[u8]>, R: TryFrom<Vec<u8>> + 'static>(
         &self,
         keys: &[K],
-        on: u32,
+        on: u64,
     ) -> Result<Box<dyn Iterator<Item = Result<(Vec<Vec<u8>>, u64, R), SuberError>> + '_>, SuberError>
     where
         <R as TryFrom<Vec<u8>>>::Error: std::fmt::Debug,
@@ -383,7 +383,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
             .get_on_io_dup_item_back_iter(
                 &self.on_base.base.sdb,
                 Some(&key),
-                Some(on as u64),
+                Some(on),
                 Some([sep]),
                 |k, o, v| {
                     collector.push((k.to_vec(), o, v.to_vec()));
@@ -410,7 +410,7 @@ impl<'db, C: ValueCodec> OnIoDupSuber<'db, C> {
     /// # Parameters
     /// * `keys` - Top keys as prefix to be combined with serialized on suffix and sep to form top key
     /// * `on` - Ordinal number used to form key
-    pub fn cnt_on<K: AsRef<[u8]>>(&self, keys: &[K], on: u32) -> Result<usize, SuberError> {
+    pub fn cnt_on<K: AsRef<[u8]>>(&self, keys: &[K], on: u64) -> Result<usize, SuberError> {
         self.on_base.cnt_on(keys, on)
     }
 
@@ -1,9 +1,15 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+
 /// Returns time now in RFC-3339 profile of ISO 8601 format.
 /// Format: YYYY-MM-DDTHH:MM:SS.ffffff+HH:MM
 /// Equivalent to Python's datetime.now(timezone.utc).isoformat(timespec='microseconds')
 pub fn nowiso8601() -> String {
-    use chrono::{SecondsFormat, Utc};
-
     // Get current UTC time and format with microsecond precision
     Utc::now().to_rfc3339_opts(SecondsFormat::Micros, true)
 }
+
+/// Returns `dt` in RFC-3339 profile of ISO 8601 format with microseconds and
+/// `+00:00` offset, the fixed 32 char form KERI stamps use
+pub fn toiso8601(dt: &DateTime<Utc>) -> String {
+    dt.to_rfc3339_opts(SecondsFormat::Micros, false)
+}
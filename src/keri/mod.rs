@@ -9,11 +9,14 @@ use regex::Regex;
 use std::fmt;
 use thiserror::Error;
 
+pub mod acdc;
 pub mod app;
 pub mod core;
 pub mod db;
 mod help;
 
+pub(crate) use help::helping::toiso8601;
+
 /// Format string for version
 pub const VERFMT: &str = "{}{:x}{:x}{}{:0{}x}_";
 
@@ -171,6 +174,9 @@ pub enum KERIError {
     #[error("Unverified receipt event error: {0}")]
     UnverifiedReceiptError(String),
 
+    #[error("Invalid receipt: {0}")]
+    InvalidReceipt(String),
+
     #[error("Query not found error: {0}")]
     QueryNotFoundError(String),
 
@@ -194,6 +200,24 @@ pub enum KERIError {
 
     #[error("Closed error")]
     ClosedError(String),
+
+    #[error("Invalid interaction event: {0}")]
+    InvalidInteraction(String),
+
+    #[error("Invalid witness threshold: {0}")]
+    InvalidToad(String),
+
+    #[error("Future dated: {0}")]
+    FutureDated(String),
+
+    #[error("Event size={size} exceeds max={max}.")]
+    EventTooLarge { size: usize, max: usize },
+
+    #[error("Stale reply: {0}")]
+    StaleReply(String),
+
+    #[error("Key reuse: {0}")]
+    KeyReuse(String),
 }
 
 impl From<MatterError> for KERIError {